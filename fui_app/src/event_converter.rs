@@ -1,13 +1,19 @@
 use windowing_api::{Event, ScrollDelta};
 
-pub fn convert_event(event: &Event) -> Option<fui_core::InputEvent> {
+///
+/// Converts the window event to the input event.
+///
+/// Positions are converted from physical to logical pixels
+/// by dividing them by the `scale_factor`.
+///
+pub fn convert_event(event: &Event, scale_factor: f32) -> Option<fui_core::InputEvent> {
     match event {
         Event::MouseEnter { .. } => Some(fui_core::InputEvent::CursorEntered {}),
 
         Event::MouseLeave { .. } => Some(fui_core::InputEvent::CursorLeft {}),
 
         Event::MouseMove { position, .. } => Some(fui_core::InputEvent::CursorMoved {
-            position: fui_core::Point::new(position.x, position.y),
+            position: fui_core::Point::new(position.x / scale_factor, position.y / scale_factor),
         }),
 
         Event::MouseButton { state, button } => Some(fui_core::InputEvent::MouseInput {
@@ -27,7 +33,9 @@ pub fn convert_event(event: &Event) -> Option<fui_core::InputEvent> {
         Event::ScrollWheel { delta } => Some(fui_core::InputEvent::ScrollWheel {
             delta: match delta {
                 ScrollDelta::LineDelta(x, y) => fui_core::ScrollDelta::LineDelta(*x, *y),
-                ScrollDelta::PixelDelta(x, y) => fui_core::ScrollDelta::PixelDelta(*x, *y),
+                ScrollDelta::PixelDelta(x, y) => {
+                    fui_core::ScrollDelta::PixelDelta(*x / scale_factor, *y / scale_factor)
+                }
             },
         }),
 
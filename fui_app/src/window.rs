@@ -1,8 +1,12 @@
 use crate::{AppFileDialog, Clipboard, APPLICATION_GUI_CONTEXT};
 use crate::{WindowOptions, APPLICATION_VM_CONTEXT};
 use anyhow::Result;
-use fui_core::{Children, FuiDrawingContext, Grid, Rect, Services, Size, TypeMap, ViewContext};
-use fui_core::{ControlObject, EventProcessor, ObservableVec};
+use fui_controls::{CurrentTheme, Theme};
+use fui_core::{
+    Children, DpiScale, FuiDrawingContext, Grid, Property, Rect, Services, Size, StyleSheet,
+    Styles, TypeMap, ViewContext, WindowSize,
+};
+use fui_core::{ControlObject, EventProcessor, ObservableVec, Subscription};
 use fui_core::{ViewModel, WindowService};
 use fui_drawing::prelude::*;
 use fui_macros::ui;
@@ -22,6 +26,9 @@ pub type WindowId = i64;
 ///
 pub struct WindowGUIThreadData {
     system_window: Option<windowing_qt::Window>,
+
+    /// Used to convert input positions to logical pixels.
+    scale_factor: f32,
 }
 
 impl Drop for WindowGUIThreadData {
@@ -44,6 +51,20 @@ pub struct WindowVMThreadData {
     view_model: RefCell<Option<Rc<dyn std::any::Any>>>,
     services: RefCell<Option<fui_core::Services>>,
 
+    /// Attached to the root control as `DpiScale`.
+    scale_factor: Property<f32>,
+
+    /// Attached to the root control as `WindowSize` (in logical pixels).
+    window_size: Property<Size>,
+
+    /// Attached to the root control as `CurrentTheme`.
+    theme: Property<Rc<Theme>>,
+    _theme_subscription: Subscription,
+
+    /// Reloads the theme file when it changes (see `Window::load_theme()`).
+    #[cfg(all(feature = "serde", debug_assertions))]
+    theme_file_watcher: RefCell<Option<fui_core::JoinHandle<()>>>,
+
     control_layers: ObservableVec<Rc<dyn ControlObject>>,
 }
 
@@ -55,6 +76,13 @@ pub struct Window {
 impl Window {
     pub async fn create(window_options: WindowOptions) -> Result<Self> {
         // VM Thread
+        let scale_factor = window_options.scale_factor;
+        let theme = window_options.theme.clone();
+        let initial_size = Size::new(
+            window_options.width as f32 / scale_factor,
+            window_options.height as f32 / scale_factor,
+        );
+
         let (tx, rx) = oneshot::channel::<WindowId>();
         windowing_qt::Application::post_func(move || {
             // GUI Thread
@@ -81,6 +109,7 @@ impl Window {
 
             let window_gui_thread_data = WindowGUIThreadData {
                 system_window: Some(native_window),
+                scale_factor,
             };
 
             let window_id = APPLICATION_GUI_CONTEXT.with(move |context| {
@@ -105,13 +134,29 @@ impl Window {
         let window_id = rx.await?;
 
         let control_layers = ObservableVec::<Rc<dyn ControlObject>>::new();
+        let scale_factor = Property::new(scale_factor);
+        let window_size = Property::new(initial_size);
+        let theme = Property::new(Rc::new(theme));
 
         let content = ui!(
             Grid {
+                DpiScale: scale_factor.clone(),
+                WindowSize: window_size.clone(),
+                CurrentTheme: theme.clone(),
                 &control_layers,
             }
         );
 
+        // all the controls read the theme again
+        let theme_subscription = theme.on_changed({
+            let content_weak = Rc::downgrade(&content);
+            move |_| {
+                if let Some(content) = content_weak.upgrade() {
+                    content.get_context().invalidate_tree();
+                }
+            }
+        });
+
         let window_data_rc = Rc::new(WindowVMThreadData {
             id: window_id,
             event_processor: RefCell::new(EventProcessor::new()),
@@ -119,6 +164,12 @@ impl Window {
             view: RefCell::new(None),
             view_model: RefCell::new(None),
             services: RefCell::new(None),
+            scale_factor,
+            window_size,
+            theme,
+            _theme_subscription: theme_subscription,
+            #[cfg(all(feature = "serde", debug_assertions))]
+            theme_file_watcher: RefCell::new(None),
             control_layers,
         });
 
@@ -192,6 +243,110 @@ impl Window {
         self.data.services.borrow().clone().unwrap()
     }
 
+    pub fn get_scale_factor(&self) -> f32 {
+        self.data.scale_factor.get()
+    }
+
+    ///
+    /// Sets the number of physical pixels per one logical pixel
+    /// (e.g. when the window has moved to another monitor).
+    ///
+    /// The whole window is measured and drawn again.
+    ///
+    pub fn set_scale_factor(&self, scale_factor: f32) {
+        // VM Thread
+        self.data.scale_factor.set(scale_factor);
+
+        let window_id = self.data.id;
+        windowing_qt::Application::post_func(move || {
+            // GUI Thread
+            APPLICATION_GUI_CONTEXT.with(move |context| {
+                let mut context = context.borrow_mut();
+                let app_context = context.as_mut().unwrap();
+                if let Some(window) = app_context.windows.get_mut(&window_id) {
+                    window.scale_factor = scale_factor;
+                    window.system_window.as_mut().unwrap().update();
+                }
+            });
+        });
+    }
+
+    pub fn get_theme(&self) -> Rc<Theme> {
+        self.data.theme.get()
+    }
+
+    ///
+    /// Replaces the theme of the window (e.g. switches between `Theme::dark()`
+    /// and `Theme::light()`). The whole window is measured and drawn again.
+    ///
+    pub fn set_theme(&self, theme: Theme) {
+        self.data.theme.set(Rc::new(theme));
+    }
+
+    ///
+    /// Loads the theme from the file (TOML for the `.toml` extension and JSON otherwise,
+    /// see `Theme::load()`).
+    ///
+    /// In debug builds the file is watched and the theme is reloaded
+    /// when the file changes, so colors can be tweaked without recompiling.
+    ///
+    #[cfg(feature = "serde")]
+    pub fn load_theme<P: AsRef<std::path::Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref().to_path_buf();
+        self.set_theme(Theme::load(&path)?);
+
+        #[cfg(debug_assertions)]
+        self.watch_theme_file(path);
+
+        Ok(())
+    }
+
+    #[cfg(all(feature = "serde", debug_assertions))]
+    fn watch_theme_file(&self, path: std::path::PathBuf) {
+        let modified_time = |path: &std::path::Path| {
+            std::fs::metadata(path)
+                .and_then(|metadata| metadata.modified())
+                .ok()
+        };
+
+        let data_weak = Rc::downgrade(&self.data);
+        let watcher = fui_core::spawn_local(async move {
+            let mut last_modified = modified_time(&path);
+            loop {
+                tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+
+                let modified = modified_time(&path);
+                if modified == last_modified {
+                    continue;
+                }
+                last_modified = modified;
+
+                let Some(data) = data_weak.upgrade() else {
+                    break;
+                };
+                match Theme::load(&path) {
+                    Ok(theme) => data.theme.set(Rc::new(theme)),
+                    // keep the previous theme until the file is fixed
+                    Err(err) => eprintln!("Cannot reload theme from {:?}: {}", path, err),
+                }
+            }
+        });
+
+        // the previous watcher is aborted
+        *self.data.theme_file_watcher.borrow_mut() = Some(watcher);
+    }
+
+    ///
+    /// Sets the style sheet of the whole window (see `Styles`).
+    /// Style sheets attached to the controls of the window override it.
+    ///
+    pub fn set_style_sheet(&self, style_sheet: StyleSheet) {
+        self.data
+            .root_control
+            .get_context()
+            .set_attached_value::<Styles>(Rc::new(style_sheet));
+    }
+
     fn setup_window_events(window_id: WindowId) {
         APPLICATION_GUI_CONTEXT.with(move |context| {
             let mut context = context.borrow_mut();
@@ -263,7 +418,20 @@ impl Window {
 
                 window_data.system_window.as_mut().unwrap().on_event({
                     move |event| {
-                        if let Some(input_event) = crate::event_converter::convert_event(&event) {
+                        let scale_factor = APPLICATION_GUI_CONTEXT.with(move |context| {
+                            context
+                                .borrow()
+                                .as_ref()
+                                .unwrap()
+                                .windows
+                                .get(&window_id)
+                                .map(|window_data| window_data.scale_factor)
+                                .unwrap_or(1.0f32)
+                        });
+
+                        if let Some(input_event) =
+                            crate::event_converter::convert_event(&event, scale_factor)
+                        {
                             APPLICATION_GUI_CONTEXT.with(move |context| {
                                 let mut context = context.borrow_mut();
                                 let app_context = context.as_mut().unwrap();
@@ -366,6 +534,15 @@ impl Window {
                             window_data.root_control.get_rect()
                         };
 
+                        // logical to physical pixels
+                        let scale_factor = window_data.scale_factor.get();
+                        let min_size = Rect::new(
+                            0.0f32,
+                            0.0f32,
+                            min_size.width * scale_factor,
+                            min_size.height * scale_factor,
+                        );
+
                         tx.send(Some(min_size)).unwrap();
                     } else {
                         tx.send(None).unwrap();
@@ -417,19 +594,19 @@ impl Window {
                         .with(move |context| context.borrow().as_ref().unwrap().fonts.clone());
 
                     if let Some(window_data) = window_data {
-                        let size = Size::new(width as f32, height as f32);
+                        let physical_size = Size::new(width as f32, height as f32);
 
                         let mut display_list_builder = DrawingDisplayListBuilder::new(Some(rect(
                             0.0,
                             0.0,
-                            size.width,
-                            size.height,
+                            physical_size.width,
+                            physical_size.height,
                         )));
 
                         display_list_builder.draw_paint([0.3f32, 0.4f32, 0.3f32, 1.0f32]);
 
                         display_list_builder.draw_rect(
-                            rect(0.0f32, 0.0f32, size.width, size.height),
+                            rect(0.0f32, 0.0f32, physical_size.width, physical_size.height),
                             DrawingPaint::color_source(ColorSource::Image {
                                 image: background_texture,
                                 horizontal_tile_mode: TileMode::Repeat,
@@ -439,6 +616,17 @@ impl Window {
                             }),
                         );
 
+                        // the layout works in logical pixels
+                        let scale_factor = window_data.scale_factor.get();
+                        let size = Size::new(
+                            physical_size.width / scale_factor,
+                            physical_size.height / scale_factor,
+                        );
+                        display_list_builder.scale(scale_factor, scale_factor);
+
+                        // adaptive layouts react to the change with the next frame
+                        window_data.window_size.set(size);
+
                         let mut fui_drawing_context = FuiDrawingContext {
                             fonts: &mut fonts,
                             display: &mut display_list_builder,
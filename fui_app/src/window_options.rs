@@ -1,3 +1,4 @@
+use fui_controls::Theme;
 use windowing_api::{TranslucentEffect, WindowFrameType};
 
 #[derive(Clone)]
@@ -11,6 +12,11 @@ pub struct WindowOptions {
     pub translucent_effect: TranslucentEffect,
     pub frame_type: WindowFrameType,
     pub visible: bool,
+
+    /// Number of physical pixels per one logical pixel.
+    pub scale_factor: f32,
+
+    pub theme: Theme,
 }
 
 impl WindowOptions {
@@ -25,6 +31,8 @@ impl WindowOptions {
             translucent_effect: TranslucentEffect::None,
             frame_type: WindowFrameType::Normal,
             visible: true,
+            scale_factor: 1.0f32,
+            theme: Theme::default(),
         }
     }
 
@@ -68,4 +76,14 @@ impl WindowOptions {
         self.visible = visible;
         self
     }
+
+    pub fn with_scale_factor(mut self, scale_factor: f32) -> Self {
+        self.scale_factor = scale_factor;
+        self
+    }
+
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
 }
@@ -57,10 +57,7 @@ impl DefaultBorderStyle {
     }
 
     fn get_border_size(data: &mut Border, control_context: &ControlContext) -> f32 {
-        let border_size = control_context
-            .get_inherited_value::<BorderSize>()
-            .map(|p| p.get())
-            .unwrap_or(default_theme::BORDER_SIZE);
+        let border_size = BorderSize::get(control_context);
         match data.border_type {
             BorderType::None => 0f32,
             BorderType::Sunken | BorderType::Raisen => border_size,
@@ -175,6 +172,7 @@ impl Style<Border> for DefaultBorderStyle {
         drawing_context: &mut FuiDrawingContext,
     ) {
         let r = control_context.get_rect();
+        let theme = Theme::get(control_context);
 
         let x = r.x;
         let y = r.y;
@@ -193,6 +191,7 @@ impl Style<Border> for DefaultBorderStyle {
 
             BorderType::Sunken => default_theme::border_3d_single(
                 &mut drawing_context.display,
+                &theme,
                 x,
                 y,
                 width,
@@ -204,6 +203,7 @@ impl Style<Border> for DefaultBorderStyle {
 
             BorderType::Raisen => default_theme::border_3d_single(
                 &mut drawing_context.display,
+                &theme,
                 x,
                 y,
                 width,
@@ -215,13 +215,14 @@ impl Style<Border> for DefaultBorderStyle {
 
             BorderType::Frame3D => default_theme::border_3d_with_color(
                 &mut drawing_context.display,
+                &theme,
                 x,
                 y,
                 width,
                 height,
                 true,
                 true,
-                default_theme::WINDOW_FRAME_COLOR.into(),
+                theme.palette.window_frame_color.into(),
             ),
         }
 
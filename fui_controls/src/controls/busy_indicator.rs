@@ -1,4 +1,5 @@
 use crate::controls::*;
+use crate::style::{BusyIndicatorOverlay, Theme};
 use crate::DataHolder;
 use fui_core::*;
 use fui_drawing::Color;
@@ -18,18 +19,37 @@ impl BusyIndicator {
         _style: Option<Box<dyn Style<Self>>>,
         context: ViewContext,
     ) -> Rc<dyn ControlObject> {
-        let content = ui! {
-            Grid {
-                Visible: self.is_busy,
+        let is_busy = self.is_busy;
 
-                Border {
-                    border_type: BorderType::None,
-                    Style: Default { background_color: Color::rgba(0.0, 0.0, 0.0, 0.7), },
+        // built when attached, so the overlay color can follow the theme
+        let content = CompositeControl::new(
+            ViewContext {
+                attached_values: TypeMap::new(),
+                children: context.children,
+            },
+            move |ctx: &ControlContext| {
+                let overlay_color = ctx
+                    .get_inherited_value::<BusyIndicatorOverlay>()
+                    .unwrap_or_else(|| {
+                        Theme::get_property(ctx)
+                            .map_value(|theme| Color::from(theme.palette.busy_indicator_overlay))
+                    });
+                let children: Vec<_> = ctx.get_children().into_iter().collect();
+
+                ui! {
+                    Grid {
+                        Visible: is_busy,
 
-                    context.children,
+                        Border {
+                            border_type: BorderType::None,
+                            Style: Default { background_color: overlay_color, },
+
+                            children,
+                        }
+                    }
                 }
-            }
-        };
+            },
+        );
 
         let data_holder = DataHolder { data: () };
         data_holder.to_view(
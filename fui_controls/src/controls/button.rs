@@ -37,27 +37,17 @@ impl Button {
 #[derive(TypedBuilder)]
 pub struct DefaultButtonStyleParams {}
 
-pub struct DefaultButtonStyle {
-    is_hover: Property<bool>,
-    is_pressed: Property<bool>,
-    is_focused: Property<bool>,
-}
+pub struct DefaultButtonStyle {}
 
 impl DefaultButtonStyle {
     pub fn new(_params: DefaultButtonStyleParams) -> Self {
-        DefaultButtonStyle {
-            is_hover: Property::new(false),
-            is_pressed: Property::new(false),
-            is_focused: Property::new(false),
-        }
+        DefaultButtonStyle {}
     }
 }
 
 impl Style<Button> for DefaultButtonStyle {
     fn setup(&mut self, _data: &mut Button, control_context: &ControlContext) {
-        control_context.dirty_watch_property(&self.is_hover);
-        control_context.dirty_watch_property(&self.is_pressed);
-        control_context.dirty_watch_property(&self.is_focused);
+        control_context.set_default_attached_value::<Padding>(Thickness::all(10.0f32));
     }
 
     fn handle_event(
@@ -68,44 +58,13 @@ impl Style<Button> for DefaultButtonStyle {
         _event_context: &mut dyn EventContext,
         event: ControlEvent,
     ) {
-        match event {
-            ControlEvent::TapDown { .. } => {
-                self.is_pressed.set(true);
-            }
-
-            ControlEvent::TapUp { ref position } => {
-                if let Some(hit_control) = self.hit_test(&data, &control_context, *position) {
-                    if Rc::ptr_eq(&hit_control, &control_context.get_self_rc()) {
-                        data.clicked.emit(());
-                    }
-                }
-                self.is_pressed.set(false);
-            }
-
-            ControlEvent::TapMove { ref position } => {
-                match self.hit_test(&data, &control_context, *position) {
-                    Some(hit_control) => {
-                        if Rc::ptr_eq(&hit_control, &control_context.get_self_rc()) {
-                            self.is_pressed.set(true);
-                        } else {
-                            self.is_pressed.set(false);
-                        }
-                    }
-                    _ => {
-                        self.is_pressed.set(false);
-                    }
+        // the visual state (hover, pressed, focused) is tracked by the control context
+        if let ControlEvent::TapUp { ref position } = event {
+            if let Some(hit_control) = self.hit_test(&data, &control_context, *position) {
+                if Rc::ptr_eq(&hit_control, &control_context.get_self_rc()) {
+                    data.clicked.emit(());
                 }
             }
-
-            ControlEvent::HoverChange(value) => {
-                self.is_hover.set(value);
-            }
-
-            ControlEvent::FocusChange(value) => {
-                self.is_focused.set(value);
-            }
-
-            _ => (),
         }
     }
 
@@ -114,26 +73,17 @@ impl Style<Button> for DefaultButtonStyle {
         _data: &mut Button,
         control_context: &ControlContext,
         drawing_context: &mut FuiDrawingContext,
-        mut size: Size,
+        size: Size,
     ) -> Size {
-        if size.width.is_finite() {
-            size.width = 0.0f32.max(size.width - 20.0f32);
-        }
-        if size.height.is_finite() {
-            size.height = 0.0f32.max(size.height - 20.0f32);
-        }
-
         let children = control_context.get_children();
-        let content_size = match children.into_iter().next() {
+        match children.into_iter().next() {
             Some(ref content) => {
                 content.measure(drawing_context, size);
                 let rect = content.get_rect();
                 Size::new(rect.width, rect.height)
             }
             _ => Size::new(0f32, 0f32),
-        };
-
-        Size::new(content_size.width + 20.0f32, content_size.height + 20.0f32)
+        }
     }
 
     fn set_rect(
@@ -143,16 +93,9 @@ impl Style<Button> for DefaultButtonStyle {
         drawing_context: &mut FuiDrawingContext,
         rect: Rect,
     ) {
-        let content_rect = Rect::new(
-            rect.x + 10.0f32,
-            rect.y + 10.0f32,
-            rect.width - 20.0f32,
-            rect.height - 20.0f32,
-        );
-
         let children = control_context.get_children();
         if let Some(ref content) = children.into_iter().next() {
-            content.set_rect(drawing_context, content_rect);
+            content.set_rect(drawing_context, rect);
         }
     }
 
@@ -176,6 +119,8 @@ impl Style<Button> for DefaultButtonStyle {
         drawing_context: &mut FuiDrawingContext,
     ) {
         let rect = control_context.get_rect();
+        let theme = Theme::get(control_context);
+        let visual_state = control_context.get_visual_state();
 
         let x = rect.x;
         let y = rect.y;
@@ -184,23 +129,24 @@ impl Style<Button> for DefaultButtonStyle {
 
         default_theme::button(
             &mut drawing_context.display,
+            &theme,
             x,
             y,
             width,
             height,
-            self.is_pressed.get(),
-            self.is_hover.get(),
-            self.is_focused.get(),
+            visual_state.is_pressed,
+            visual_state.is_hover,
+            visual_state.is_focused,
         );
 
         let children = control_context.get_children();
         if let Some(ref content) = children.into_iter().next() {
-            if self.is_pressed.get() {
+            if visual_state.is_pressed {
                 drawing_context.display.save();
                 drawing_context.display.translate(1.0, 1.0);
             }
             content.draw(drawing_context);
-            if self.is_pressed.get() {
+            if visual_state.is_pressed {
                 drawing_context.display.restore();
             }
         }
@@ -0,0 +1,500 @@
+use std::rc::Rc;
+
+use fui_core::*;
+use fui_drawing::prelude::*;
+use fui_drawing::DisplayListBuilder;
+use typed_builder::TypedBuilder;
+use windowing_api::CursorShape;
+
+use crate::style::*;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum GridResizeDirection {
+    /// Resizes the columns on the left and on the right of the splitter.
+    Columns,
+
+    /// Resizes the rows above and below the splitter.
+    Rows,
+}
+
+///
+/// Resizes the rows or columns of the parent `Grid` next to the splitter.
+/// The splitter should be placed in its own row or column. The index of the row or column
+/// has to be specified with the `Row` or `Column` attached value (the cells assigned
+/// by the auto-flow of the grid are not taken into account).
+///
+/// The new sizes are stored in `Grid::resized_widths` / `Grid::resized_heights`.
+///
+/// # Example
+///
+/// ```ignore
+/// ui! {
+///     Grid {
+///         columns: 3,
+///         widths: vec![(0, Length::Exact(200.0f32)), (1, Length::Auto)],
+///         min_widths: vec![(0, 100.0f32)],
+///         resized_widths: &mut self.pane_widths,
+///
+///         Text { text: "Left pane" },
+///         GridSplitter { Column: 1 },
+///         Text { text: "Right pane" },
+///     }
+/// }
+/// ```
+///
+#[derive(TypedBuilder)]
+pub struct GridSplitter {
+    #[builder(default = GridResizeDirection::Columns)]
+    pub direction: GridResizeDirection,
+
+    /// How many pixels the arrow keys move the splitter.
+    #[builder(default = 10.0f32)]
+    pub keyboard_increment: f32,
+}
+
+impl GridSplitter {
+    pub fn to_view(
+        self,
+        style: Option<Box<dyn Style<Self>>>,
+        context: ViewContext,
+    ) -> Rc<dyn ControlObject> {
+        StyledControl::new(
+            self,
+            style.unwrap_or_else(|| {
+                Box::new(DefaultGridSplitterStyle::new(
+                    DefaultGridSplitterStyleParams::builder().build(),
+                ))
+            }),
+            context,
+        )
+    }
+
+    /// Index of the splitter's row or column set with the `Row` / `Column` attached value
+    /// (it has to have neighbours on both sides).
+    fn get_index(&self, control_context: &ControlContext) -> Option<usize> {
+        let index = match self.direction {
+            GridResizeDirection::Columns => *control_context.get_attached_value::<Column>()?,
+            GridResizeDirection::Rows => *control_context.get_attached_value::<Row>()?,
+        };
+        if index >= 1 {
+            Some(index as usize)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the definitions before and after the splitter.
+    fn get_neighbours(
+        &self,
+        control_context: &ControlContext,
+    ) -> Option<(GridDefinition, GridDefinition)> {
+        let index = self.get_index(control_context)?;
+        let parent = control_context.get_parent()?;
+        let grid = parent.as_any().downcast_ref::<StyledControl<Grid>>()?;
+        let grid = grid.data.borrow();
+        let definitions = match self.direction {
+            GridResizeDirection::Columns => grid.get_actual_columns(),
+            GridResizeDirection::Rows => grid.get_actual_rows(),
+        };
+        if index + 1 < definitions.len() {
+            Some((definitions[index - 1], definitions[index + 1]))
+        } else {
+            None
+        }
+    }
+
+    /// Moves the splitter by `delta` pixels from the position
+    /// where the `prev` and `next` definitions were arranged.
+    fn resize(
+        &self,
+        control_context: &ControlContext,
+        prev: &GridDefinition,
+        next: &GridDefinition,
+        delta: f32,
+    ) {
+        let Some(index) = self.get_index(control_context) else {
+            return;
+        };
+        let Some(parent) = control_context.get_parent() else {
+            return;
+        };
+        let Some(grid) = parent.as_any().downcast_ref::<StyledControl<Grid>>() else {
+            return;
+        };
+
+        let (prev_length, next_length) = resize_definitions(prev, next, delta);
+        let changes = [
+            (index as i32 - 1, prev_length),
+            (index as i32 + 1, next_length),
+        ];
+
+        let grid = grid.data.borrow();
+        let resized_lengths = match self.direction {
+            GridResizeDirection::Columns => &grid.resized_widths,
+            GridResizeDirection::Rows => &grid.resized_heights,
+        };
+
+        let mut lengths = resized_lengths.get();
+        lengths.retain(|(i, _)| changes.iter().all(|(changed, _)| changed != i));
+        lengths.extend(changes);
+        lengths.sort_by_key(|(i, _)| *i);
+        resized_lengths.set(lengths);
+    }
+
+    /// Position along the resize direction (columns grow from the right
+    /// for the right-to-left `FlowDirection`).
+    fn get_position(&self, control_context: &ControlContext, position: &Point) -> f32 {
+        match self.direction {
+            GridResizeDirection::Columns => {
+                if FlowDirection::get(control_context).is_right_to_left() {
+                    -position.x
+                } else {
+                    position.x
+                }
+            }
+            GridResizeDirection::Rows => position.y,
+        }
+    }
+}
+
+/// Returns the new lengths of the definitions after moving the border between them
+/// by `delta` pixels. The sizes are kept within the min / max limits.
+fn resize_definitions(
+    prev: &GridDefinition,
+    next: &GridDefinition,
+    delta: f32,
+) -> (Length, Length) {
+    let min_delta = (prev.min_size - prev.actual_size).max(next.actual_size - next.max_size);
+    let max_delta = (prev.max_size - prev.actual_size).min(next.actual_size - next.min_size);
+    let delta = if min_delta <= max_delta {
+        delta.max(min_delta).min(max_delta)
+    } else {
+        0.0f32
+    };
+
+    let prev_size = prev.actual_size + delta;
+    let next_size = next.actual_size - delta;
+
+    match (prev.length, next.length) {
+        (Length::Fill(prev_weight), Length::Fill(next_weight)) => {
+            // keep the total weight, so other fill definitions are not affected
+            let total_size = prev.actual_size + next.actual_size;
+            if total_size > 0.0f32 {
+                let total_weight = prev_weight + next_weight;
+                (
+                    Length::Fill(total_weight * prev_size / total_size),
+                    Length::Fill(total_weight * next_size / total_size),
+                )
+            } else {
+                (prev.length, next.length)
+            }
+        }
+
+        // fill definition takes the space left by the other one
+        (Length::Fill(_), _) => (prev.length, Length::Exact(next_size)),
+        (_, Length::Fill(_)) => (Length::Exact(prev_size), next.length),
+
+        _ => (Length::Exact(prev_size), Length::Exact(next_size)),
+    }
+}
+
+//
+// Default GridSplitter Style
+//
+
+#[derive(TypedBuilder)]
+pub struct DefaultGridSplitterStyleParams {}
+
+pub struct DefaultGridSplitterStyle {
+    /// Pointer position and the neighbour definitions when dragging started.
+    drag_start: Option<(f32, GridDefinition, GridDefinition)>,
+}
+
+impl DefaultGridSplitterStyle {
+    pub fn new(_params: DefaultGridSplitterStyleParams) -> Self {
+        DefaultGridSplitterStyle { drag_start: None }
+    }
+}
+
+impl Style<GridSplitter> for DefaultGridSplitterStyle {
+    fn setup(&mut self, _data: &mut GridSplitter, _control_context: &ControlContext) {}
+
+    fn handle_event(
+        &mut self,
+        data: &mut GridSplitter,
+        control_context: &ControlContext,
+        _drawing_context: &mut FuiDrawingContext,
+        _event_context: &mut dyn EventContext,
+        event: ControlEvent,
+    ) {
+        match event {
+            ControlEvent::TapDown { ref position } => {
+                if let Some((prev, next)) = data.get_neighbours(control_context) {
+                    self.drag_start =
+                        Some((data.get_position(control_context, position), prev, next));
+                    control_context.invalidate_visual();
+                }
+            }
+
+            ControlEvent::TapMove { ref position } => {
+                if let Some((start_position, prev, next)) = &self.drag_start {
+                    let delta = data.get_position(control_context, position) - start_position;
+                    data.resize(control_context, prev, next, delta);
+                }
+            }
+
+            ControlEvent::TapUp { .. } => {
+                self.drag_start = None;
+                control_context.invalidate_visual();
+            }
+
+            ControlEvent::KeyboardInput(ref key_event) if key_event.state == KeyState::Pressed => {
+                let keycode = key_event
+                    .keycode
+                    .as_ref()
+                    .map(|keycode| FlowDirection::get(control_context).mirror_keycode(keycode));
+                let delta = match (&data.direction, &keycode) {
+                    (GridResizeDirection::Columns, Some(Keycode::Left))
+                    | (GridResizeDirection::Rows, Some(Keycode::Up)) => -data.keyboard_increment,
+                    (GridResizeDirection::Columns, Some(Keycode::Right))
+                    | (GridResizeDirection::Rows, Some(Keycode::Down)) => data.keyboard_increment,
+                    _ => return,
+                };
+                if let Some((prev, next)) = data.get_neighbours(control_context) {
+                    data.resize(control_context, &prev, &next, delta);
+                }
+            }
+
+            ControlEvent::HoverChange(value) => {
+                let cursor = match (value, &data.direction) {
+                    (true, GridResizeDirection::Columns) => CursorShape::SizeHorCursor,
+                    (true, GridResizeDirection::Rows) => CursorShape::SizeVerCursor,
+                    (false, _) => CursorShape::ArrowCursor,
+                };
+
+                // post set_cursor()
+                // (cannot call it directly because services can be already borrowed)
+                if let Some(services) = control_context.get_services().clone() {
+                    spawn_local_and_forget(async move {
+                        let window_service = services.get_window_service();
+                        if let Some(window_service) = window_service {
+                            window_service.set_cursor(cursor);
+                        }
+                    });
+                }
+            }
+
+            _ => (),
+        }
+    }
+
+    fn measure(
+        &mut self,
+        data: &mut GridSplitter,
+        control_context: &ControlContext,
+        _drawing_context: &mut FuiDrawingContext,
+        _size: Size,
+    ) -> Size {
+        let size = Theme::get(control_context).metrics.grid_splitter_size;
+        match data.direction {
+            GridResizeDirection::Columns => Size::new(size, 0.0f32),
+            GridResizeDirection::Rows => Size::new(0.0f32, size),
+        }
+    }
+
+    fn set_rect(
+        &mut self,
+        _data: &mut GridSplitter,
+        _control_context: &ControlContext,
+        _drawing_context: &mut FuiDrawingContext,
+        _rect: Rect,
+    ) {
+    }
+
+    fn hit_test(
+        &self,
+        _data: &GridSplitter,
+        control_context: &ControlContext,
+        point: Point,
+    ) -> Option<Rc<dyn ControlObject>> {
+        if point.is_inside(&control_context.get_rect()) {
+            Some(control_context.get_self_rc())
+        } else {
+            None
+        }
+    }
+
+    fn draw(
+        &mut self,
+        _data: &GridSplitter,
+        control_context: &ControlContext,
+        drawing_context: &mut FuiDrawingContext,
+    ) {
+        let r = control_context.get_rect();
+        let theme = Theme::get(control_context);
+
+        let visual_state = control_context.get_visual_state();
+
+        let background =
+            if visual_state.is_hover || visual_state.is_focused || self.drag_start.is_some() {
+                theme.palette.grid_splitter_active_background
+            } else {
+                theme.palette.grid_splitter_background
+            };
+
+        drawing_context
+            .display
+            .draw_rect(rect(r.x, r.y, r.width, r.height), background);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use fui_core::*;
+
+    use super::{GridResizeDirection, GridSplitter};
+    use crate::test_controls::{layout, run_local, run_pending_tasks, send_event, FixedSize};
+
+    /// Creates a grid with three columns (or rows) and the splitter in the middle one.
+    fn create_grid(
+        direction: GridResizeDirection,
+        resized_lengths: &Property<Vec<(i32, Length)>>,
+    ) -> (Rc<dyn ControlObject>, Rc<dyn ControlObject>) {
+        let mut splitter_attached_values = TypeMap::new();
+        match direction {
+            GridResizeDirection::Columns => splitter_attached_values.insert::<Column>(1),
+            GridResizeDirection::Rows => splitter_attached_values.insert::<Row>(1),
+        };
+        let splitter = GridSplitter::builder()
+            .direction(direction)
+            .build()
+            .to_view(
+                None,
+                ViewContext {
+                    attached_values: splitter_attached_values,
+                    children: Children::empty(),
+                },
+            );
+
+        let mut children: Vec<Rc<dyn ControlObject>> = Vec::new();
+        for index in [0, 2] {
+            let mut attached_values = TypeMap::new();
+            match direction {
+                GridResizeDirection::Columns => attached_values.insert::<Column>(index),
+                GridResizeDirection::Rows => attached_values.insert::<Row>(index),
+            };
+            children.push(FixedSize::new(
+                10.0f32,
+                10.0f32,
+                ViewContext {
+                    attached_values,
+                    children: Children::empty(),
+                },
+            ));
+        }
+        children.insert(1, splitter.clone());
+
+        let grid = match direction {
+            GridResizeDirection::Columns => Grid::builder()
+                .rows(Property::new(1))
+                .columns(Property::new(3))
+                .widths(Property::new(vec![(1, Length::Exact(10.0f32))]))
+                .resized_widths(resized_lengths.clone())
+                .build(),
+            GridResizeDirection::Rows => Grid::builder()
+                .columns(Property::new(1))
+                .rows(Property::new(3))
+                .heights(Property::new(vec![(1, Length::Exact(10.0f32))]))
+                .resized_heights(resized_lengths.clone())
+                .build(),
+        }
+        .to_view(
+            None,
+            ViewContext {
+                attached_values: TypeMap::new(),
+                children: Children::MultipleStatic(children),
+            },
+        );
+
+        (grid, splitter)
+    }
+
+    #[test]
+    fn test_drag_resizes_columns() {
+        run_local(async {
+            let resized_widths = Property::new(Vec::new());
+            let (grid, splitter) = create_grid(GridResizeDirection::Columns, &resized_widths);
+            run_pending_tasks().await;
+            layout(&grid, Size::new(210.0f32, 100.0f32));
+
+            send_event(
+                &splitter,
+                ControlEvent::TapDown {
+                    position: Point::new(105.0f32, 50.0f32),
+                },
+            );
+            send_event(
+                &splitter,
+                ControlEvent::TapMove {
+                    position: Point::new(155.0f32, 50.0f32),
+                },
+            );
+            send_event(
+                &splitter,
+                ControlEvent::TapUp {
+                    position: Point::new(155.0f32, 50.0f32),
+                },
+            );
+
+            // the total weight of the fill columns is kept
+            assert_eq!(
+                resized_widths.get(),
+                vec![(0, Length::Fill(1.5f32)), (2, Length::Fill(0.5f32))]
+            );
+
+            run_pending_tasks().await;
+            layout(&grid, Size::new(210.0f32, 100.0f32));
+            assert_eq!(
+                splitter.get_context().get_rect(),
+                Rect::new(150.0f32, 0.0f32, 10.0f32, 100.0f32)
+            );
+        });
+    }
+
+    #[test]
+    fn test_drag_resizes_rows() {
+        run_local(async {
+            let resized_heights = Property::new(Vec::new());
+            let (grid, splitter) = create_grid(GridResizeDirection::Rows, &resized_heights);
+            run_pending_tasks().await;
+            layout(&grid, Size::new(100.0f32, 210.0f32));
+
+            send_event(
+                &splitter,
+                ControlEvent::TapDown {
+                    position: Point::new(50.0f32, 105.0f32),
+                },
+            );
+            send_event(
+                &splitter,
+                ControlEvent::TapMove {
+                    position: Point::new(50.0f32, 55.0f32),
+                },
+            );
+
+            assert_eq!(
+                resized_heights.get(),
+                vec![(0, Length::Fill(0.5f32)), (2, Length::Fill(1.5f32))]
+            );
+
+            run_pending_tasks().await;
+            layout(&grid, Size::new(100.0f32, 210.0f32));
+            assert_eq!(
+                splitter.get_context().get_rect(),
+                Rect::new(0.0f32, 50.0f32, 100.0f32, 10.0f32)
+            );
+        });
+    }
+}
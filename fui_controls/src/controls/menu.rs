@@ -8,8 +8,7 @@ use std::cell::{Cell, RefCell};
 use std::rc::{Rc, Weak};
 use typed_builder::TypedBuilder;
 
-use crate::style::default_theme::MENU_FOREGROUND;
-use crate::style::Foreground;
+use crate::style::{Foreground, Theme};
 use crate::GestureArea;
 use crate::{controls::*, DataHolder};
 use fui_core::*;
@@ -130,7 +129,14 @@ impl Menu {
             let mut inner_values = TypeMap::new();
             inner_values.insert::<IsInsideMenu>(true);
 
-            menu_impl(ctx.get_children(), menu_data, is_top_level, inner_values)
+            let foreground = Theme::get(ctx).palette.menu_foreground;
+            menu_impl(
+                ctx.get_children(),
+                menu_data,
+                is_top_level,
+                inner_values,
+                foreground,
+            )
         })
     }
 }
@@ -144,8 +150,9 @@ fn menu_impl(
     menu_data: Option<Rc<MenuData>>,
     is_top_level: bool,
     mut attached_values: TypeMap,
+    foreground: [f32; 4],
 ) -> Rc<dyn ControlObject> {
-    attached_values.insert::<Foreground>(Property::new(Color::from(MENU_FOREGROUND)));
+    attached_values.insert::<Foreground>(Property::new(Color::from(foreground)));
 
     // Assign a unique ID if this is a top-level trigger on the bar
     let my_menu_id = if let Some(md) = &menu_data {
@@ -464,6 +471,7 @@ impl MenuItem {
         CompositeControl::new(context, move |ctx: &ControlContext| {
             let menu_data = ctx.get_inherited_value::<ActiveMenu>();
             let activated_callback = activated_callback.clone();
+            let foreground = Theme::get(ctx).palette.menu_foreground;
 
             let children: Vec<_> = ctx.get_children().into_iter().collect();
             let content = children
@@ -492,7 +500,7 @@ impl MenuItem {
 
             ui!(
                 GestureArea {
-                    Foreground: Color::from(MENU_FOREGROUND),
+                    Foreground: Color::from(foreground),
                     hover_change: on_hover_callback,
                     tap_up: on_tap_up_callback,
                     Border {
@@ -3,6 +3,7 @@ pub use border::*;
 pub use busy_indicator::*;
 pub use button::*;
 pub use drop_down::*;
+pub use grid_splitter::*;
 pub use menu::*;
 pub use path_edit::*;
 pub use popup::*;
@@ -15,12 +16,14 @@ pub use tab_control::*;
 pub use text::*;
 pub use text_box::*;
 pub use toggle_button::*;
+pub use virtualizing_stack_panel::*;
 
 mod bitmap;
 mod border;
 mod busy_indicator;
 mod button;
 mod drop_down;
+mod grid_splitter;
 mod menu;
 mod path_edit;
 mod popup;
@@ -33,3 +36,4 @@ mod tab_control;
 mod text;
 mod text_box;
 mod toggle_button;
+mod virtualizing_stack_panel;
@@ -80,10 +80,7 @@ impl Style<ProgressBar> for DefaultProgressBarStyle {
         _drawing_context: &mut FuiDrawingContext,
         _size: Size,
     ) -> Size {
-        let min_size = control_context
-            .get_inherited_value::<ProgressBarMinSize>()
-            .map(|p| p.get())
-            .unwrap_or(default_theme::PROGRESS_BAR_MIN_SIZE);
+        let min_size = ProgressBarMinSize::get(control_context);
         match data.orientation {
             Orientation::Horizontal => Size::new(min_size, 20.0f32),
             Orientation::Vertical => Size::new(20.0f32, min_size),
@@ -124,18 +121,9 @@ impl Style<ProgressBar> for DefaultProgressBarStyle {
         let width = r.width;
         let height = r.height;
 
-        let start_margin = control_context
-            .get_inherited_value::<ProgressBarStartMargin>()
-            .map(|p| p.get())
-            .unwrap_or(default_theme::PROGRESS_BAR_START_MARGIN);
-        let end_margin = control_context
-            .get_inherited_value::<ProgressBarEndMargin>()
-            .map(|p| p.get())
-            .unwrap_or(default_theme::PROGRESS_BAR_END_MARGIN);
-        let side_margin = control_context
-            .get_inherited_value::<ProgressBarSideMargin>()
-            .map(|p| p.get())
-            .unwrap_or(default_theme::PROGRESS_BAR_SIDE_MARGIN);
+        let start_margin = ProgressBarStartMargin::get(control_context);
+        let end_margin = ProgressBarEndMargin::get(control_context);
+        let side_margin = ProgressBarSideMargin::get(control_context);
 
         let progress_bar_size_px = match data.orientation {
             Orientation::Horizontal => width - start_margin - end_margin,
@@ -147,11 +135,13 @@ impl Style<ProgressBar> for DefaultProgressBarStyle {
             / (data.max_value.get() - data.min_value.get()))
         .round();
 
-        let foreground = default_theme::PROGRESS_BAR_FOREGROUND;
-        let background = default_theme::PROGRESS_BAR_BACKGROUND;
+        let theme = Theme::get(control_context);
+        let foreground = ProgressBarForeground::get(control_context);
+        let background = ProgressBarBackground::get(control_context);
 
         default_theme::border_3d_single(
             &mut drawing_context.display,
+            &theme,
             x,
             y,
             width,
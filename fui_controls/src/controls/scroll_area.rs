@@ -12,6 +12,26 @@ pub struct ViewportInfo {
     pub viewport_height: f32,
 }
 
+//
+// Attached values
+//
+
+///
+/// Attached by `ScrollArea` to itself, so the content can find out
+/// which part of it is visible (used by `VirtualizingStackPanel`).
+///
+pub struct ScrollViewport;
+impl TypeMapKey for ScrollViewport {
+    type Value = ScrollViewportProperties;
+}
+
+#[derive(Clone)]
+pub struct ScrollViewportProperties {
+    pub offset_x: Property<f32>,
+    pub offset_y: Property<f32>,
+    pub viewport_info: Property<ViewportInfo>,
+}
+
 #[derive(TypedBuilder)]
 pub struct ScrollArea {
     #[builder(default = Property::new(0.0f32))]
@@ -28,8 +48,16 @@ impl ScrollArea {
     pub fn to_view(
         self,
         style: Option<Box<dyn Style<Self>>>,
-        context: ViewContext,
+        mut context: ViewContext,
     ) -> Rc<dyn ControlObject> {
+        context
+            .attached_values
+            .insert::<ScrollViewport>(ScrollViewportProperties {
+                offset_x: self.offset_x.clone(),
+                offset_y: self.offset_y.clone(),
+                viewport_info: self.viewport_info.clone(),
+            });
+
         StyledControl::new(
             self,
             style.unwrap_or_else(|| {
@@ -79,18 +79,9 @@ impl DefaultScrollBarStyle {
     }
 
     fn calc_sizes(&mut self, data: &ScrollBar, control_context: &ControlContext, rect: Rect) {
-        let start_margin = control_context
-            .get_inherited_value::<ScrollBarStartMargin>()
-            .map(|p| p.get())
-            .unwrap_or(default_theme::SCROLL_BAR_START_MARGIN);
-        let end_margin = control_context
-            .get_inherited_value::<ScrollBarEndMargin>()
-            .map(|p| p.get())
-            .unwrap_or(default_theme::SCROLL_BAR_END_MARGIN);
-        let min_thumb_size = control_context
-            .get_inherited_value::<ScrollBarMinThumbSize>()
-            .map(|p| p.get())
-            .unwrap_or(default_theme::SCROLL_BAR_MIN_THUMB_SIZE);
+        let start_margin = ScrollBarStartMargin::get(control_context);
+        let end_margin = ScrollBarEndMargin::get(control_context);
+        let min_thumb_size = ScrollBarMinThumbSize::get(control_context);
 
         let scroll_bar_size_px = match data.orientation {
             Orientation::Horizontal => rect.width - start_margin - end_margin,
@@ -99,15 +90,25 @@ impl DefaultScrollBarStyle {
         let scroll_bar_size_f32 =
             data.max_value.get() - data.min_value.get() + data.viewport_size.get();
 
-        self.thumb_size_px = ((data.viewport_size.get() * scroll_bar_size_px)
-            / scroll_bar_size_f32)
-            .round()
-            .max(min_thumb_size);
+        // the thumb is snapped to the physical pixels like the rects of the controls
+        let use_layout_rounding = UseLayoutRounding::get(control_context);
+        let dpi_scale = DpiScale::get(control_context);
+        let round = |value: f32| {
+            if use_layout_rounding {
+                round_layout_value(value, dpi_scale)
+            } else {
+                value
+            }
+        };
+
+        self.thumb_size_px =
+            round((data.viewport_size.get() * scroll_bar_size_px) / scroll_bar_size_f32)
+                .max(min_thumb_size);
 
-        self.thumb_pos_px = ((scroll_bar_size_px - self.thumb_size_px)
-            * (data.value.get() - data.min_value.get())
-            / (data.max_value.get() - data.min_value.get()))
-        .round();
+        self.thumb_pos_px = round(
+            (scroll_bar_size_px - self.thumb_size_px) * (data.value.get() - data.min_value.get())
+                / (data.max_value.get() - data.min_value.get()),
+        );
     }
 }
 
@@ -132,10 +133,7 @@ impl Style<ScrollBar> for DefaultScrollBarStyle {
         match event {
             ControlEvent::TapDown { position } => {
                 let rect = control_context.get_rect();
-                let start_margin = control_context
-                    .get_inherited_value::<ScrollBarStartMargin>()
-                    .map(|p| p.get())
-                    .unwrap_or(default_theme::SCROLL_BAR_START_MARGIN);
+                let start_margin = ScrollBarStartMargin::get(control_context);
                 let pos = match data.orientation {
                     Orientation::Horizontal => position.x - rect.x - start_margin,
                     Orientation::Vertical => position.y - rect.y - start_margin,
@@ -153,14 +151,8 @@ impl Style<ScrollBar> for DefaultScrollBarStyle {
             ControlEvent::TapMove { ref position } => {
                 if self.is_thumb_pressed.get() {
                     let rect = control_context.get_rect();
-                    let start_margin = control_context
-                        .get_inherited_value::<ScrollBarStartMargin>()
-                        .map(|p| p.get())
-                        .unwrap_or(default_theme::SCROLL_BAR_START_MARGIN);
-                    let end_margin = control_context
-                        .get_inherited_value::<ScrollBarEndMargin>()
-                        .map(|p| p.get())
-                        .unwrap_or(default_theme::SCROLL_BAR_END_MARGIN);
+                    let start_margin = ScrollBarStartMargin::get(control_context);
+                    let end_margin = ScrollBarEndMargin::get(control_context);
 
                     let scroll_bar_size_px = match data.orientation {
                         Orientation::Horizontal => rect.width - start_margin - end_margin,
@@ -223,10 +215,7 @@ impl Style<ScrollBar> for DefaultScrollBarStyle {
         _drawing_context: &mut FuiDrawingContext,
         size: Size,
     ) -> Size {
-        let min_thumb_size = control_context
-            .get_inherited_value::<ScrollBarMinThumbSize>()
-            .map(|p| p.get())
-            .unwrap_or(default_theme::SCROLL_BAR_MIN_THUMB_SIZE);
+        let min_thumb_size = ScrollBarMinThumbSize::get(control_context);
         let min_size = min_thumb_size * 2.0;
 
         match data.orientation {
@@ -284,25 +273,17 @@ impl Style<ScrollBar> for DefaultScrollBarStyle {
         let width = r.width;
         let height = r.height;
 
-        let start_margin = control_context
-            .get_inherited_value::<ScrollBarStartMargin>()
-            .map(|p| p.get())
-            .unwrap_or(default_theme::SCROLL_BAR_START_MARGIN);
-        let end_margin = control_context
-            .get_inherited_value::<ScrollBarEndMargin>()
-            .map(|p| p.get())
-            .unwrap_or(default_theme::SCROLL_BAR_END_MARGIN);
-        let side_margin = control_context
-            .get_inherited_value::<ScrollBarSideMargin>()
-            .map(|p| p.get())
-            .unwrap_or(default_theme::SCROLL_BAR_SIDE_MARGIN);
+        let start_margin = ScrollBarStartMargin::get(control_context);
+        let end_margin = ScrollBarEndMargin::get(control_context);
+        let side_margin = ScrollBarSideMargin::get(control_context);
 
         let scroll_bar_size_px = match data.orientation {
             Orientation::Horizontal => width - start_margin - end_margin,
             Orientation::Vertical => height - start_margin - end_margin,
         };
 
-        let background = default_theme::SCROLL_BAR_BACKGROUND;
+        let theme = Theme::get(control_context);
+        let background = ScrollBarBackground::get(control_context);
 
         if self.thumb_pos_px > 0.0f32 {
             drawing_context.display.draw_rect(
@@ -320,13 +301,14 @@ impl Style<ScrollBar> for DefaultScrollBarStyle {
                         self.thumb_pos_px,
                     ),
                 },
-                background,
+                background.clone(),
             );
         }
 
         match data.orientation {
             Orientation::Horizontal => default_theme::button(
                 &mut drawing_context.display,
+                &theme,
                 x + self.thumb_pos_px + start_margin,
                 y + side_margin,
                 self.thumb_size_px,
@@ -337,6 +319,7 @@ impl Style<ScrollBar> for DefaultScrollBarStyle {
             ),
             Orientation::Vertical => default_theme::button(
                 &mut drawing_context.display,
+                &theme,
                 x + side_margin,
                 y + self.thumb_pos_px + start_margin,
                 width - side_margin - side_margin,
@@ -369,6 +352,7 @@ impl Style<ScrollBar> for DefaultScrollBarStyle {
 
         default_theme::border_3d_single(
             &mut drawing_context.display,
+            &theme,
             x,
             y,
             width,
@@ -36,42 +36,66 @@ impl TabControl {
         _style: Option<Box<dyn Style<Self>>>,
         context: ViewContext,
     ) -> Rc<dyn ControlObject> {
-        let tabs_source = &context.children as &dyn ObservableCollection<Rc<dyn ControlObject>>;
-        let selected_tab = Property::new(tabs_source.get(0).unwrap());
-
-        let selected_tab_clone = selected_tab.clone();
-        let tab_button_vms =
-            tabs_source.map(move |c| TabButtonViewModel::new(&c, &selected_tab_clone));
-        let tab_button_vms_controls = (&tab_button_vms
-            as &dyn ObservableCollection<Rc<TabButtonViewModel>>)
-            .map(|vm| vm.create_view());
-
-        let content = ui! {
-            Grid {
-                columns: 1,
-                heights: vec![(0, Length::Auto), (1, Length::Fill(1.0f32))],
-
-                Horizontal {
-                    &tab_button_vms_controls,
-                },
-
-                Shadow {
-                    Border {
-                        border_type: BorderType::Raisen,
-                        Style: Default { background_color: Color::rgba(1.0, 1.0, 1.0, 0.05), },
-
-                        &selected_tab,
+        // built when attached, so the colors can follow the theme
+        let content = CompositeControl::new(
+            ViewContext {
+                attached_values: TypeMap::new(),
+                children: context.children,
+            },
+            move |ctx: &ControlContext| {
+                let theme = Theme::get_property(ctx);
+                let tabs_source =
+                    ctx.get_children() as &dyn ObservableCollection<Rc<dyn ControlObject>>;
+                let selected_tab = Property::new(tabs_source.get(0).unwrap());
+
+                let selected_tab_clone = selected_tab.clone();
+                let theme_clone = theme.clone();
+                let tab_button_vms = tabs_source
+                    .map(move |c| TabButtonViewModel::new(&c, &selected_tab_clone, &theme_clone));
+                let tab_button_vms_controls = (&tab_button_vms
+                    as &dyn ObservableCollection<Rc<TabButtonViewModel>>)
+                    .map(|vm| vm.create_view());
+
+                let background_color =
+                    theme.map_value(|theme| Color::from(theme.palette.tab_background));
+
+                let content = ui! {
+                    Grid {
+                        columns: 1,
+                        heights: vec![(0, Length::Auto), (1, Length::Fill(1.0f32))],
+
+                        Horizontal {
+                            &tab_button_vms_controls,
+                        },
+
+                        Shadow {
+                            Border {
+                                border_type: BorderType::Raisen,
+                                Style: Default { background_color: background_color, },
+
+                                &selected_tab,
+                            }
+                        }
                     }
-                }
-            }
-        };
-
-        let radio_controller =
-            RadioController::<StyledControl<ToggleButton>>::new(tab_button_vms_controls);
+                };
+
+                let radio_controller =
+                    RadioController::<StyledControl<ToggleButton>>::new(tab_button_vms_controls);
+
+                let data_holder = DataHolder {
+                    data: (selected_tab, radio_controller, tab_button_vms),
+                };
+                data_holder.to_view(
+                    None,
+                    ViewContext {
+                        attached_values: TypeMap::new(),
+                        children: Children::SingleStatic(content),
+                    },
+                )
+            },
+        );
 
-        let data_holder = DataHolder {
-            data: (selected_tab, radio_controller, tab_button_vms),
-        };
+        let data_holder = DataHolder { data: () };
         data_holder.to_view(
             None,
             ViewContext {
@@ -87,6 +111,7 @@ struct TabButtonViewModel {
     pub is_checked: Property<bool>,
     pub content: Rc<dyn ControlObject>,
     pub selected_tab: Property<Rc<dyn ControlObject>>,
+    pub theme: Property<Rc<Theme>>,
     pub event_subscription: Cell<Option<Subscription>>,
 }
 
@@ -94,6 +119,7 @@ impl TabButtonViewModel {
     pub fn new(
         content: &Rc<dyn ControlObject>,
         selected_tab: &Property<Rc<dyn ControlObject>>,
+        theme: &Property<Rc<Theme>>,
     ) -> Rc<Self> {
         let title = content
             .get_context()
@@ -106,6 +132,7 @@ impl TabButtonViewModel {
             is_checked: Property::new(false),
             content: content.clone(),
             selected_tab: selected_tab.clone(),
+            theme: theme.clone(),
             event_subscription: Cell::new(None),
         });
 
@@ -135,10 +162,15 @@ impl ViewModel for TabButtonViewModel {
                 is_checked: self.is_checked.clone(),
 
                 Text {
-                    Foreground: Property::new(if self.is_checked.get() {
-                        Color::rgba(1.0, 0.8, 0.0, 1.0)
-                    } else {
-                        Color::rgba(1.0, 1.0, 1.0, 1.0)
+                    Foreground: self.theme.map_value({
+                        let is_checked = self.is_checked.get();
+                        move |theme| {
+                            Color::from(if is_checked {
+                                theme.palette.tab_selected_foreground
+                            } else {
+                                theme.palette.tab_foreground
+                            })
+                        }
                     }),
 
                     text: &self.title
@@ -4,7 +4,6 @@ use fui_core::*;
 use fui_drawing::prelude::*;
 use typed_builder::TypedBuilder;
 
-use crate::style::default_theme;
 use crate::style::{FontFamily, FontSize, Foreground};
 
 #[derive(TypedBuilder)]
@@ -63,20 +62,11 @@ impl Style<Text> for DefaultTextStyle {
         _size: Size,
     ) -> Size {
         // Get values from attached inherited values, fall back to defaults
-        let font_family = control_context
-            .get_inherited_value::<FontFamily>()
-            .map(|p| p.get())
-            .unwrap_or_else(|| default_theme::DEFAULT_FONT_FAMILY.to_string());
-
-        let font_size = control_context
-            .get_inherited_value::<FontSize>()
-            .map(|p| p.get())
-            .unwrap_or(default_theme::DEFAULT_FONT_SIZE.into());
-
-        let foreground = control_context
-            .get_inherited_value::<Foreground>()
-            .map(|p| p.get())
-            .unwrap_or(default_theme::DEFAULT_FOREGROUND.into());
+        let font_family = FontFamily::get(control_context);
+
+        let font_size = FontSize::get(control_context);
+
+        let foreground = Foreground::get(control_context);
 
         let mut builder = DrawingParagraphBuilder::new(drawing_context.fonts).unwrap();
         builder.push_style(ParagraphStyle::simple(&font_family, font_size, &foreground));
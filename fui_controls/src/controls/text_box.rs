@@ -7,11 +7,16 @@ use fui_drawing::prelude::*;
 use typed_builder::TypedBuilder;
 
 use crate::style::default_theme;
-use crate::style::{FontFamily, FontSize, Foreground};
+use crate::style::{FontFamily, FontSize, Foreground, Theme};
 
 #[derive(TypedBuilder)]
 pub struct TextBox {
     pub text: Property<String>,
+
+    /// Shared undo manager (the text property has to be tracked by it).
+    /// If not set, the text box has its own undo history.
+    #[builder(default = None)]
+    pub undo_manager: Option<UndoManager>,
 }
 
 impl TextBox {
@@ -47,8 +52,10 @@ pub struct DefaultTextBoxStyle {
 
     buffer: Option<TextBuffer>,
 
-    is_hover: bool,
-    is_focused: bool,
+    direction: LayoutDirection,
+
+    // rect of the text (without the padding)
+    content_rect: Rect,
 
     cursor_pos_px: f32,
     offset_x: f32,
@@ -64,8 +71,9 @@ impl DefaultTextBoxStyle {
             params: _params,
             buffer: None,
 
-            is_hover: false,
-            is_focused: false,
+            direction: LayoutDirection::LeftToRight,
+
+            content_rect: Rect::empty(),
 
             cursor_pos_px: 0.0f32,
             offset_x: 0.0f32,
@@ -101,27 +109,21 @@ impl DefaultTextBoxStyle {
         self.selection_start_px = self.calc_px_from_char(self.buf().get_selection_start());
 
         // scroll to cursor if needed
-        self.update_offset_x(rect);
+        self.update_offset_x(rect, control_context.get_visual_state().is_focused);
     }
 
     fn update_paragraph(&mut self, fonts: &DrawingFonts, control_context: &ControlContext) {
         let display_text = self.get_display_text();
 
         // Get values from inherited attached values, fall back to defaults
-        let font_family = control_context
-            .get_inherited_value::<FontFamily>()
-            .map(|p| p.get())
-            .unwrap_or_else(|| default_theme::DEFAULT_FONT_FAMILY.to_string());
+        let font_family = FontFamily::get(control_context);
 
-        let font_size = control_context
-            .get_inherited_value::<FontSize>()
-            .map(|p| p.get())
-            .unwrap_or(default_theme::DEFAULT_FONT_SIZE.into());
+        let font_size = FontSize::get(control_context);
 
         let foreground = control_context
             .get_inherited_value::<Foreground>()
             .map(|p| p.get())
-            .unwrap_or(default_theme::DEFAULT_EDIT_TEXT_COLOR.into());
+            .unwrap_or_else(|| Theme::get(control_context).palette.edit_text_color.into());
 
         let mut builder = DrawingParagraphBuilder::new(fonts).unwrap();
         builder.push_style(ParagraphStyle::simple(&font_family, font_size, &foreground));
@@ -129,18 +131,33 @@ impl DefaultTextBoxStyle {
         self.paragraph = Some(builder.build(f32::INFINITY).unwrap());
     }
 
-    fn update_offset_x(&mut self, rect: Rect) {
-        if self.is_focused {
+    fn update_offset_x(&mut self, rect: Rect, is_focused: bool) {
+        if is_focused {
             if self.cursor_pos_px < self.offset_x {
                 self.offset_x = self.cursor_pos_px;
-            } else if self.cursor_pos_px > self.offset_x + rect.width - 8.0f32 {
-                self.offset_x = self.cursor_pos_px - rect.width + 8.0f32 + 2.0f32;
+            } else if self.cursor_pos_px > self.offset_x + rect.width {
+                self.offset_x = self.cursor_pos_px - rect.width + 2.0f32;
             }
         } else {
             self.offset_x = 0.0f32;
         }
     }
 
+    /// Returns the x position of the text start (without the scroll offset).
+    /// The text is aligned to the right for the right-to-left direction.
+    fn get_text_x(&self, rect: Rect) -> f32 {
+        let x = rect.x;
+        if self.direction.is_right_to_left() {
+            if let Some(paragraph) = &self.paragraph {
+                let space_left = rect.width - paragraph.get_longest_line_width();
+                if space_left > 0.0f32 {
+                    return x + space_left;
+                }
+            }
+        }
+        x
+    }
+
     fn calc_px_from_char(&self, char_idx: usize) -> f32 {
         if char_idx == 0 {
             return 0.0;
@@ -176,7 +193,7 @@ impl DefaultTextBoxStyle {
 
         let paragraph = self.paragraph.as_ref().unwrap();
 
-        let pos = (pos.x - rect.x - 4.0f32) + self.offset_x;
+        let pos = (pos.x - self.get_text_x(rect)) + self.offset_x;
         let glyph_info = paragraph.create_glyph_info_at_paragraph_coordinates(pos as f64, 0.0);
         if let Some(glyph_info) = glyph_info {
             let rect = glyph_info.get_grapheme_cluster_bounds();
@@ -244,8 +261,14 @@ impl DefaultTextBoxStyle {
 
 impl Style<TextBox> for DefaultTextBoxStyle {
     fn setup(&mut self, data: &mut TextBox, control_context: &ControlContext) {
-        self.buffer = Some(TextBuffer::new(data.text.clone()));
+        self.buffer = Some(match &data.undo_manager {
+            Some(undo_manager) => {
+                TextBuffer::with_undo_manager(data.text.clone(), undo_manager.clone())
+            }
+            None => TextBuffer::new(data.text.clone()),
+        });
         control_context.dirty_watch_property(&data.text);
+        control_context.set_default_attached_value::<Padding>(Thickness::all(4.0f32));
     }
 
     fn handle_event(
@@ -260,26 +283,17 @@ impl Style<TextBox> for DefaultTextBoxStyle {
             return;
         }
 
+        // the visual state (hover, focused) is tracked by the control context
         match event {
-            ControlEvent::FocusChange(value) => {
-                self.is_focused = value;
-                control_context.set_is_dirty(true);
-            }
-
-            ControlEvent::HoverChange(value) => {
-                self.is_hover = value;
-                control_context.set_is_dirty(true);
-            }
-
             ControlEvent::TapDown { ref position } => {
-                let rect = control_context.get_rect();
+                let rect = self.content_rect;
                 let cursor_pos =
                     self.calc_cursor_pos(position, rect, drawing_context.fonts, control_context);
 
                 let shift_pressed = false;
                 self.buf_mut().set_cursor(cursor_pos, shift_pressed);
                 self.sync_with_buffer(drawing_context.fonts, rect, control_context);
-                control_context.set_is_dirty(true);
+                control_context.invalidate_visual();
             }
 
             ControlEvent::KeyboardInput(ref key_event) if key_event.state == KeyState::Pressed => {
@@ -289,7 +303,8 @@ impl Style<TextBox> for DefaultTextBoxStyle {
                 let mut handled = false;
 
                 if let Some(ref key_code) = key_event.keycode {
-                    match key_code {
+                    // arrow keys move the cursor visually
+                    match &self.direction.mirror_keycode(key_code) {
                         // Edition
                         Keycode::Backspace => {
                             self.buf_mut().backspace();
@@ -366,6 +381,20 @@ impl Style<TextBox> for DefaultTextBoxStyle {
                             handled = true;
                         }
 
+                        // Undo / redo
+                        Keycode::KeyZ if ctrl && !shift => {
+                            changed = self.buf_mut().undo();
+                            handled = true;
+                        }
+                        Keycode::KeyZ if ctrl && shift => {
+                            changed = self.buf_mut().redo();
+                            handled = true;
+                        }
+                        Keycode::KeyY if ctrl => {
+                            changed = self.buf_mut().redo();
+                            handled = true;
+                        }
+
                         // Ignored keys
                         Keycode::Esc | Keycode::Tab | Keycode::Enter => {
                             handled = true;
@@ -384,7 +413,7 @@ impl Style<TextBox> for DefaultTextBoxStyle {
                 if changed {
                     self.sync_with_buffer(
                         drawing_context.fonts,
-                        control_context.get_rect(),
+                        self.content_rect,
                         control_context,
                     );
                     control_context.set_is_dirty(true);
@@ -407,22 +436,24 @@ impl Style<TextBox> for DefaultTextBoxStyle {
         let paragraph_height = paragraph.get_height();
 
         let width = if size.width.is_infinite() {
-            8.0f32 + 8.0f32
+            8.0f32
         } else {
-            size.width.max(8.0f32 + 8.0f32)
+            size.width.max(8.0f32)
         };
 
-        Size::new(width, paragraph_height + 8.0f32)
+        Size::new(width, paragraph_height)
     }
 
     fn set_rect(
         &mut self,
         _data: &mut TextBox,
-        _control_context: &ControlContext,
+        control_context: &ControlContext,
         _drawing_context: &mut FuiDrawingContext,
         rect: Rect,
     ) {
-        self.update_offset_x(rect);
+        self.direction = FlowDirection::get(control_context);
+        self.content_rect = rect;
+        self.update_offset_x(rect, control_context.get_visual_state().is_focused);
     }
 
     fn hit_test(
@@ -446,6 +477,8 @@ impl Style<TextBox> for DefaultTextBoxStyle {
     ) {
         if let Some(paragraph) = &self.paragraph {
             let r = control_context.get_rect();
+            let theme = Theme::get(control_context);
+            let visual_state = control_context.get_visual_state();
             let x = r.x;
             let y = r.y;
             let width = r.width;
@@ -453,15 +486,19 @@ impl Style<TextBox> for DefaultTextBoxStyle {
 
             let text_width = paragraph.get_longest_line_width();
             let text_height = paragraph.get_height();
+            let content_rect = self.content_rect;
+            let text_x = self.get_text_x(content_rect);
+            let text_y = content_rect.y + (content_rect.height - text_height as f32) / 2.0;
 
             default_theme::border_3d_edit(
                 &mut drawing_context.display,
+                &theme,
                 x,
                 y,
                 width,
                 height,
-                self.is_hover,
-                self.is_focused,
+                visual_state.is_hover,
+                visual_state.is_focused,
             );
 
             gradient_rect(
@@ -470,29 +507,35 @@ impl Style<TextBox> for DefaultTextBoxStyle {
                 y + 3.0f32,
                 width - 6.0f32,
                 height - 6.0f32,
-                if self.is_focused {
+                if visual_state.is_focused {
                     Color::rgba(1.0, 1.0, 1.0, 0.75)
-                } else if self.is_hover {
+                } else if visual_state.is_hover {
                     Color::rgba(1.0, 1.0, 1.0, 0.675)
                 } else {
                     Color::rgba(1.0, 1.0, 1.0, 0.6)
                 },
-                if self.is_focused {
+                if visual_state.is_focused {
                     Color::rgba(0.9, 0.9, 0.9, 0.75)
-                } else if self.is_hover {
+                } else if visual_state.is_hover {
                     Color::rgba(0.9, 0.9, 0.9, 0.675)
                 } else {
                     Color::rgba(0.9, 0.9, 0.9, 0.6)
                 },
             );
 
-            let clip =
-                text_width > width - 8.0 || text_height > height - 8.0 || self.offset_x != 0.0f32;
+            let clip = text_width > content_rect.width
+                || text_height > content_rect.height
+                || self.offset_x != 0.0f32;
 
             if clip {
                 drawing_context.display.save();
                 drawing_context.display.clip_rect(
-                    rect(x + 4.0f32, y + 4.0f32, width - 8.0f32, height - 8.0f32),
+                    rect(
+                        content_rect.x,
+                        content_rect.y,
+                        content_rect.width,
+                        content_rect.height,
+                    ),
                     ClipOperation::Intersect,
                 );
                 if self.offset_x != 0.0f32 {
@@ -510,28 +553,22 @@ impl Style<TextBox> for DefaultTextBoxStyle {
                 let rect_width = (s_px - c_px).abs();
 
                 drawing_context.display.draw_rect(
-                    rect(
-                        x + 4.0f32 + rect_x,
-                        y + (height - text_height as f32) / 2.0,
-                        rect_width,
-                        text_height,
-                    ),
+                    rect(text_x + rect_x, text_y, rect_width, text_height),
                     Color::rgba(0.0, 0.47, 0.83, 0.35),
                 );
             }
 
             // draw text
-            drawing_context.display.draw_paragraph(
-                (x + 4.0f32, y + (height - text_height as f32) / 2.0),
-                &paragraph,
-            );
+            drawing_context
+                .display
+                .draw_paragraph((text_x, text_y), &paragraph);
 
             // draw cursor
-            if self.is_focused {
+            if visual_state.is_focused {
                 drawing_context.display.draw_rect(
                     rect(
-                        x + 4.0f32 + self.cursor_pos_px,
-                        y + (height - text_height as f32) / 2.0,
+                        text_x + self.cursor_pos_px,
+                        text_y,
                         2.0f32,
                         text_height as f32,
                     ),
@@ -37,28 +37,18 @@ impl ToggleButton {
 #[derive(TypedBuilder)]
 pub struct DefaultToggleButtonStyleParams {}
 
-pub struct DefaultToggleButtonStyle {
-    is_tapped: Property<bool>,
-    is_hover: Property<bool>,
-    is_focused: Property<bool>,
-}
+pub struct DefaultToggleButtonStyle {}
 
 impl DefaultToggleButtonStyle {
     pub fn new(_params: DefaultToggleButtonStyleParams) -> Self {
-        DefaultToggleButtonStyle {
-            is_tapped: Property::new(false),
-            is_hover: Property::new(false),
-            is_focused: Property::new(false),
-        }
+        DefaultToggleButtonStyle {}
     }
 }
 
 impl Style<ToggleButton> for DefaultToggleButtonStyle {
     fn setup(&mut self, data: &mut ToggleButton, control_context: &ControlContext) {
+        control_context.set_default_attached_value::<Padding>(Thickness::all(10.0f32));
         control_context.dirty_watch_property(&data.is_checked);
-        control_context.dirty_watch_property(&self.is_tapped);
-        control_context.dirty_watch_property(&self.is_hover);
-        control_context.dirty_watch_property(&self.is_focused);
     }
 
     fn handle_event(
@@ -69,46 +59,13 @@ impl Style<ToggleButton> for DefaultToggleButtonStyle {
         _event_context: &mut dyn EventContext,
         event: ControlEvent,
     ) {
-        match event {
-            ControlEvent::TapDown { .. } => {
-                self.is_tapped.set(true);
-            }
-
-            ControlEvent::TapUp { ref position } => {
-                if let Some(hit_control) = self.hit_test(&data, &control_context, *position) {
-                    if Rc::ptr_eq(&hit_control, &control_context.get_self_rc()) {
-                        data.is_checked.change(|val| !val);
-                    } else {
-                        self.is_tapped.set(false);
-                    }
-                }
-                self.is_tapped.set(false);
-            }
-
-            ControlEvent::TapMove { ref position } => {
-                match self.hit_test(&data, &control_context, *position) {
-                    Some(hit_control) => {
-                        if Rc::ptr_eq(&hit_control, &control_context.get_self_rc()) {
-                            self.is_tapped.set(true);
-                        } else {
-                            self.is_tapped.set(false);
-                        }
-                    }
-                    _ => {
-                        self.is_tapped.set(false);
-                    }
+        // the visual state (hover, pressed, focused) is tracked by the control context
+        if let ControlEvent::TapUp { ref position } = event {
+            if let Some(hit_control) = self.hit_test(&data, &control_context, *position) {
+                if Rc::ptr_eq(&hit_control, &control_context.get_self_rc()) {
+                    data.is_checked.change(|val| !val);
                 }
             }
-
-            ControlEvent::HoverChange(value) => {
-                self.is_hover.set(value);
-            }
-
-            ControlEvent::FocusChange(value) => {
-                self.is_focused.set(value);
-            }
-
-            _ => (),
         }
     }
 
@@ -120,16 +77,14 @@ impl Style<ToggleButton> for DefaultToggleButtonStyle {
         size: Size,
     ) -> Size {
         let children = control_context.get_children();
-        let content_size = match children.into_iter().next() {
+        match children.into_iter().next() {
             Some(ref content) => {
                 content.measure(drawing_context, size);
                 let rect = content.get_rect();
                 Size::new(rect.width, rect.height)
             }
             _ => Size::new(0f32, 0f32),
-        };
-
-        Size::new(content_size.width + 20.0f32, content_size.height + 20.0f32)
+        }
     }
 
     fn set_rect(
@@ -139,16 +94,9 @@ impl Style<ToggleButton> for DefaultToggleButtonStyle {
         drawing_context: &mut FuiDrawingContext,
         rect: Rect,
     ) {
-        let content_rect = Rect::new(
-            rect.x + 10.0f32,
-            rect.y + 10.0f32,
-            rect.width - 20.0f32,
-            rect.height - 20.0f32,
-        );
-
         let children = control_context.get_children();
         if let Some(ref content) = children.into_iter().next() {
-            content.set_rect(drawing_context, content_rect);
+            content.set_rect(drawing_context, rect);
         }
     }
 
@@ -172,12 +120,14 @@ impl Style<ToggleButton> for DefaultToggleButtonStyle {
         drawing_context: &mut FuiDrawingContext,
     ) {
         let rect = control_context.get_rect();
+        let theme = Theme::get(control_context);
+        let visual_state = control_context.get_visual_state();
         let x = rect.x;
         let y = rect.y;
         let width = rect.width;
         let height = rect.height;
 
-        let is_pressed = if self.is_tapped.get() {
+        let is_pressed = if visual_state.is_pressed {
             !data.is_checked.get()
         } else {
             data.is_checked.get()
@@ -185,13 +135,14 @@ impl Style<ToggleButton> for DefaultToggleButtonStyle {
 
         default_theme::button(
             &mut drawing_context.display,
+            &theme,
             x,
             y,
             width,
             height,
             is_pressed,
-            self.is_hover.get(),
-            self.is_focused.get(),
+            visual_state.is_hover,
+            visual_state.is_focused,
         );
 
         let children = control_context.get_children();
@@ -217,28 +168,17 @@ impl Style<ToggleButton> for DefaultToggleButtonStyle {
 #[derive(TypedBuilder)]
 pub struct CheckBoxToggleButtonStyleParams {}
 
-pub struct CheckBoxToggleButtonStyle {
-    is_tapped: Property<bool>,
-    is_hover: Property<bool>,
-    is_focused: Property<bool>,
-}
+pub struct CheckBoxToggleButtonStyle {}
 
 impl CheckBoxToggleButtonStyle {
     pub fn new(_params: CheckBoxToggleButtonStyleParams) -> Self {
-        CheckBoxToggleButtonStyle {
-            is_tapped: Property::new(false),
-            is_hover: Property::new(false),
-            is_focused: Property::new(false),
-        }
+        CheckBoxToggleButtonStyle {}
     }
 }
 
 impl Style<ToggleButton> for CheckBoxToggleButtonStyle {
     fn setup(&mut self, data: &mut ToggleButton, control_context: &ControlContext) {
         control_context.dirty_watch_property(&data.is_checked);
-        control_context.dirty_watch_property(&self.is_tapped);
-        control_context.dirty_watch_property(&self.is_hover);
-        control_context.dirty_watch_property(&self.is_focused);
     }
 
     fn handle_event(
@@ -249,46 +189,13 @@ impl Style<ToggleButton> for CheckBoxToggleButtonStyle {
         _event_context: &mut dyn EventContext,
         event: ControlEvent,
     ) {
-        match event {
-            ControlEvent::TapDown { .. } => {
-                self.is_tapped.set(true);
-            }
-
-            ControlEvent::TapUp { ref position } => {
-                if let Some(hit_control) = self.hit_test(&data, &control_context, *position) {
-                    if Rc::ptr_eq(&hit_control, &control_context.get_self_rc()) {
-                        data.is_checked.change(|val| !val);
-                    } else {
-                        self.is_tapped.set(false);
-                    }
-                }
-                self.is_tapped.set(false);
-            }
-
-            ControlEvent::TapMove { ref position } => {
-                match self.hit_test(&data, &control_context, *position) {
-                    Some(hit_control) => {
-                        if Rc::ptr_eq(&hit_control, &control_context.get_self_rc()) {
-                            self.is_tapped.set(true);
-                        } else {
-                            self.is_tapped.set(false);
-                        }
-                    }
-                    _ => {
-                        self.is_tapped.set(false);
-                    }
+        // the visual state (hover, pressed, focused) is tracked by the control context
+        if let ControlEvent::TapUp { ref position } = event {
+            if let Some(hit_control) = self.hit_test(&data, &control_context, *position) {
+                if Rc::ptr_eq(&hit_control, &control_context.get_self_rc()) {
+                    data.is_checked.change(|val| !val);
                 }
             }
-
-            ControlEvent::HoverChange(value) => {
-                self.is_hover.set(value);
-            }
-
-            ControlEvent::FocusChange(value) => {
-                self.is_focused.set(value);
-            }
-
-            _ => (),
         }
     }
 
@@ -299,14 +206,8 @@ impl Style<ToggleButton> for CheckBoxToggleButtonStyle {
         drawing_context: &mut FuiDrawingContext,
         size: Size,
     ) -> Size {
-        let check_box_button_size = control_context
-            .get_inherited_value::<CheckBoxButtonSize>()
-            .map(|p| p.get())
-            .unwrap_or(default_theme::CHECK_BOX_BUTTON_SIZE);
-        let check_box_margin = control_context
-            .get_inherited_value::<CheckBoxMargin>()
-            .map(|p| p.get())
-            .unwrap_or(default_theme::CHECK_BOX_MARGIN);
+        let check_box_button_size = CheckBoxButtonSize::get(control_context);
+        let check_box_margin = CheckBoxMargin::get(control_context);
 
         let children = control_context.get_children();
         let content_size = match children.into_iter().next() {
@@ -343,14 +244,8 @@ impl Style<ToggleButton> for CheckBoxToggleButtonStyle {
         drawing_context: &mut FuiDrawingContext,
         rect: Rect,
     ) {
-        let check_box_button_size = control_context
-            .get_inherited_value::<CheckBoxButtonSize>()
-            .map(|p| p.get())
-            .unwrap_or(default_theme::CHECK_BOX_BUTTON_SIZE);
-        let check_box_margin = control_context
-            .get_inherited_value::<CheckBoxMargin>()
-            .map(|p| p.get())
-            .unwrap_or(default_theme::CHECK_BOX_MARGIN);
+        let check_box_button_size = CheckBoxButtonSize::get(control_context);
+        let check_box_margin = CheckBoxMargin::get(control_context);
 
         let content_rect = Rect::new(
             rect.x + check_box_button_size + check_box_margin,
@@ -385,16 +280,15 @@ impl Style<ToggleButton> for CheckBoxToggleButtonStyle {
         drawing_context: &mut FuiDrawingContext,
     ) {
         let rect = control_context.get_rect();
+        let theme = Theme::get(control_context);
+        let visual_state = control_context.get_visual_state();
         let x = rect.x;
         let y = rect.y;
         let height = rect.height;
 
-        let check_box_button_size = control_context
-            .get_inherited_value::<CheckBoxButtonSize>()
-            .map(|p| p.get())
-            .unwrap_or(default_theme::CHECK_BOX_BUTTON_SIZE);
+        let check_box_button_size = CheckBoxButtonSize::get(control_context);
 
-        let is_pressed = if self.is_tapped.get() {
+        let is_pressed = if visual_state.is_pressed {
             true
         } else {
             data.is_checked.get()
@@ -402,14 +296,15 @@ impl Style<ToggleButton> for CheckBoxToggleButtonStyle {
 
         default_theme::button_rounded(
             &mut drawing_context.display,
+            &theme,
             x,
             y,
             check_box_button_size,
             height,
             3.0f32,
             is_pressed,
-            self.is_hover.get(),
-            self.is_focused.get(),
+            visual_state.is_hover,
+            visual_state.is_focused,
         );
 
         if is_pressed {
@@ -456,28 +351,18 @@ impl Style<ToggleButton> for CheckBoxToggleButtonStyle {
 #[derive(TypedBuilder)]
 pub struct TabToggleButtonStyleParams {}
 
-pub struct TabToggleButtonStyle {
-    is_tapped: Property<bool>,
-    is_hover: Property<bool>,
-    is_focused: Property<bool>,
-}
+pub struct TabToggleButtonStyle {}
 
 impl TabToggleButtonStyle {
     pub fn new(_params: TabToggleButtonStyleParams) -> Self {
-        TabToggleButtonStyle {
-            is_tapped: Property::new(false),
-            is_hover: Property::new(false),
-            is_focused: Property::new(false),
-        }
+        TabToggleButtonStyle {}
     }
 }
 
 impl Style<ToggleButton> for TabToggleButtonStyle {
     fn setup(&mut self, data: &mut ToggleButton, control_context: &ControlContext) {
+        control_context.set_default_attached_value::<Padding>(Thickness::all(10.0f32));
         control_context.dirty_watch_property(&data.is_checked);
-        control_context.dirty_watch_property(&self.is_tapped);
-        control_context.dirty_watch_property(&self.is_hover);
-        control_context.dirty_watch_property(&self.is_focused);
     }
 
     fn handle_event(
@@ -488,47 +373,13 @@ impl Style<ToggleButton> for TabToggleButtonStyle {
         _event_context: &mut dyn EventContext,
         event: ControlEvent,
     ) {
-        match event {
-            ControlEvent::TapDown { .. } => {
-                self.is_tapped.set(true);
-            }
-
-            ControlEvent::TapUp { ref position } => {
-                if let Some(hit_control) = self.hit_test(&data, &control_context, *position) {
-                    if Rc::ptr_eq(&hit_control, &control_context.get_self_rc()) {
-                        data.is_checked.set(true);
-                    } else {
-                        self.is_tapped.set(false);
-                    }
+        // the visual state (hover, pressed, focused) is tracked by the control context
+        if let ControlEvent::TapUp { ref position } = event {
+            if let Some(hit_control) = self.hit_test(&data, &control_context, *position) {
+                if Rc::ptr_eq(&hit_control, &control_context.get_self_rc()) {
+                    data.is_checked.set(true);
                 }
-                self.is_tapped.set(false);
-            }
-
-            ControlEvent::TapMove { ref position } => {
-                match self.hit_test(&data, &control_context, *position) {
-                    Some(_) => match self.hit_test(&data, &control_context, *position) {
-                        Some(_) => {
-                            self.is_tapped.set(true);
-                        }
-                        _ => {
-                            self.is_tapped.set(false);
-                        }
-                    },
-                    _ => {
-                        self.is_tapped.set(false);
-                    }
-                }
-            }
-
-            ControlEvent::HoverChange(value) => {
-                self.is_hover.set(value);
             }
-
-            ControlEvent::FocusChange(value) => {
-                self.is_focused.set(value);
-            }
-
-            _ => (),
         }
     }
 
@@ -540,16 +391,14 @@ impl Style<ToggleButton> for TabToggleButtonStyle {
         size: Size,
     ) -> Size {
         let children = control_context.get_children();
-        let content_size = match children.into_iter().next() {
+        match children.into_iter().next() {
             Some(ref content) => {
                 content.measure(drawing_context, size);
                 let rect = content.get_rect();
                 Size::new(rect.width, rect.height)
             }
             _ => Size::new(0f32, 0f32),
-        };
-
-        Size::new(content_size.width + 20.0f32, content_size.height + 20.0f32)
+        }
     }
 
     fn set_rect(
@@ -559,16 +408,9 @@ impl Style<ToggleButton> for TabToggleButtonStyle {
         drawing_context: &mut FuiDrawingContext,
         rect: Rect,
     ) {
-        let content_rect = Rect::new(
-            rect.x + 10.0f32,
-            rect.y + 10.0f32,
-            rect.width - 20.0f32,
-            rect.height - 20.0f32,
-        );
-
         let children = control_context.get_children();
         if let Some(ref content) = children.into_iter().next() {
-            content.set_rect(drawing_context, content_rect);
+            content.set_rect(drawing_context, rect);
         }
     }
 
@@ -592,12 +434,14 @@ impl Style<ToggleButton> for TabToggleButtonStyle {
         drawing_context: &mut FuiDrawingContext,
     ) {
         let rect = control_context.get_rect();
+        let theme = Theme::get(control_context);
+        let visual_state = control_context.get_visual_state();
         let x = rect.x;
         let y = rect.y;
         let width = rect.width;
         let height = rect.height;
 
-        let is_pressed = if self.is_tapped.get() {
+        let is_pressed = if visual_state.is_pressed {
             true
         } else {
             data.is_checked.get()
@@ -605,13 +449,14 @@ impl Style<ToggleButton> for TabToggleButtonStyle {
 
         default_theme::button(
             &mut drawing_context.display,
+            &theme,
             x,
             y,
             width,
             height,
             is_pressed,
-            self.is_hover.get(),
-            self.is_focused.get(),
+            visual_state.is_hover,
+            visual_state.is_focused,
         );
 
         let children = control_context.get_children();
@@ -636,28 +481,17 @@ impl Style<ToggleButton> for TabToggleButtonStyle {
 #[derive(TypedBuilder)]
 pub struct RadioToggleButtonStyleParams {}
 
-pub struct RadioToggleButtonStyle {
-    is_tapped: Property<bool>,
-    is_hover: Property<bool>,
-    is_focused: Property<bool>,
-}
+pub struct RadioToggleButtonStyle {}
 
 impl RadioToggleButtonStyle {
     pub fn new(_params: RadioToggleButtonStyleParams) -> Self {
-        RadioToggleButtonStyle {
-            is_tapped: Property::new(false),
-            is_hover: Property::new(false),
-            is_focused: Property::new(false),
-        }
+        RadioToggleButtonStyle {}
     }
 }
 
 impl Style<ToggleButton> for RadioToggleButtonStyle {
     fn setup(&mut self, data: &mut ToggleButton, control_context: &ControlContext) {
         control_context.dirty_watch_property(&data.is_checked);
-        control_context.dirty_watch_property(&self.is_tapped);
-        control_context.dirty_watch_property(&self.is_hover);
-        control_context.dirty_watch_property(&self.is_focused);
     }
 
     fn handle_event(
@@ -668,47 +502,13 @@ impl Style<ToggleButton> for RadioToggleButtonStyle {
         _event_context: &mut dyn EventContext,
         event: ControlEvent,
     ) {
-        match event {
-            ControlEvent::TapDown { .. } => {
-                self.is_tapped.set(true);
-            }
-
-            ControlEvent::TapUp { ref position } => {
-                if let Some(hit_control) = self.hit_test(&data, &control_context, *position) {
-                    if Rc::ptr_eq(&hit_control, &control_context.get_self_rc()) {
-                        data.is_checked.set(true);
-                    } else {
-                        self.is_tapped.set(false);
-                    }
+        // the visual state (hover, pressed, focused) is tracked by the control context
+        if let ControlEvent::TapUp { ref position } = event {
+            if let Some(hit_control) = self.hit_test(&data, &control_context, *position) {
+                if Rc::ptr_eq(&hit_control, &control_context.get_self_rc()) {
+                    data.is_checked.set(true);
                 }
-                self.is_tapped.set(false);
             }
-
-            ControlEvent::TapMove { ref position } => {
-                match self.hit_test(&data, &control_context, *position) {
-                    Some(_) => match self.hit_test(&data, &control_context, *position) {
-                        Some(_) => {
-                            self.is_tapped.set(true);
-                        }
-                        _ => {
-                            self.is_tapped.set(false);
-                        }
-                    },
-                    _ => {
-                        self.is_tapped.set(false);
-                    }
-                }
-            }
-
-            ControlEvent::HoverChange(value) => {
-                self.is_hover.set(value);
-            }
-
-            ControlEvent::FocusChange(value) => {
-                self.is_focused.set(value);
-            }
-
-            _ => (),
         }
     }
 
@@ -719,14 +519,8 @@ impl Style<ToggleButton> for RadioToggleButtonStyle {
         drawing_context: &mut FuiDrawingContext,
         size: Size,
     ) -> Size {
-        let radio_button_size = control_context
-            .get_inherited_value::<RadioButtonSize>()
-            .map(|p| p.get())
-            .unwrap_or(default_theme::RADIO_BUTTON_SIZE);
-        let radio_margin = control_context
-            .get_inherited_value::<RadioMargin>()
-            .map(|p| p.get())
-            .unwrap_or(default_theme::RADIO_MARGIN);
+        let radio_button_size = RadioButtonSize::get(control_context);
+        let radio_margin = RadioMargin::get(control_context);
 
         let children = control_context.get_children();
         let content_size = match children.into_iter().next() {
@@ -763,14 +557,8 @@ impl Style<ToggleButton> for RadioToggleButtonStyle {
         drawing_context: &mut FuiDrawingContext,
         rect: Rect,
     ) {
-        let radio_button_size = control_context
-            .get_inherited_value::<RadioButtonSize>()
-            .map(|p| p.get())
-            .unwrap_or(default_theme::RADIO_BUTTON_SIZE);
-        let radio_margin = control_context
-            .get_inherited_value::<RadioMargin>()
-            .map(|p| p.get())
-            .unwrap_or(default_theme::RADIO_MARGIN);
+        let radio_button_size = RadioButtonSize::get(control_context);
+        let radio_margin = RadioMargin::get(control_context);
 
         let content_rect = Rect::new(
             rect.x + radio_button_size + radio_margin,
@@ -805,20 +593,16 @@ impl Style<ToggleButton> for RadioToggleButtonStyle {
         drawing_context: &mut FuiDrawingContext,
     ) {
         let r = control_context.get_rect();
+        let theme = Theme::get(control_context);
+        let visual_state = control_context.get_visual_state();
         let x = r.x;
         let y = r.y;
         let height = r.height;
 
-        let radio_button_size = control_context
-            .get_inherited_value::<RadioButtonSize>()
-            .map(|p| p.get())
-            .unwrap_or(default_theme::RADIO_BUTTON_SIZE);
-        let radio_bullet_size = control_context
-            .get_inherited_value::<RadioBulletSize>()
-            .map(|p| p.get())
-            .unwrap_or(default_theme::RADIO_BULLET_SIZE);
+        let radio_button_size = RadioButtonSize::get(control_context);
+        let radio_bullet_size = RadioBulletSize::get(control_context);
 
-        let is_pressed = if self.is_tapped.get() {
+        let is_pressed = if visual_state.is_pressed {
             true
         } else {
             data.is_checked.get()
@@ -826,14 +610,15 @@ impl Style<ToggleButton> for RadioToggleButtonStyle {
 
         default_theme::button_rounded(
             &mut drawing_context.display,
+            &theme,
             x,
             y,
             radio_button_size,
             height,
             3.0f32,
             is_pressed,
-            self.is_hover.get(),
-            self.is_focused.get(),
+            visual_state.is_hover,
+            visual_state.is_focused,
         );
 
         if is_pressed {
@@ -875,18 +660,12 @@ pub struct DropDownToggleButtonStyleParams {
 }
 
 pub struct DropDownToggleButtonStyle {
-    is_tapped: Property<bool>,
-    is_hover: Property<bool>,
-    is_focused: Property<bool>,
     _clicked: Callback<()>,
 }
 
 impl DropDownToggleButtonStyle {
     pub fn new(params: DropDownToggleButtonStyleParams) -> Self {
         DropDownToggleButtonStyle {
-            is_tapped: Property::new(false),
-            is_hover: Property::new(false),
-            is_focused: Property::new(false),
             _clicked: params.clicked,
         }
     }
@@ -894,10 +673,8 @@ impl DropDownToggleButtonStyle {
 
 impl Style<ToggleButton> for DropDownToggleButtonStyle {
     fn setup(&mut self, data: &mut ToggleButton, control_context: &ControlContext) {
+        control_context.set_default_attached_value::<Padding>(Thickness::all(10.0f32));
         control_context.dirty_watch_property(&data.is_checked);
-        control_context.dirty_watch_property(&self.is_tapped);
-        control_context.dirty_watch_property(&self.is_hover);
-        control_context.dirty_watch_property(&self.is_focused);
     }
 
     fn handle_event(
@@ -908,47 +685,13 @@ impl Style<ToggleButton> for DropDownToggleButtonStyle {
         _event_context: &mut dyn EventContext,
         event: ControlEvent,
     ) {
-        match event {
-            ControlEvent::TapDown { .. } => {
-                self.is_tapped.set(true);
-            }
-
-            ControlEvent::TapUp { ref position } => {
-                if let Some(hit_control) = self.hit_test(&data, &control_context, *position) {
-                    if Rc::ptr_eq(&hit_control, &control_context.get_self_rc()) {
-                        data.is_checked.set(true);
-                    } else {
-                        self.is_tapped.set(false);
-                    }
+        // the visual state (hover, pressed, focused) is tracked by the control context
+        if let ControlEvent::TapUp { ref position } = event {
+            if let Some(hit_control) = self.hit_test(&data, &control_context, *position) {
+                if Rc::ptr_eq(&hit_control, &control_context.get_self_rc()) {
+                    data.is_checked.set(true);
                 }
-                self.is_tapped.set(false);
-            }
-
-            ControlEvent::TapMove { ref position } => {
-                match self.hit_test(&data, &control_context, *position) {
-                    Some(_) => match self.hit_test(&data, &control_context, *position) {
-                        Some(_) => {
-                            self.is_tapped.set(true);
-                        }
-                        _ => {
-                            self.is_tapped.set(false);
-                        }
-                    },
-                    _ => {
-                        self.is_tapped.set(false);
-                    }
-                }
-            }
-
-            ControlEvent::HoverChange(value) => {
-                self.is_hover.set(value);
             }
-
-            ControlEvent::FocusChange(value) => {
-                self.is_focused.set(value);
-            }
-
-            _ => (),
         }
     }
 
@@ -960,16 +703,14 @@ impl Style<ToggleButton> for DropDownToggleButtonStyle {
         size: Size,
     ) -> Size {
         let children = control_context.get_children();
-        let content_size = match children.into_iter().next() {
+        match children.into_iter().next() {
             Some(ref content) => {
                 content.measure(drawing_context, size);
                 let rect = content.get_rect();
                 Size::new(rect.width, rect.height)
             }
             _ => Size::new(0f32, 0f32),
-        };
-
-        Size::new(content_size.width + 20.0f32, content_size.height + 20.0f32)
+        }
     }
 
     fn set_rect(
@@ -979,16 +720,9 @@ impl Style<ToggleButton> for DropDownToggleButtonStyle {
         drawing_context: &mut FuiDrawingContext,
         rect: Rect,
     ) {
-        let content_rect = Rect::new(
-            rect.x + 10.0f32,
-            rect.y + 10.0f32,
-            rect.width - 20.0f32,
-            rect.height - 20.0f32,
-        );
-
         let children = control_context.get_children();
         if let Some(ref content) = children.into_iter().next() {
-            content.set_rect(drawing_context, content_rect);
+            content.set_rect(drawing_context, rect);
         }
     }
 
@@ -1012,12 +746,14 @@ impl Style<ToggleButton> for DropDownToggleButtonStyle {
         drawing_context: &mut FuiDrawingContext,
     ) {
         let rect = control_context.get_rect();
+        let theme = Theme::get(control_context);
+        let visual_state = control_context.get_visual_state();
         let x = rect.x;
         let y = rect.y;
         let width = rect.width;
         let height = rect.height;
 
-        let is_pressed = if self.is_tapped.get() {
+        let is_pressed = if visual_state.is_pressed {
             true
         } else {
             data.is_checked.get()
@@ -1025,13 +761,14 @@ impl Style<ToggleButton> for DropDownToggleButtonStyle {
 
         default_theme::button(
             &mut drawing_context.display,
+            &theme,
             x,
             y,
             width,
             height,
             is_pressed,
-            self.is_hover.get(),
-            self.is_focused.get(),
+            visual_state.is_hover,
+            visual_state.is_focused,
         );
 
         let children = control_context.get_children();
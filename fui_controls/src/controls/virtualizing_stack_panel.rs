@@ -0,0 +1,659 @@
+use std::cell::{Cell, RefCell};
+use std::collections::{BTreeMap, VecDeque};
+use std::f32;
+use std::ops::Deref;
+use std::rc::Rc;
+
+use fui_core::*;
+use typed_builder::TypedBuilder;
+
+use crate::controls::ScrollViewport;
+
+//
+// VirtualizingStackPanel.
+//
+
+///
+/// Vertical list of item views that creates views only for the items
+/// in the visible part of the enclosing `ScrollViewer` / `ScrollArea`
+/// (plus a few items before and after them).
+///
+/// The panel should be the content of the scroll area.
+///
+/// # Example
+///
+/// ```ignore
+/// ui! {
+///     ScrollViewer {
+///         VirtualizingStackPanel {
+///             items: &self.rows,
+///             item_height: 24.0f32,
+///             item_template: ItemTemplate::new(|row: &Property<ItemRef<RowViewModel>>| {
+///                 ui! { Text { text: row.map_value(|row| row.name.clone()) } }
+///             }),
+///         }
+///     }
+/// }
+/// ```
+///
+#[derive(TypedBuilder)]
+pub struct VirtualizingStackPanel<V>
+where
+    V: ViewModel + 'static,
+{
+    #[builder(default = Box::new(Vec::<Rc<V>>::new()))]
+    pub items: Box<dyn ObservableCollection<Rc<V>>>,
+
+    /// Fixed height of every item. When `None` the items are measured.
+    #[builder(default = None)]
+    pub item_height: Option<f32>,
+
+    /// Height used for the items that were not measured yet.
+    #[builder(default = 30.0f32)]
+    pub estimated_item_height: f32,
+
+    /// Number of items realized before and after the visible ones.
+    #[builder(default = 5)]
+    pub buffer_items: usize,
+
+    /// Creates a view bound to the item property. Views of the items
+    /// scrolled out of the view are rebound to other items by setting the property.
+    /// When `None`, views are created with `ViewModel::create_view()`
+    /// and are reused only for the same items.
+    #[builder(default = None, setter(strip_option))]
+    pub item_template: Option<ItemTemplate<V>>,
+
+    /// Number of views of items scrolled out of the view that are kept
+    /// in the pool for reuse.
+    #[builder(default = 100)]
+    pub recycle_pool_size: usize,
+}
+
+///
+/// Creates views bound to item properties.
+///
+pub struct ItemTemplate<V> {
+    create_view: Rc<CreateItemViewFn<V>>,
+}
+
+type CreateItemViewFn<V> = dyn Fn(&Property<ItemRef<V>>) -> Rc<dyn ControlObject>;
+
+impl<V> ItemTemplate<V> {
+    pub fn new<F: 'static + Fn(&Property<ItemRef<V>>) -> Rc<dyn ControlObject>>(f: F) -> Self {
+        ItemTemplate {
+            create_view: Rc::new(f),
+        }
+    }
+
+    pub fn create_view(&self, item: &Property<ItemRef<V>>) -> Rc<dyn ControlObject> {
+        (self.create_view)(item)
+    }
+}
+
+///
+/// Item bound to a recycled view.
+///
+/// Items are compared by identity, so view models
+/// don't have to implement `PartialEq`.
+///
+pub struct ItemRef<V>(pub Rc<V>);
+
+impl<V> Clone for ItemRef<V> {
+    fn clone(&self) -> Self {
+        ItemRef(self.0.clone())
+    }
+}
+
+impl<V> PartialEq for ItemRef<V> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl<V> Deref for ItemRef<V> {
+    type Target = V;
+
+    fn deref(&self) -> &V {
+        &self.0
+    }
+}
+
+impl<V> VirtualizingStackPanel<V>
+where
+    V: ViewModel + 'static,
+{
+    pub fn to_view(
+        self,
+        style: Option<Box<dyn Style<Self>>>,
+        context: ViewContext,
+    ) -> Rc<dyn ControlObject> {
+        StyledControl::new(
+            self,
+            style.unwrap_or_else(|| {
+                Box::new(DefaultVirtualizingStackPanelStyle::new(
+                    DefaultVirtualizingStackPanelStyleParams::builder().build(),
+                ))
+            }),
+            context,
+        )
+    }
+}
+
+//
+// Default VirtualizingStackPanel Style
+//
+
+#[derive(TypedBuilder)]
+pub struct DefaultVirtualizingStackPanelStyleParams {}
+
+pub struct DefaultVirtualizingStackPanelStyle<V> {
+    state: Rc<RefCell<RealizationState<V>>>,
+
+    /// Position of every item (and the end of the last one).
+    item_offsets: Vec<f32>,
+
+    offset_y: Option<Property<f32>>,
+    viewport_height: Option<Property<f32>>,
+
+    items_subscription: Option<Subscription>,
+    viewport_subscriptions: Vec<Subscription>,
+}
+
+impl<V> DefaultVirtualizingStackPanelStyle<V>
+where
+    V: ViewModel + 'static,
+{
+    pub fn new(_params: DefaultVirtualizingStackPanelStyleParams) -> Self {
+        DefaultVirtualizingStackPanelStyle {
+            state: Rc::new(RefCell::new(RealizationState {
+                item_heights: Vec::new(),
+                realized: BTreeMap::new(),
+                pool: VecDeque::new(),
+            })),
+            item_offsets: vec![0.0f32],
+            offset_y: None,
+            viewport_height: None,
+            items_subscription: None,
+            viewport_subscriptions: Vec::new(),
+        }
+    }
+
+    /// Returns the visible range of the panel (offset and height).
+    fn get_visible_range(&self, available_size: Size) -> (f32, f32) {
+        let offset = self
+            .offset_y
+            .as_ref()
+            .map(|offset_y| offset_y.get())
+            .unwrap_or(0.0f32);
+
+        let height = if available_size.height.is_finite() {
+            available_size.height
+        } else {
+            match self.viewport_height {
+                Some(ref viewport_height) => viewport_height.get(),
+                // not in scroll area - realize everything
+                None => f32::INFINITY,
+            }
+        };
+
+        (offset, height)
+    }
+}
+
+impl<V> Style<VirtualizingStackPanel<V>> for DefaultVirtualizingStackPanelStyle<V>
+where
+    V: ViewModel + 'static,
+{
+    fn setup(&mut self, data: &mut VirtualizingStackPanel<V>, control_context: &ControlContext) {
+        self.state.borrow_mut().item_heights = vec![None; data.items.len()];
+
+        let state = self.state.clone();
+        let self_weak = control_context.get_self_weak();
+        let recycle_pool_size = data.recycle_pool_size;
+        let is_update_scheduled = Rc::new(Cell::new(false));
+        self.items_subscription =
+            data.items
+                .on_changed(Box::new(move |changed_args: VecDiff<Rc<V>>| {
+                    state
+                        .borrow_mut()
+                        .items_changed(changed_args, recycle_pool_size);
+
+                    // the changes come in batches (e.g. clear followed by inserts
+                    // of all the items), so the layout is invalidated once per batch
+                    if !is_update_scheduled.replace(true) {
+                        let state = state.clone();
+                        let self_weak = self_weak.clone();
+                        let is_update_scheduled = is_update_scheduled.clone();
+                        spawn_local_and_forget(async move {
+                            is_update_scheduled.set(false);
+                            if let Some(control) = self_weak.upgrade() {
+                                let context = control.get_context();
+                                context.set_realized_children(state.borrow().get_realized_views());
+                                context.invalidate_measure();
+                            }
+                        });
+                    }
+                }));
+    }
+
+    fn parent_attached(
+        &mut self,
+        _data: &mut VirtualizingStackPanel<V>,
+        control_context: &ControlContext,
+    ) {
+        if let Some(viewport) = control_context.get_inherited_value::<ScrollViewport>() {
+            let viewport_height = viewport
+                .viewport_info
+                .map_value(|info| info.viewport_height);
+
+            for property in [&viewport.offset_y, &viewport_height] {
+                let self_weak = control_context.get_self_weak();
+                self.viewport_subscriptions
+                    .push(property.on_changed(move |_| {
+                        if let Some(control) = self_weak.upgrade() {
+                            control.get_context().invalidate_measure();
+                        }
+                    }));
+            }
+
+            self.offset_y = Some(viewport.offset_y);
+            self.viewport_height = Some(viewport_height);
+        }
+    }
+
+    fn parent_detached(
+        &mut self,
+        _data: &mut VirtualizingStackPanel<V>,
+        _control_context: &ControlContext,
+    ) {
+        self.viewport_subscriptions.clear();
+        self.offset_y = None;
+        self.viewport_height = None;
+    }
+
+    fn handle_event(
+        &mut self,
+        _data: &mut VirtualizingStackPanel<V>,
+        _control_context: &ControlContext,
+        _drawing_context: &mut FuiDrawingContext,
+        _event_context: &mut dyn EventContext,
+        _event: ControlEvent,
+    ) {
+    }
+
+    fn measure(
+        &mut self,
+        data: &mut VirtualizingStackPanel<V>,
+        control_context: &ControlContext,
+        drawing_context: &mut FuiDrawingContext,
+        size: Size,
+    ) -> Size {
+        let (visible_offset, visible_height) = self.get_visible_range(size);
+
+        let mut state = self.state.borrow_mut();
+        let count = state.item_heights.len().min(data.items.len());
+        let item_size = Size::new(size.width, data.item_height.unwrap_or(f32::INFINITY));
+
+        // find the first visible item
+        let mut first_visible = 0;
+        let mut first_visible_offset = 0.0f32;
+        while first_visible < count {
+            let height = state.get_item_height(first_visible, data);
+            if first_visible_offset + height > visible_offset {
+                break;
+            }
+            first_visible_offset += height;
+            first_visible += 1;
+        }
+
+        // realize items measuring them, so the visible area is filled
+        // also when the estimated heights were wrong
+        let first = first_visible.saturating_sub(data.buffer_items);
+        let mut width = 0.0f32;
+        let mut index = first;
+        let mut bottom = first_visible_offset;
+        let mut buffer_left = data.buffer_items;
+        while index < count {
+            if index >= first_visible && bottom >= visible_offset + visible_height {
+                if buffer_left == 0 {
+                    break;
+                }
+                buffer_left -= 1;
+            }
+
+            if let Some(item) = data.items.get(index) {
+                let view = state.realize(index, item, data, control_context);
+                view.measure(drawing_context, item_size);
+                let view_rect = view.get_rect();
+                width = width.max(view_rect.width);
+                if data.item_height.is_none() {
+                    state.item_heights[index] = Some(view_rect.height);
+                }
+            }
+
+            if index >= first_visible {
+                bottom += state.get_item_height(index, data);
+            }
+            index += 1;
+        }
+
+        // views out of the range go to the pool
+        let out_of_range: Vec<usize> = state
+            .realized
+            .keys()
+            .cloned()
+            .filter(|i| *i < first || *i >= index)
+            .collect();
+        for i in out_of_range {
+            if let Some(realized) = state.realized.remove(&i) {
+                state.release(realized, data.recycle_pool_size);
+            }
+        }
+        control_context.set_realized_children(state.get_realized_views());
+
+        self.item_offsets.clear();
+        let mut offset = 0.0f32;
+        self.item_offsets.push(offset);
+        for i in 0..count {
+            offset += state.get_item_height(i, data);
+            self.item_offsets.push(offset);
+        }
+
+        Size::new(width, offset)
+    }
+
+    fn set_rect(
+        &mut self,
+        _data: &mut VirtualizingStackPanel<V>,
+        _control_context: &ControlContext,
+        drawing_context: &mut FuiDrawingContext,
+        rect: Rect,
+    ) {
+        let state = self.state.borrow();
+        for (&index, realized) in state.realized.iter() {
+            if index + 1 < self.item_offsets.len() {
+                let y = self.item_offsets[index];
+                let height = self.item_offsets[index + 1] - y;
+                realized.view.set_rect(
+                    drawing_context,
+                    Rect::new(rect.x, rect.y + y, rect.width, height),
+                );
+            }
+        }
+    }
+
+    fn hit_test(
+        &self,
+        _data: &VirtualizingStackPanel<V>,
+        control_context: &ControlContext,
+        point: Point,
+    ) -> Option<Rc<dyn ControlObject>> {
+        if point.is_inside(&control_context.get_rect()) {
+            for realized in self.state.borrow().realized.values() {
+                let rect = realized.view.get_rect();
+                if point.is_inside(&rect) {
+                    let hit_control = realized.view.hit_test(point);
+                    if hit_control.is_some() {
+                        return hit_control;
+                    }
+                }
+            }
+            None
+        } else {
+            None
+        }
+    }
+
+    fn draw(
+        &mut self,
+        _data: &VirtualizingStackPanel<V>,
+        _control_context: &ControlContext,
+        drawing_context: &mut FuiDrawingContext,
+    ) {
+        for realized in self.state.borrow().realized.values() {
+            realized.view.draw(drawing_context);
+        }
+    }
+}
+
+//
+// Realization state (shared with the items change handler).
+//
+
+struct RealizedItem<V> {
+    item: Rc<V>,
+
+    /// Property the view is bound to (only for the views created by the item template).
+    item_property: Option<Property<ItemRef<V>>>,
+
+    view: Rc<dyn ControlObject>,
+}
+
+struct RealizationState<V> {
+    /// Measured heights of the items (`None` - not measured yet).
+    item_heights: Vec<Option<f32>>,
+
+    /// Item views by item index.
+    realized: BTreeMap<usize, RealizedItem<V>>,
+
+    /// Views of items no longer realized (the oldest first).
+    pool: VecDeque<RealizedItem<V>>,
+}
+
+impl<V> RealizationState<V>
+where
+    V: ViewModel + 'static,
+{
+    fn get_item_height(&self, index: usize, data: &VirtualizingStackPanel<V>) -> f32 {
+        data.item_height
+            .or(self.item_heights[index])
+            .unwrap_or(data.estimated_item_height)
+    }
+
+    fn get_realized_views(&self) -> Vec<Rc<dyn ControlObject>> {
+        self.realized
+            .values()
+            .map(|realized| realized.view.clone())
+            .collect()
+    }
+
+    fn realize(
+        &mut self,
+        index: usize,
+        item: Rc<V>,
+        data: &VirtualizingStackPanel<V>,
+        control_context: &ControlContext,
+    ) -> Rc<dyn ControlObject> {
+        if let Some(realized) = self.realized.remove(&index) {
+            if Rc::ptr_eq(&realized.item, &item) {
+                let view = realized.view.clone();
+                self.realized.insert(index, realized);
+                return view;
+            }
+            self.release(realized, data.recycle_pool_size);
+        }
+
+        // the view of the same item is preferred, then any view
+        // that can be rebound (the oldest first)
+        let same_item_position = self
+            .pool
+            .iter()
+            .position(|pooled| Rc::ptr_eq(&pooled.item, &item));
+        let realized = match same_item_position {
+            Some(position) => self.pool.remove(position).unwrap(),
+            None => match data.item_template {
+                Some(ref item_template) => match self.pool.pop_front() {
+                    Some(mut pooled) => {
+                        if let Some(ref item_property) = pooled.item_property {
+                            item_property.set(ItemRef(item.clone()));
+                        }
+                        pooled.item = item;
+                        pooled
+                    }
+                    None => {
+                        let item_property = Property::new(ItemRef(item.clone()));
+                        let view = item_template.create_view(&item_property);
+                        RealizedItem {
+                            item,
+                            item_property: Some(item_property),
+                            view,
+                        }
+                    }
+                },
+                None => RealizedItem {
+                    view: item.create_view(),
+                    item,
+                    item_property: None,
+                },
+            },
+        };
+
+        let view = realized.view.clone();
+        view.get_context()
+            .set_services(control_context.get_services());
+        view.get_context()
+            .set_parent(&control_context.get_self_rc());
+        self.realized.insert(index, realized);
+
+        view
+    }
+
+    /// Detaches the view and keeps it in the pool.
+    fn release(&mut self, realized: RealizedItem<V>, recycle_pool_size: usize) {
+        realized.view.get_context().detach_tree();
+
+        if recycle_pool_size > 0 {
+            if self.pool.len() >= recycle_pool_size {
+                self.pool.pop_front();
+            }
+            self.pool.push_back(realized);
+        }
+    }
+
+    fn remap_realized<F: Fn(usize) -> usize>(&mut self, f: F) {
+        self.realized = std::mem::take(&mut self.realized)
+            .into_iter()
+            .map(|(index, realized)| (f(index), realized))
+            .collect();
+    }
+
+    /// Updates the state without realizing any item.
+    fn items_changed(&mut self, changed_args: VecDiff<Rc<V>>, recycle_pool_size: usize) {
+        match changed_args {
+            VecDiff::Clear { .. } => {
+                self.item_heights.clear();
+                for (_, realized) in std::mem::take(&mut self.realized) {
+                    self.release(realized, recycle_pool_size);
+                }
+            }
+
+            VecDiff::InsertAt { index, .. } => {
+                self.item_heights.insert(index, None);
+                self.remap_realized(|i| if i >= index { i + 1 } else { i });
+            }
+
+            VecDiff::RemoveAt { index, .. } => {
+                self.item_heights.remove(index);
+                if let Some(realized) = self.realized.remove(&index) {
+                    self.release(realized, recycle_pool_size);
+                }
+                self.remap_realized(|i| if i > index { i - 1 } else { i });
+            }
+
+            VecDiff::Move {
+                old_index,
+                new_index,
+            } => {
+                let height = self.item_heights.remove(old_index);
+                self.item_heights.insert(new_index, height);
+
+                let moved = self.realized.remove(&old_index);
+                self.remap_realized(|i| {
+                    let i = if i > old_index { i - 1 } else { i };
+                    if i >= new_index {
+                        i + 1
+                    } else {
+                        i
+                    }
+                });
+                if let Some(moved) = moved {
+                    self.realized.insert(new_index, moved);
+                }
+            }
+
+            VecDiff::Pop { .. } => {
+                self.item_heights.pop();
+                let index = self.item_heights.len();
+                if let Some(realized) = self.realized.remove(&index) {
+                    self.release(realized, recycle_pool_size);
+                }
+            }
+
+            VecDiff::Push { .. } => {
+                self.item_heights.push(None);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use fui_core::*;
+
+    use super::{ItemRef, ItemTemplate, VirtualizingStackPanel};
+    use crate::test_controls::{layout, run_local, run_pending_tasks, FixedSize};
+
+    struct Row;
+
+    impl ViewModel for Row {
+        fn create_view(self: &Rc<Self>) -> Rc<dyn ControlObject> {
+            FixedSize::new(100.0f32, 10.0f32, ViewContext::empty())
+        }
+    }
+
+    fn rows(count: usize) -> Vec<Rc<Row>> {
+        (0..count).map(|_| Rc::new(Row)).collect()
+    }
+
+    #[test]
+    fn test_views_are_rebound_to_new_items() {
+        run_local(async {
+            let items = ObservableVec::new();
+            items.lock_mut().replace_cloned(rows(50));
+
+            let item_properties = Rc::new(RefCell::new(Vec::<Property<ItemRef<Row>>>::new()));
+            let panel = VirtualizingStackPanel::builder()
+                .items((&items).into())
+                .item_height(Some(10.0f32))
+                .buffer_items(0)
+                .item_template(ItemTemplate::new({
+                    let item_properties = item_properties.clone();
+                    move |item| {
+                        item_properties.borrow_mut().push(item.clone());
+                        FixedSize::new(100.0f32, 10.0f32, ViewContext::empty())
+                    }
+                }))
+                .build()
+                .to_view(None, ViewContext::empty());
+            run_pending_tasks().await;
+
+            layout(&panel, Size::new(100.0f32, 100.0f32));
+            assert_eq!(item_properties.borrow().len(), 10);
+
+            let new_rows = rows(50);
+            items.lock_mut().replace_cloned(new_rows.clone());
+            run_pending_tasks().await;
+            layout(&panel, Size::new(100.0f32, 100.0f32));
+
+            // the views of the old items are rebound to the visible new items
+            assert_eq!(item_properties.borrow().len(), 10);
+            for item_property in item_properties.borrow().iter() {
+                let item = item_property.get();
+                assert!(new_rows[0..10].iter().any(|row| Rc::ptr_eq(row, &item.0)));
+            }
+        });
+    }
+}
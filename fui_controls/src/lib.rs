@@ -12,3 +12,6 @@ mod special;
 mod style;
 mod utils;
 mod view_models;
+
+#[cfg(test)]
+mod test_controls;
@@ -1,5 +1,7 @@
 use fui_drawing::*;
 
+use crate::style::{Theme, ThemeMetrics, ThemePalette, ThemeTypography};
+
 // ============================================================================
 // Default values for inherited attached properties
 // ============================================================================
@@ -63,6 +65,12 @@ pub const PROGRESS_BAR_BACKGROUND: [f32; 4] = [0.0, 0.0, 0.0, 0.25];
 /// Scroll bar background color
 pub const SCROLL_BAR_BACKGROUND: [f32; 4] = [0.0, 0.0, 0.0, 0.25];
 
+/// Grid splitter color
+pub const GRID_SPLITTER_BACKGROUND: [f32; 4] = [0.0, 0.0, 0.0, 0.25];
+
+/// Grid splitter color when hovered, dragged or focused
+pub const GRID_SPLITTER_ACTIVE_BACKGROUND: [f32; 4] = [1.0, 0.8, 0.0, 0.5];
+
 /// Menu text foreground
 pub const MENU_FOREGROUND: [f32; 4] = [0.0, 0.0, 0.0, 1.0];
 
@@ -75,6 +83,9 @@ pub const MENU_HOVER_BACKGROUND: [f32; 4] = [0.0, 0.0, 0.0, 0.8];
 /// TextBox border color
 pub const TEXT_BOX_BORDER_COLOR: [f32; 4] = [0.4, 0.4, 0.4, 1.0];
 
+/// TextBox border color when hovered
+pub const TEXT_BOX_HOVER_BORDER_COLOR: [f32; 4] = [0.35, 0.35, 0.35, 1.0];
+
 /// Text selection background color
 pub const TEXT_SELECTION_BACKGROUND: [f32; 4] = [0.0, 0.47, 0.83, 0.35];
 
@@ -84,6 +95,15 @@ pub const TEXT_CURSOR_COLOR: [f32; 4] = [1.0, 1.0, 0.0, 1.0];
 /// Busy indicator overlay color
 pub const BUSY_INDICATOR_OVERLAY: [f32; 4] = [0.0, 0.0, 0.0, 0.7];
 
+/// Background of the tab control content
+pub const TAB_BACKGROUND: [f32; 4] = [1.0, 1.0, 1.0, 0.05];
+
+/// Tab title color
+pub const TAB_FOREGROUND: [f32; 4] = [1.0, 1.0, 1.0, 1.0];
+
+/// Tab title color of the selected tab
+pub const TAB_SELECTED_FOREGROUND: [f32; 4] = [1.0, 0.8, 0.0, 1.0];
+
 // ============================================================================
 // Size defaults
 // ============================================================================
@@ -118,6 +138,9 @@ pub const SCROLL_BAR_SIDE_MARGIN: f32 = 1.0;
 /// Scroll bar minimum thumb size
 pub const SCROLL_BAR_MIN_THUMB_SIZE: f32 = 20.0;
 
+/// Grid splitter thickness
+pub const GRID_SPLITTER_SIZE: f32 = 6.0;
+
 /// Progress bar start margin
 pub const PROGRESS_BAR_START_MARGIN: f32 = 1.0;
 
@@ -130,6 +153,125 @@ pub const PROGRESS_BAR_SIDE_MARGIN: f32 = 1.0;
 /// Progress bar minimum size
 pub const PROGRESS_BAR_MIN_SIZE: f32 = 22.0;
 
+// ============================================================================
+// Built-in themes
+// ============================================================================
+
+impl Theme {
+    /// Dark theme (the default one).
+    pub fn dark() -> Self {
+        Theme {
+            palette: ThemePalette {
+                foreground: DEFAULT_FOREGROUND,
+                background: DEFAULT_BACKGROUND,
+                edit_text_color: DEFAULT_EDIT_TEXT_COLOR,
+                border_light1: BORDER_LIGHT1,
+                border_light2: BORDER_LIGHT2,
+                border_medium1: BORDER_MEDIUM1,
+                border_medium2: BORDER_MEDIUM2,
+                border_dark: BORDER_DARK,
+                gradient_top_normal: GRADIENT_TOP_NORMAL,
+                gradient_bot_normal: GRADIENT_BOT_NORMAL,
+                hover_highlight: HOVER_HIGHLIGHT,
+                pressed_highlight: PRESSED_HIGHLIGHT,
+                focused_highlight: FOCUSED_HIGHLIGHT,
+                window_frame_color: WINDOW_FRAME_COLOR,
+                progress_bar_foreground: PROGRESS_BAR_FOREGROUND,
+                progress_bar_background: PROGRESS_BAR_BACKGROUND,
+                scroll_bar_background: SCROLL_BAR_BACKGROUND,
+                grid_splitter_background: GRID_SPLITTER_BACKGROUND,
+                grid_splitter_active_background: GRID_SPLITTER_ACTIVE_BACKGROUND,
+                menu_foreground: MENU_FOREGROUND,
+                menu_background: MENU_BACKGROUND,
+                menu_hover_background: MENU_HOVER_BACKGROUND,
+                text_box_border_color: TEXT_BOX_BORDER_COLOR,
+                text_box_hover_border_color: TEXT_BOX_HOVER_BORDER_COLOR,
+                text_selection_background: TEXT_SELECTION_BACKGROUND,
+                text_cursor_color: TEXT_CURSOR_COLOR,
+                busy_indicator_overlay: BUSY_INDICATOR_OVERLAY,
+                tab_background: TAB_BACKGROUND,
+                tab_foreground: TAB_FOREGROUND,
+                tab_selected_foreground: TAB_SELECTED_FOREGROUND,
+            },
+            typography: Self::default_typography(),
+            metrics: Self::default_metrics(),
+        }
+    }
+
+    /// Light theme.
+    pub fn light() -> Self {
+        Theme {
+            palette: ThemePalette {
+                foreground: [0.1, 0.1, 0.1, 1.0],
+                background: [0.0, 0.0, 0.0, 0.0],
+                edit_text_color: [0.0, 0.0, 0.0, 1.0],
+                border_light1: [1.0, 1.0, 1.0, 1.0],
+                border_light2: [0.92, 0.92, 0.92, 1.0],
+                border_medium1: [0.62, 0.62, 0.62, 1.0],
+                border_medium2: [0.55, 0.55, 0.55, 1.0],
+                border_dark: [0.35, 0.35, 0.35, 1.0],
+                gradient_top_normal: [0.96, 0.96, 0.96, 1.0],
+                gradient_bot_normal: [0.86, 0.86, 0.86, 1.0],
+                hover_highlight: [0.94, 0.97, 1.0],
+                pressed_highlight: [0.85, 0.85, 0.85],
+                focused_highlight: [0.6, 0.8, 1.0],
+                window_frame_color: [0.0, 0.4, 1.0, 1.0],
+                progress_bar_foreground: [0.0, 0.47, 0.83, 0.85],
+                progress_bar_background: [0.0, 0.0, 0.0, 0.1],
+                scroll_bar_background: [0.0, 0.0, 0.0, 0.08],
+                grid_splitter_background: [0.0, 0.0, 0.0, 0.12],
+                grid_splitter_active_background: [0.0, 0.47, 0.83, 0.5],
+                menu_foreground: [0.0, 0.0, 0.0, 1.0],
+                menu_background: [1.0, 1.0, 1.0, 0.95],
+                menu_hover_background: [0.0, 0.47, 0.83, 0.25],
+                text_box_border_color: [0.6, 0.6, 0.6, 1.0],
+                text_box_hover_border_color: [0.5, 0.5, 0.5, 1.0],
+                text_selection_background: [0.0, 0.47, 0.83, 0.3],
+                text_cursor_color: [0.0, 0.0, 0.0, 1.0],
+                busy_indicator_overlay: [1.0, 1.0, 1.0, 0.7],
+                tab_background: [0.0, 0.0, 0.0, 0.03],
+                tab_foreground: [0.1, 0.1, 0.1, 1.0],
+                tab_selected_foreground: [0.0, 0.47, 0.83, 1.0],
+            },
+            typography: Self::default_typography(),
+            metrics: Self::default_metrics(),
+        }
+    }
+
+    fn default_typography() -> ThemeTypography {
+        ThemeTypography {
+            font_family: DEFAULT_FONT_FAMILY.to_string(),
+            font_size: DEFAULT_FONT_SIZE,
+        }
+    }
+
+    fn default_metrics() -> ThemeMetrics {
+        ThemeMetrics {
+            border_size: BORDER_SIZE,
+            check_box_button_size: CHECK_BOX_BUTTON_SIZE,
+            check_box_margin: CHECK_BOX_MARGIN,
+            radio_button_size: RADIO_BUTTON_SIZE,
+            radio_bullet_size: RADIO_BULLET_SIZE,
+            radio_margin: RADIO_MARGIN,
+            scroll_bar_start_margin: SCROLL_BAR_START_MARGIN,
+            scroll_bar_end_margin: SCROLL_BAR_END_MARGIN,
+            scroll_bar_side_margin: SCROLL_BAR_SIDE_MARGIN,
+            scroll_bar_min_thumb_size: SCROLL_BAR_MIN_THUMB_SIZE,
+            grid_splitter_size: GRID_SPLITTER_SIZE,
+            progress_bar_start_margin: PROGRESS_BAR_START_MARGIN,
+            progress_bar_end_margin: PROGRESS_BAR_END_MARGIN,
+            progress_bar_side_margin: PROGRESS_BAR_SIDE_MARGIN,
+            progress_bar_min_size: PROGRESS_BAR_MIN_SIZE,
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme::dark()
+    }
+}
+
 fn multiply_color(color: [f32; 4], factor: [f32; 3]) -> [f32; 4] {
     [
         (color[0] * factor[0]).min(1.0f32),
@@ -141,6 +283,7 @@ fn multiply_color(color: [f32; 4], factor: [f32; 3]) -> [f32; 4] {
 
 pub fn border_3d_single(
     display: &mut DrawingDisplayListBuilder,
+    theme: &Theme,
     x: f32,
     y: f32,
     width: f32,
@@ -167,29 +310,40 @@ pub fn border_3d_single(
     let (mut border_color1, mut border_color2, mut border_color3, mut border_color4) = if is_pressed
     {
         (
-            multiply_color(BORDER_MEDIUM2, PRESSED_HIGHLIGHT),
-            multiply_color(BORDER_MEDIUM1, PRESSED_HIGHLIGHT),
-            multiply_color(BORDER_LIGHT2, PRESSED_HIGHLIGHT),
-            multiply_color(BORDER_LIGHT1, PRESSED_HIGHLIGHT),
+            multiply_color(
+                theme.palette.border_medium2,
+                theme.palette.pressed_highlight,
+            ),
+            multiply_color(
+                theme.palette.border_medium1,
+                theme.palette.pressed_highlight,
+            ),
+            multiply_color(theme.palette.border_light2, theme.palette.pressed_highlight),
+            multiply_color(theme.palette.border_light1, theme.palette.pressed_highlight),
         )
     } else {
         if is_hover {
             (
-                multiply_color(BORDER_LIGHT1, HOVER_HIGHLIGHT),
-                multiply_color(BORDER_LIGHT2, HOVER_HIGHLIGHT),
-                multiply_color(BORDER_MEDIUM1, HOVER_HIGHLIGHT),
-                multiply_color(BORDER_MEDIUM2, HOVER_HIGHLIGHT),
+                multiply_color(theme.palette.border_light1, theme.palette.hover_highlight),
+                multiply_color(theme.palette.border_light2, theme.palette.hover_highlight),
+                multiply_color(theme.palette.border_medium1, theme.palette.hover_highlight),
+                multiply_color(theme.palette.border_medium2, theme.palette.hover_highlight),
             )
         } else {
-            (BORDER_LIGHT1, BORDER_LIGHT2, BORDER_MEDIUM1, BORDER_MEDIUM2)
+            (
+                theme.palette.border_light1,
+                theme.palette.border_light2,
+                theme.palette.border_medium1,
+                theme.palette.border_medium2,
+            )
         }
     };
 
     if is_focused {
-        border_color1 = multiply_color(border_color1, FOCUSED_HIGHLIGHT);
-        border_color2 = multiply_color(border_color2, FOCUSED_HIGHLIGHT);
-        border_color3 = multiply_color(border_color3, FOCUSED_HIGHLIGHT);
-        border_color4 = multiply_color(border_color4, FOCUSED_HIGHLIGHT);
+        border_color1 = multiply_color(border_color1, theme.palette.focused_highlight);
+        border_color2 = multiply_color(border_color2, theme.palette.focused_highlight);
+        border_color3 = multiply_color(border_color3, theme.palette.focused_highlight);
+        border_color4 = multiply_color(border_color4, theme.palette.focused_highlight);
     }
 
     // border light
@@ -256,6 +410,7 @@ pub fn border_3d_single(
 
 pub fn border_3d_single_rounded(
     display: &mut DrawingDisplayListBuilder,
+    theme: &Theme,
     x: f32,
     y: f32,
     width: f32,
@@ -277,29 +432,40 @@ pub fn border_3d_single_rounded(
     let (mut border_color1, mut border_color2, mut border_color3, mut border_color4) = if is_pressed
     {
         (
-            multiply_color(BORDER_MEDIUM2, PRESSED_HIGHLIGHT),
-            multiply_color(BORDER_MEDIUM1, PRESSED_HIGHLIGHT),
-            multiply_color(BORDER_LIGHT2, PRESSED_HIGHLIGHT),
-            multiply_color(BORDER_LIGHT1, PRESSED_HIGHLIGHT),
+            multiply_color(
+                theme.palette.border_medium2,
+                theme.palette.pressed_highlight,
+            ),
+            multiply_color(
+                theme.palette.border_medium1,
+                theme.palette.pressed_highlight,
+            ),
+            multiply_color(theme.palette.border_light2, theme.palette.pressed_highlight),
+            multiply_color(theme.palette.border_light1, theme.palette.pressed_highlight),
         )
     } else {
         if is_hover {
             (
-                multiply_color(BORDER_LIGHT1, HOVER_HIGHLIGHT),
-                multiply_color(BORDER_LIGHT2, HOVER_HIGHLIGHT),
-                multiply_color(BORDER_MEDIUM1, HOVER_HIGHLIGHT),
-                multiply_color(BORDER_MEDIUM2, HOVER_HIGHLIGHT),
+                multiply_color(theme.palette.border_light1, theme.palette.hover_highlight),
+                multiply_color(theme.palette.border_light2, theme.palette.hover_highlight),
+                multiply_color(theme.palette.border_medium1, theme.palette.hover_highlight),
+                multiply_color(theme.palette.border_medium2, theme.palette.hover_highlight),
             )
         } else {
-            (BORDER_LIGHT1, BORDER_LIGHT2, BORDER_MEDIUM1, BORDER_MEDIUM2)
+            (
+                theme.palette.border_light1,
+                theme.palette.border_light2,
+                theme.palette.border_medium1,
+                theme.palette.border_medium2,
+            )
         }
     };
 
     if is_focused {
-        border_color1 = multiply_color(border_color1, FOCUSED_HIGHLIGHT);
-        border_color2 = multiply_color(border_color2, FOCUSED_HIGHLIGHT);
-        border_color3 = multiply_color(border_color3, FOCUSED_HIGHLIGHT);
-        border_color4 = multiply_color(border_color4, FOCUSED_HIGHLIGHT);
+        border_color1 = multiply_color(border_color1, theme.palette.focused_highlight);
+        border_color2 = multiply_color(border_color2, theme.palette.focused_highlight);
+        border_color3 = multiply_color(border_color3, theme.palette.focused_highlight);
+        border_color4 = multiply_color(border_color4, theme.palette.focused_highlight);
     }
 
     // border light
@@ -350,6 +516,7 @@ pub fn border_3d_single_rounded(
 
 pub fn border_3d(
     display: &mut DrawingDisplayListBuilder,
+    theme: &Theme,
     x: f32,
     y: f32,
     width: f32,
@@ -365,6 +532,7 @@ pub fn border_3d(
 
     border_3d_single(
         display,
+        theme,
         x + physical_pixel,
         y + physical_pixel,
         width - physical_pixel * 2.0f32,
@@ -382,12 +550,13 @@ pub fn border_3d(
             width - physical_pixel,
             height - physical_pixel,
         ),
-        DrawingPaint::stroke_color(BORDER_DARK, line_thickness),
+        DrawingPaint::stroke_color(theme.palette.border_dark, line_thickness),
     );
 }
 
 pub fn border_3d_rounded(
     display: &mut DrawingDisplayListBuilder,
+    theme: &Theme,
     x: f32,
     y: f32,
     width: f32,
@@ -403,6 +572,7 @@ pub fn border_3d_rounded(
 
     border_3d_single_rounded(
         display,
+        theme,
         x + physical_pixel,
         y + physical_pixel,
         width - physical_pixel * 2.0,
@@ -422,12 +592,13 @@ pub fn border_3d_rounded(
             height - physical_pixel,
         ),
         RoundingRadii::single_radii(radius + physical_pixel),
-        DrawingPaint::stroke_color(BORDER_DARK, physical_pixel),
+        DrawingPaint::stroke_color(theme.palette.border_dark, physical_pixel),
     );
 }
 
 pub fn border_3d_edit(
     display: &mut DrawingDisplayListBuilder,
+    theme: &Theme,
     x: f32,
     y: f32,
     width: f32,
@@ -436,15 +607,22 @@ pub fn border_3d_edit(
     is_focused: bool,
 ) {
     let color = if is_focused {
-        multiply_color([0.4f32, 0.4f32, 0.4f32, 1.0f32], FOCUSED_HIGHLIGHT)
+        multiply_color(
+            theme.palette.text_box_border_color,
+            theme.palette.focused_highlight,
+        )
     } else if is_hover {
-        multiply_color([0.35f32, 0.35f32, 0.35f32, 1.0f32], FOCUSED_HIGHLIGHT)
+        multiply_color(
+            theme.palette.text_box_hover_border_color,
+            theme.palette.focused_highlight,
+        )
     } else {
-        [0.4f32, 0.4f32, 0.4f32, 1.0f32]
+        theme.palette.text_box_border_color
     };
 
     border_3d_with_color(
         display,
+        theme,
         x,
         y,
         width,
@@ -457,6 +635,7 @@ pub fn border_3d_edit(
 
 pub fn border_3d_with_color(
     mut display: &mut DrawingDisplayListBuilder,
+    theme: &Theme,
     x: f32,
     y: f32,
     width: f32,
@@ -469,10 +648,13 @@ pub fn border_3d_with_color(
     let physical_pixel = 1.0 / dpr;
     let half_pixel = physical_pixel / 2.0;
 
-    border_3d_single(display, x, y, width, height, false, is_hover, is_focused);
+    border_3d_single(
+        display, theme, x, y, width, height, false, is_hover, is_focused,
+    );
 
     border_3d_single(
         display,
+        theme,
         x + physical_pixel * 2.0,
         y + physical_pixel * 2.0,
         width - physical_pixel * 4.0,
@@ -737,6 +919,7 @@ pub fn shadow_under_rect_rounded(
 
 pub fn button(
     mut display: &mut DrawingDisplayListBuilder,
+    theme: &Theme,
     x: f32,
     y: f32,
     width: f32,
@@ -750,17 +933,32 @@ pub fn button(
 
     let (gradient_top_color, gradient_bottom_color) = if is_pressed {
         (
-            multiply_color(GRADIENT_BOT_NORMAL, PRESSED_HIGHLIGHT),
-            multiply_color(GRADIENT_TOP_NORMAL, PRESSED_HIGHLIGHT),
+            multiply_color(
+                theme.palette.gradient_bot_normal,
+                theme.palette.pressed_highlight,
+            ),
+            multiply_color(
+                theme.palette.gradient_top_normal,
+                theme.palette.pressed_highlight,
+            ),
         )
     } else {
         if is_hover {
             (
-                multiply_color(GRADIENT_TOP_NORMAL, HOVER_HIGHLIGHT),
-                multiply_color(GRADIENT_BOT_NORMAL, HOVER_HIGHLIGHT),
+                multiply_color(
+                    theme.palette.gradient_top_normal,
+                    theme.palette.hover_highlight,
+                ),
+                multiply_color(
+                    theme.palette.gradient_bot_normal,
+                    theme.palette.hover_highlight,
+                ),
             )
         } else {
-            (GRADIENT_TOP_NORMAL, GRADIENT_BOT_NORMAL)
+            (
+                theme.palette.gradient_top_normal,
+                theme.palette.gradient_bot_normal,
+            )
         }
     };
 
@@ -776,6 +974,7 @@ pub fn button(
 
     border_3d(
         &mut display,
+        theme,
         x,
         y,
         width,
@@ -797,6 +996,7 @@ pub fn button(
 
 pub fn button_rounded(
     mut display: &mut DrawingDisplayListBuilder,
+    theme: &Theme,
     x: f32,
     y: f32,
     width: f32,
@@ -816,17 +1016,32 @@ pub fn button_rounded(
 
     let (gradient_top_color, gradient_bottom_color) = if is_pressed {
         (
-            multiply_color(GRADIENT_BOT_NORMAL, PRESSED_HIGHLIGHT),
-            multiply_color(GRADIENT_TOP_NORMAL, PRESSED_HIGHLIGHT),
+            multiply_color(
+                theme.palette.gradient_bot_normal,
+                theme.palette.pressed_highlight,
+            ),
+            multiply_color(
+                theme.palette.gradient_top_normal,
+                theme.palette.pressed_highlight,
+            ),
         )
     } else {
         if is_hover {
             (
-                multiply_color(GRADIENT_TOP_NORMAL, HOVER_HIGHLIGHT),
-                multiply_color(GRADIENT_BOT_NORMAL, HOVER_HIGHLIGHT),
+                multiply_color(
+                    theme.palette.gradient_top_normal,
+                    theme.palette.hover_highlight,
+                ),
+                multiply_color(
+                    theme.palette.gradient_bot_normal,
+                    theme.palette.hover_highlight,
+                ),
             )
         } else {
-            (GRADIENT_TOP_NORMAL, GRADIENT_BOT_NORMAL)
+            (
+                theme.palette.gradient_top_normal,
+                theme.palette.gradient_bot_normal,
+            )
         }
     };
 
@@ -859,6 +1074,7 @@ pub fn button_rounded(
 
     border_3d_rounded(
         &mut display,
+        theme,
         x,
         y,
         width,
@@ -2,3 +2,6 @@ pub mod default_theme;
 
 mod theme;
 pub use theme::*;
+
+#[cfg(feature = "serde")]
+mod theme_file;
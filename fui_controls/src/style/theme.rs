@@ -1,249 +1,365 @@
-use fui_core::{Property, TypeMapKey};
+use std::rc::Rc;
+
+use fui_core::{ControlContext, Property, TypeMapKey};
 use fui_drawing::Color;
 
 // ============================================================================
-// Inherited Attached Properties for Theming
+// Theme
 // ============================================================================
 
-/// Foreground color (for text and controls)
-pub struct Foreground;
-impl TypeMapKey for Foreground {
-    type Value = Property<Color>;
+/// Colors used by the default styles.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct ThemePalette {
+    pub foreground: [f32; 4],
+    pub background: [f32; 4],
+    pub edit_text_color: [f32; 4],
+
+    pub border_light1: [f32; 4],
+    pub border_light2: [f32; 4],
+    pub border_medium1: [f32; 4],
+    pub border_medium2: [f32; 4],
+    pub border_dark: [f32; 4],
+
+    pub gradient_top_normal: [f32; 4],
+    pub gradient_bot_normal: [f32; 4],
+
+    pub hover_highlight: [f32; 3],
+    pub pressed_highlight: [f32; 3],
+    pub focused_highlight: [f32; 3],
+
+    pub window_frame_color: [f32; 4],
+
+    pub progress_bar_foreground: [f32; 4],
+    pub progress_bar_background: [f32; 4],
+    pub scroll_bar_background: [f32; 4],
+    pub grid_splitter_background: [f32; 4],
+    pub grid_splitter_active_background: [f32; 4],
+    pub menu_foreground: [f32; 4],
+    pub menu_background: [f32; 4],
+    pub menu_hover_background: [f32; 4],
+    pub text_box_border_color: [f32; 4],
+    pub text_box_hover_border_color: [f32; 4],
+    pub text_selection_background: [f32; 4],
+    pub text_cursor_color: [f32; 4],
+    pub busy_indicator_overlay: [f32; 4],
+    pub tab_background: [f32; 4],
+    pub tab_foreground: [f32; 4],
+    pub tab_selected_foreground: [f32; 4],
+}
+
+/// Fonts used by the default styles.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct ThemeTypography {
+    pub font_family: String,
+    pub font_size: f32,
+}
+
+/// Sizes used by the default styles.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct ThemeMetrics {
+    pub border_size: f32,
+    pub check_box_button_size: f32,
+    pub check_box_margin: f32,
+    pub radio_button_size: f32,
+    pub radio_bullet_size: f32,
+    pub radio_margin: f32,
+    pub scroll_bar_start_margin: f32,
+    pub scroll_bar_end_margin: f32,
+    pub scroll_bar_side_margin: f32,
+    pub scroll_bar_min_thumb_size: f32,
+    pub grid_splitter_size: f32,
+    pub progress_bar_start_margin: f32,
+    pub progress_bar_end_margin: f32,
+    pub progress_bar_side_margin: f32,
+    pub progress_bar_min_size: f32,
+}
+
+///
+/// Palette, typography and metrics used by the default styles.
+///
+/// The theme is set on the root control of the window (see `CurrentTheme`)
+/// and can be replaced at runtime. Built-in themes are `Theme::dark()` (the default)
+/// and `Theme::light()`. With the `serde` feature themes can be loaded
+/// from TOML or JSON files (see `Theme::load()`).
+///
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct Theme {
+    pub palette: ThemePalette,
+    pub typography: ThemeTypography,
+    pub metrics: ThemeMetrics,
+}
+
+thread_local! {
+    static DEFAULT_THEME: Rc<Theme> = Rc::new(Theme::default());
+}
+
+impl Theme {
+    /// Returns the current theme of the control (the default one if not set).
+    pub fn get(control_context: &ControlContext) -> Rc<Theme> {
+        control_context
+            .get_inherited_value::<CurrentTheme>()
+            .map(|theme| theme.get())
+            .unwrap_or_else(|| DEFAULT_THEME.with(|theme| theme.clone()))
+    }
+
+    ///
+    /// Returns the current theme of the control as a property, so views built
+    /// from the theme can follow its changes (a constant one if the theme is not set).
+    ///
+    pub fn get_property(control_context: &ControlContext) -> Property<Rc<Theme>> {
+        control_context
+            .get_inherited_value::<CurrentTheme>()
+            .unwrap_or_else(|| Property::new(Theme::get(control_context)))
+    }
+}
+
+/// Theme of the control and its children (set by the window on its root control).
+pub struct CurrentTheme;
+impl TypeMapKey for CurrentTheme {
+    type Value = Property<Rc<Theme>>;
 }
 
-/// Font family for text
-pub struct FontFamily;
-impl TypeMapKey for FontFamily {
-    type Value = Property<String>;
-}
-
-/// Font size for text
-pub struct FontSize;
-impl TypeMapKey for FontSize {
-    type Value = Property<f32>;
-}
-
-/// Background color for controls
-pub struct Background;
-impl TypeMapKey for Background {
-    type Value = Property<Color>;
-}
+// ============================================================================
+// Inherited Attached Properties for Theming
+// ============================================================================
 
-/// Border color
-pub struct BorderColor;
-impl TypeMapKey for BorderColor {
-    type Value = Property<Color>;
-}
+///
+/// Declares an attached value that overrides a single value of the `Theme`
+/// for the control and its children.
+///
+macro_rules! theme_value {
+    ($(#[$attr:meta])* $name:ident: $value_type:ty => |$theme:ident| $value:expr) => {
+        $(#[$attr])*
+        pub struct $name;
+        impl TypeMapKey for $name {
+            type Value = Property<$value_type>;
+        }
+
+        impl $name {
+            /// Returns the value set on the control or its ancestors
+            /// or the value of the current theme.
+            pub fn get(control_context: &ControlContext) -> $value_type {
+                control_context
+                    .get_inherited_value::<$name>()
+                    .map(|p| p.get())
+                    .unwrap_or_else(|| {
+                        let $theme = Theme::get(control_context);
+                        $value
+                    })
+            }
+        }
+    };
+}
+
+theme_value!(
+    /// Foreground color (for text and controls)
+    Foreground: Color => |theme| theme.palette.foreground.into()
+);
+
+theme_value!(
+    /// Font family for text
+    FontFamily: String => |theme| theme.typography.font_family.clone()
+);
+
+theme_value!(
+    /// Font size for text
+    FontSize: f32 => |theme| theme.typography.font_size
+);
+
+theme_value!(
+    /// Background color for controls
+    Background: Color => |theme| theme.palette.background.into()
+);
+
+theme_value!(
+    /// Border color
+    BorderColor: Color => |theme| theme.palette.border_dark.into()
+);
 
 // ============================================================================
 // Border Colors
 // ============================================================================
 
-/// Border color variants for 3D borders
-pub struct BorderLight1;
-impl TypeMapKey for BorderLight1 {
-    type Value = Property<Color>;
-}
+theme_value!(
+    /// Border color variants for 3D borders
+    BorderLight1: Color => |theme| theme.palette.border_light1.into()
+);
 
-pub struct BorderLight2;
-impl TypeMapKey for BorderLight2 {
-    type Value = Property<Color>;
-}
+theme_value!(
+    BorderLight2: Color => |theme| theme.palette.border_light2.into()
+);
 
-pub struct BorderMedium1;
-impl TypeMapKey for BorderMedium1 {
-    type Value = Property<Color>;
-}
+theme_value!(
+    BorderMedium1: Color => |theme| theme.palette.border_medium1.into()
+);
 
-pub struct BorderMedium2;
-impl TypeMapKey for BorderMedium2 {
-    type Value = Property<Color>;
-}
+theme_value!(
+    BorderMedium2: Color => |theme| theme.palette.border_medium2.into()
+);
 
-pub struct BorderDark;
-impl TypeMapKey for BorderDark {
-    type Value = Property<Color>;
-}
+theme_value!(
+    BorderDark: Color => |theme| theme.palette.border_dark.into()
+);
 
 // ============================================================================
 // Gradient Colors
 // ============================================================================
 
-/// Gradient colors for button backgrounds
-pub struct GradientTopNormal;
-impl TypeMapKey for GradientTopNormal {
-    type Value = Property<Color>;
-}
+theme_value!(
+    /// Gradient colors for button backgrounds
+    GradientTopNormal: Color => |theme| theme.palette.gradient_top_normal.into()
+);
 
-pub struct GradientBotNormal;
-impl TypeMapKey for GradientBotNormal {
-    type Value = Property<Color>;
-}
+theme_value!(
+    GradientBotNormal: Color => |theme| theme.palette.gradient_bot_normal.into()
+);
 
 // ============================================================================
 // Highlight Multipliers
 // ============================================================================
 
-/// Color multipliers for hover state
-pub struct HoverHighlight;
-impl TypeMapKey for HoverHighlight {
-    type Value = Property<[f32; 3]>;
-}
+theme_value!(
+    /// Color multipliers for hover state
+    HoverHighlight: [f32; 3] => |theme| theme.palette.hover_highlight
+);
 
-/// Color multipliers for pressed state
-pub struct PressedHighlight;
-impl TypeMapKey for PressedHighlight {
-    type Value = Property<[f32; 3]>;
-}
+theme_value!(
+    /// Color multipliers for pressed state
+    PressedHighlight: [f32; 3] => |theme| theme.palette.pressed_highlight
+);
 
-/// Color multipliers for focused state
-pub struct FocusedHighlight;
-impl TypeMapKey for FocusedHighlight {
-    type Value = Property<[f32; 3]>;
-}
+theme_value!(
+    /// Color multipliers for focused state
+    FocusedHighlight: [f32; 3] => |theme| theme.palette.focused_highlight
+);
 
 // ============================================================================
 // Control Specific Colors
 // ============================================================================
 
-/// Progress bar foreground color
-pub struct ProgressBarForeground;
-impl TypeMapKey for ProgressBarForeground {
-    type Value = Property<Color>;
-}
-
-/// Progress bar background color
-pub struct ProgressBarBackground;
-impl TypeMapKey for ProgressBarBackground {
-    type Value = Property<Color>;
-}
-
-/// Scroll bar background color
-pub struct ScrollBarBackground;
-impl TypeMapKey for ScrollBarBackground {
-    type Value = Property<Color>;
-}
-
-/// Menu background color
-pub struct MenuBackground;
-impl TypeMapKey for MenuBackground {
-    type Value = Property<Color>;
-}
-
-/// Menu hover background color
-pub struct MenuHoverBackground;
-impl TypeMapKey for MenuHoverBackground {
-    type Value = Property<Color>;
-}
-
-/// TextBox edit border color
-pub struct TextBoxBorderColor;
-impl TypeMapKey for TextBoxBorderColor {
-    type Value = Property<Color>;
-}
-
-/// Text selection background color
-pub struct TextSelectionBackground;
-impl TypeMapKey for TextSelectionBackground {
-    type Value = Property<Color>;
-}
-
-/// Text cursor color
-pub struct TextCursorColor;
-impl TypeMapKey for TextCursorColor {
-    type Value = Property<Color>;
-}
-
-/// Busy indicator overlay color
-pub struct BusyIndicatorOverlay;
-impl TypeMapKey for BusyIndicatorOverlay {
-    type Value = Property<Color>;
-}
+theme_value!(
+    /// Progress bar foreground color
+    ProgressBarForeground: Color => |theme| theme.palette.progress_bar_foreground.into()
+);
+
+theme_value!(
+    /// Progress bar background color
+    ProgressBarBackground: Color => |theme| theme.palette.progress_bar_background.into()
+);
+
+theme_value!(
+    /// Scroll bar background color
+    ScrollBarBackground: Color => |theme| theme.palette.scroll_bar_background.into()
+);
+
+theme_value!(
+    /// Menu background color
+    MenuBackground: Color => |theme| theme.palette.menu_background.into()
+);
+
+theme_value!(
+    /// Menu hover background color
+    MenuHoverBackground: Color => |theme| theme.palette.menu_hover_background.into()
+);
+
+theme_value!(
+    /// TextBox edit border color
+    TextBoxBorderColor: Color => |theme| theme.palette.text_box_border_color.into()
+);
+
+theme_value!(
+    /// Text selection background color
+    TextSelectionBackground: Color => |theme| theme.palette.text_selection_background.into()
+);
+
+theme_value!(
+    /// Text cursor color
+    TextCursorColor: Color => |theme| theme.palette.text_cursor_color.into()
+);
+
+theme_value!(
+    /// Busy indicator overlay color
+    BusyIndicatorOverlay: Color => |theme| theme.palette.busy_indicator_overlay.into()
+);
 
 // ============================================================================
 // Size Constants as Attached Properties
 // ============================================================================
 
-/// Check box button size
-pub struct CheckBoxButtonSize;
-impl TypeMapKey for CheckBoxButtonSize {
-    type Value = Property<f32>;
-}
-
-/// Check box margin
-pub struct CheckBoxMargin;
-impl TypeMapKey for CheckBoxMargin {
-    type Value = Property<f32>;
-}
-
-/// Radio button size
-pub struct RadioButtonSize;
-impl TypeMapKey for RadioButtonSize {
-    type Value = Property<f32>;
-}
-
-/// Radio bullet size
-pub struct RadioBulletSize;
-impl TypeMapKey for RadioBulletSize {
-    type Value = Property<f32>;
-}
-
-/// Radio margin
-pub struct RadioMargin;
-impl TypeMapKey for RadioMargin {
-    type Value = Property<f32>;
-}
-
-/// Border size
-pub struct BorderSize;
-impl TypeMapKey for BorderSize {
-    type Value = Property<f32>;
-}
-
-/// Scroll bar margin (start)
-pub struct ScrollBarStartMargin;
-impl TypeMapKey for ScrollBarStartMargin {
-    type Value = Property<f32>;
-}
-
-/// Scroll bar margin (end)
-pub struct ScrollBarEndMargin;
-impl TypeMapKey for ScrollBarEndMargin {
-    type Value = Property<f32>;
-}
-
-/// Scroll bar margin (side)
-pub struct ScrollBarSideMargin;
-impl TypeMapKey for ScrollBarSideMargin {
-    type Value = Property<f32>;
-}
-
-/// Scroll bar minimum thumb size
-pub struct ScrollBarMinThumbSize;
-impl TypeMapKey for ScrollBarMinThumbSize {
-    type Value = Property<f32>;
-}
-
-/// Progress bar margin (start)
-pub struct ProgressBarStartMargin;
-impl TypeMapKey for ProgressBarStartMargin {
-    type Value = Property<f32>;
-}
-
-/// Progress bar margin (end)
-pub struct ProgressBarEndMargin;
-impl TypeMapKey for ProgressBarEndMargin {
-    type Value = Property<f32>;
-}
-
-/// Progress bar margin (side)
-pub struct ProgressBarSideMargin;
-impl TypeMapKey for ProgressBarSideMargin {
-    type Value = Property<f32>;
-}
-
-/// Progress bar minimum size
-pub struct ProgressBarMinSize;
-impl TypeMapKey for ProgressBarMinSize {
-    type Value = Property<f32>;
-}
+theme_value!(
+    /// Check box button size
+    CheckBoxButtonSize: f32 => |theme| theme.metrics.check_box_button_size
+);
+
+theme_value!(
+    /// Check box margin
+    CheckBoxMargin: f32 => |theme| theme.metrics.check_box_margin
+);
+
+theme_value!(
+    /// Radio button size
+    RadioButtonSize: f32 => |theme| theme.metrics.radio_button_size
+);
+
+theme_value!(
+    /// Radio bullet size
+    RadioBulletSize: f32 => |theme| theme.metrics.radio_bullet_size
+);
+
+theme_value!(
+    /// Radio margin
+    RadioMargin: f32 => |theme| theme.metrics.radio_margin
+);
+
+theme_value!(
+    /// Border size
+    BorderSize: f32 => |theme| theme.metrics.border_size
+);
+
+theme_value!(
+    /// Scroll bar margin (start)
+    ScrollBarStartMargin: f32 => |theme| theme.metrics.scroll_bar_start_margin
+);
+
+theme_value!(
+    /// Scroll bar margin (end)
+    ScrollBarEndMargin: f32 => |theme| theme.metrics.scroll_bar_end_margin
+);
+
+theme_value!(
+    /// Scroll bar margin (side)
+    ScrollBarSideMargin: f32 => |theme| theme.metrics.scroll_bar_side_margin
+);
+
+theme_value!(
+    /// Scroll bar minimum thumb size
+    ScrollBarMinThumbSize: f32 => |theme| theme.metrics.scroll_bar_min_thumb_size
+);
+
+theme_value!(
+    /// Progress bar margin (start)
+    ProgressBarStartMargin: f32 => |theme| theme.metrics.progress_bar_start_margin
+);
+
+theme_value!(
+    /// Progress bar margin (end)
+    ProgressBarEndMargin: f32 => |theme| theme.metrics.progress_bar_end_margin
+);
+
+theme_value!(
+    /// Progress bar margin (side)
+    ProgressBarSideMargin: f32 => |theme| theme.metrics.progress_bar_side_margin
+);
+
+theme_value!(
+    /// Progress bar minimum size
+    ProgressBarMinSize: f32 => |theme| theme.metrics.progress_bar_min_size
+);
@@ -0,0 +1,242 @@
+use std::path::Path;
+
+use anyhow::{anyhow, Result};
+use fui_core::SnapshotFormat;
+use serde_json::Value;
+
+use crate::style::Theme;
+
+///
+/// Loading themes from TOML and JSON files.
+///
+/// A theme file changes only the given values of the base theme
+/// (`"dark"` by default). Colors are given as `[r, g, b, a]` arrays
+/// or as `"#RRGGBB"` / `"#RRGGBBAA"` strings.
+///
+/// ```toml
+/// base = "light"
+///
+/// [palette]
+/// foreground = "#202020"
+/// progress_bar_foreground = [0.2, 0.6, 1.0, 1.0]
+/// hover_highlight = [1.1, 1.1, 1.1]
+///
+/// [typography]
+/// font_family = "OpenSans-Regular.ttf"
+/// font_size = 14.0
+///
+/// [metrics]
+/// border_size = 2.0
+/// ```
+///
+impl Theme {
+    /// Loads the theme from the file (TOML for the `.toml` extension and JSON otherwise).
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Theme> {
+        let path = path.as_ref();
+        let format = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => SnapshotFormat::Toml,
+            _ => SnapshotFormat::Json,
+        };
+        let text = std::fs::read_to_string(path)?;
+        Theme::parse(&text, format)
+    }
+
+    pub fn parse(text: &str, format: SnapshotFormat) -> Result<Theme> {
+        let mut value: Value = match format {
+            SnapshotFormat::Json => serde_json::from_str(text)?,
+            SnapshotFormat::Toml => toml::from_str(text)?,
+        };
+        let map = value
+            .as_object_mut()
+            .ok_or_else(|| anyhow!("Theme has to be a map!"))?;
+
+        let base = match map.remove("base") {
+            None => Theme::dark(),
+            Some(Value::String(name)) if name == "dark" => Theme::dark(),
+            Some(Value::String(name)) if name == "light" => Theme::light(),
+            Some(base) => return Err(anyhow!("Unknown base theme: {}", base)),
+        };
+
+        let mut theme = serde_json::to_value(&base)?;
+        merge(&mut theme, value, "")?;
+        Ok(serde_json::from_value(theme)?)
+    }
+
+    /// Writes all the values of the theme (a starting point for a theme file).
+    pub fn to_string(&self, format: SnapshotFormat) -> Result<String> {
+        Ok(match format {
+            SnapshotFormat::Json => serde_json::to_string_pretty(self)?,
+            SnapshotFormat::Toml => toml::to_string_pretty(self)?,
+        })
+    }
+}
+
+/// Overrides the values of the base theme with the values from the file.
+fn merge(base: &mut Value, value: Value, path: &str) -> Result<()> {
+    match (base, value) {
+        (Value::Object(base), Value::Object(map)) => {
+            for (key, value) in map {
+                let key_path = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", path, key)
+                };
+                match base.get_mut(&key) {
+                    Some(base_value) => merge(base_value, value, &key_path)?,
+                    // reported by serde (with the list of the expected names)
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+
+        (Value::Array(base), Value::String(color)) => {
+            *base = parse_color(&color, base.len())
+                .ok_or_else(|| anyhow!("Invalid color {:?} for {}", color, path))?
+                .into_iter()
+                .map(Value::from)
+                .collect();
+        }
+
+        (base, value) => *base = value,
+    }
+    Ok(())
+}
+
+/// Parses `#RRGGBB` or `#RRGGBBAA` color to the given number of components.
+fn parse_color(color: &str, components: usize) -> Option<Vec<f64>> {
+    let hex = color.strip_prefix('#')?;
+    if (hex.len() != 6 && hex.len() != 8) || !hex.is_ascii() {
+        return None;
+    }
+
+    let mut color = (0..hex.len() / 2)
+        .map(|i| u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).map(|c| c as f64 / 255.0))
+        .collect::<Result<Vec<_>, _>>()
+        .ok()?;
+    if color.len() < components {
+        // opaque by default
+        color.push(1.0);
+    }
+    if color.len() != components {
+        return None;
+    }
+    Some(color)
+}
+
+#[cfg(test)]
+mod tests {
+    use fui_core::SnapshotFormat;
+
+    use crate::style::Theme;
+
+    #[test]
+    fn test_parse_toml() {
+        let theme = Theme::parse(
+            r##"
+            base = "light"
+
+            [palette]
+            foreground = "#204080"
+            progress_bar_foreground = [0.2, 0.6, 1.0, 0.5]
+            hover_highlight = [1.1, 1.1, 1.1]
+
+            [metrics]
+            border_size = 2.0
+            "##,
+            SnapshotFormat::Toml,
+        )
+        .unwrap();
+
+        let base = Theme::light();
+        assert_eq!(
+            theme.palette.foreground,
+            [
+                32.0f32 / 255.0f32,
+                64.0f32 / 255.0f32,
+                128.0f32 / 255.0f32,
+                1.0f32
+            ]
+        );
+        assert_eq!(
+            theme.palette.progress_bar_foreground,
+            [0.2f32, 0.6f32, 1.0f32, 0.5f32]
+        );
+        assert_eq!(theme.palette.hover_highlight, [1.1f32, 1.1f32, 1.1f32]);
+        assert_eq!(theme.metrics.border_size, 2.0f32);
+
+        // the other values come from the base theme
+        assert_eq!(theme.palette.background, base.palette.background);
+        assert_eq!(theme.typography, base.typography);
+    }
+
+    #[test]
+    fn test_parse_json() {
+        let theme = Theme::parse(
+            r##"{
+                "palette": { "background": "#FF000080" },
+                "typography": { "font_size": 14.0 }
+            }"##,
+            SnapshotFormat::Json,
+        )
+        .unwrap();
+
+        // dark is the default base theme
+        let base = Theme::dark();
+        assert_eq!(
+            theme.palette.background,
+            [1.0f32, 0.0f32, 0.0f32, 128.0f32 / 255.0f32]
+        );
+        assert_eq!(theme.typography.font_size, 14.0f32);
+        assert_eq!(theme.typography.font_family, base.typography.font_family);
+        assert_eq!(theme.metrics, base.metrics);
+    }
+
+    #[test]
+    fn test_written_theme_is_parsed_back() {
+        for format in [SnapshotFormat::Toml, SnapshotFormat::Json] {
+            let text = Theme::light().to_string(format).unwrap();
+            assert_eq!(Theme::parse(&text, format).unwrap(), Theme::light());
+        }
+    }
+
+    #[test]
+    fn test_load_uses_the_file_extension() {
+        let path = std::env::temp_dir().join(format!("fui_theme_{}.toml", std::process::id()));
+        std::fs::write(&path, r#"base = "light""#).unwrap();
+        let theme = Theme::load(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(theme.unwrap(), Theme::light());
+    }
+
+    #[test]
+    fn test_errors() {
+        let errors = [
+            // unknown base theme
+            (r#"base = "blue""#, SnapshotFormat::Toml),
+            // invalid color
+            ("[palette]\nforeground = \"#12345\"", SnapshotFormat::Toml),
+            // unknown name
+            (
+                r#"{ "palette": { "foregroud": [1.0, 1.0, 1.0, 1.0] } }"#,
+                SnapshotFormat::Json,
+            ),
+            // wrong type
+            (
+                r#"{ "metrics": { "border_size": "thick" } }"#,
+                SnapshotFormat::Json,
+            ),
+            // not a map
+            ("[1.0, 2.0]", SnapshotFormat::Json),
+            // syntax error
+            ("{ palette", SnapshotFormat::Json),
+        ];
+        for (text, format) in errors {
+            assert!(Theme::parse(text, format).is_err(), "{}", text);
+        }
+
+        assert!(Theme::load("not_existing_theme.toml").is_err());
+    }
+}
@@ -0,0 +1,133 @@
+use std::future::Future;
+use std::rc::Rc;
+
+use fui_core::*;
+use fui_drawing::prelude::*;
+use fui_drawing::{DrawingDisplayListBuilder, DrawingFonts};
+
+//
+// Controls and helpers used by the tests.
+//
+
+///
+/// Control with a fixed desired size that draws nothing.
+///
+pub(crate) struct FixedSize {
+    pub size: Size,
+}
+
+impl FixedSize {
+    pub fn new(width: f32, height: f32, context: ViewContext) -> Rc<StyledControl<FixedSize>> {
+        StyledControl::new(
+            FixedSize {
+                size: Size::new(width, height),
+            },
+            Box::new(FixedSizeStyle),
+            context,
+        )
+    }
+}
+
+struct FixedSizeStyle;
+
+impl Style<FixedSize> for FixedSizeStyle {
+    fn setup(&mut self, _data: &mut FixedSize, _control_context: &ControlContext) {}
+
+    fn handle_event(
+        &mut self,
+        _data: &mut FixedSize,
+        _control_context: &ControlContext,
+        _drawing_context: &mut FuiDrawingContext,
+        _event_context: &mut dyn EventContext,
+        _event: ControlEvent,
+    ) {
+    }
+
+    fn measure(
+        &mut self,
+        data: &mut FixedSize,
+        _control_context: &ControlContext,
+        _drawing_context: &mut FuiDrawingContext,
+        _size: Size,
+    ) -> Size {
+        data.size
+    }
+
+    fn set_rect(
+        &mut self,
+        _data: &mut FixedSize,
+        _control_context: &ControlContext,
+        _drawing_context: &mut FuiDrawingContext,
+        _rect: Rect,
+    ) {
+    }
+
+    fn hit_test(
+        &self,
+        _data: &FixedSize,
+        _control_context: &ControlContext,
+        _point: Point,
+    ) -> Option<Rc<dyn ControlObject>> {
+        None
+    }
+
+    fn draw(
+        &mut self,
+        _data: &FixedSize,
+        _control_context: &ControlContext,
+        _drawing_context: &mut FuiDrawingContext,
+    ) {
+    }
+}
+
+/// Measures and arranges the control in the rect at (0, 0) like the window does.
+pub(crate) fn layout(control: &Rc<dyn ControlObject>, size: Size) {
+    with_drawing_context(|drawing_context| {
+        control.measure(drawing_context, size);
+        control.set_rect(
+            drawing_context,
+            Rect::new(0.0f32, 0.0f32, size.width, size.height),
+        );
+    });
+}
+
+/// Sends the event directly to the control.
+pub(crate) fn send_event(control: &Rc<dyn ControlObject>, event: ControlEvent) {
+    let mut event_processor = EventProcessor::new();
+    with_drawing_context(|drawing_context| {
+        control.handle_event(drawing_context, &mut event_processor, event)
+    });
+}
+
+fn with_drawing_context<F: FnOnce(&mut FuiDrawingContext)>(f: F) {
+    let mut fonts = DrawingFonts::default();
+    let mut display = DrawingDisplayListBuilder::new(None);
+    f(&mut FuiDrawingContext {
+        fonts: &mut fonts,
+        display: &mut display,
+    });
+}
+
+///
+/// Runs the test on a single threaded runtime with a local task set
+/// (required by `spawn_local`). The time is paused and advances
+/// automatically when all the tasks wait for timers.
+///
+pub(crate) fn run_local<F: Future>(future: F) -> F::Output {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .start_paused(true)
+        .build()
+        .unwrap();
+    tokio::task::LocalSet::new().block_on(&runtime, future)
+}
+
+///
+/// Lets the spawned tasks (e.g. property subscriptions)
+/// handle the pending changes without advancing the time.
+///
+pub(crate) async fn run_pending_tasks() {
+    for _ in 0..10 {
+        tokio::task::yield_now().await;
+    }
+}
@@ -1,18 +1,29 @@
-use fui_core::Property;
+use fui_core::{Property, UndoManager};
 
 pub struct TextBuffer {
     content: Property<String>,
     cursor_pos: usize,      // index measured in chars (chars().count())
     selection_start: usize, // start of the selection, no selection if equals cursor_pos
+    undo_manager: UndoManager,
 }
 
 impl TextBuffer {
+    /// Creates a buffer with its own undo history.
     pub fn new(content: Property<String>) -> Self {
+        let undo_manager = UndoManager::new();
+        undo_manager.track_property(&content, "Typing");
+        Self::with_undo_manager(content, undo_manager)
+    }
+
+    /// Creates a buffer that uses a shared undo manager.
+    /// The content property is expected to be tracked by the caller.
+    pub fn with_undo_manager(content: Property<String>, undo_manager: UndoManager) -> Self {
         let len = content.read().chars().count();
         Self {
             content,
             cursor_pos: len,
             selection_start: len,
+            undo_manager,
         }
     }
 
@@ -177,4 +188,22 @@ impl TextBuffer {
 
         self.set_cursor(pos, extend_selection);
     }
+
+    pub fn undo(&mut self) -> bool {
+        let changed = self.undo_manager.undo();
+        self.clamp_cursor();
+        changed
+    }
+
+    pub fn redo(&mut self) -> bool {
+        let changed = self.undo_manager.redo();
+        self.clamp_cursor();
+        changed
+    }
+
+    fn clamp_cursor(&mut self) {
+        let len = self.content.read().chars().count();
+        self.cursor_pos = self.cursor_pos.min(len);
+        self.selection_start = self.selection_start.min(len);
+    }
 }
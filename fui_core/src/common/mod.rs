@@ -7,6 +7,9 @@ pub use point::*;
 mod rect;
 pub use rect::*;
 
+mod render_transform;
+pub use render_transform::*;
+
 mod rounding;
 pub use rounding::*;
 
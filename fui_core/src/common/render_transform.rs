@@ -0,0 +1,46 @@
+use crate::common::Point;
+
+///
+/// Scale and translation applied to the drawing
+/// (a point is scaled first and then translated).
+///
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct RenderTransform {
+    pub offset_x: f32,
+    pub offset_y: f32,
+    pub scale_x: f32,
+    pub scale_y: f32,
+}
+
+impl RenderTransform {
+    pub fn new(offset_x: f32, offset_y: f32, scale_x: f32, scale_y: f32) -> Self {
+        RenderTransform {
+            offset_x,
+            offset_y,
+            scale_x,
+            scale_y,
+        }
+    }
+
+    pub fn transform_point(&self, point: Point) -> Point {
+        Point::new(
+            point.x * self.scale_x + self.offset_x,
+            point.y * self.scale_y + self.offset_y,
+        )
+    }
+
+    pub fn inverse_transform_point(&self, point: Point) -> Point {
+        Point::new(
+            if self.scale_x != 0.0f32 {
+                (point.x - self.offset_x) / self.scale_x
+            } else {
+                0.0f32
+            },
+            if self.scale_y != 0.0f32 {
+                (point.y - self.offset_y) / self.scale_y
+            } else {
+                0.0f32
+            },
+        )
+    }
+}
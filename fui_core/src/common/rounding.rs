@@ -1,3 +1,5 @@
+use crate::common::Rect;
+
 pub fn round_layout_value(value: f32, dpi_scale: f32) -> f32 {
     if (dpi_scale - 1.0f32).abs() < 0.0000015f32 {
         value.round()
@@ -12,3 +14,28 @@ pub fn round_layout_value(value: f32, dpi_scale: f32) -> f32 {
         new_value
     }
 }
+
+/// Rounds the edges of the `rect` to the physical pixels.
+///
+/// Rects that share an edge before rounding still share it after rounding,
+/// so the rounding error is distributed between neighbours.
+pub fn round_layout_rect(rect: Rect, dpi_scale: f32) -> Rect {
+    let left = round_layout_value(rect.x, dpi_scale);
+    let top = round_layout_value(rect.y, dpi_scale);
+    let right = round_layout_value(rect.x + rect.width, dpi_scale);
+    let bottom = round_layout_value(rect.y + rect.height, dpi_scale);
+    Rect::new(
+        left,
+        top,
+        if rect.width.is_finite() {
+            (right - left).max(0.0f32)
+        } else {
+            rect.width
+        },
+        if rect.height.is_finite() {
+            (bottom - top).max(0.0f32)
+        } else {
+            rect.height
+        },
+    )
+}
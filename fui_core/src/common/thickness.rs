@@ -69,4 +69,14 @@ impl Thickness {
             bottom,
         }
     }
+
+    /// Returns the thickness with the left and right sides swapped.
+    pub fn mirrored(&self) -> Self {
+        Thickness {
+            left: self.right,
+            top: self.top,
+            right: self.left,
+            bottom: self.bottom,
+        }
+    }
 }
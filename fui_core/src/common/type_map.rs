@@ -67,6 +67,23 @@ impl TypeMap {
         self.map.contains_key(&TypeId::of::<K>())
     }
 
+    /// Returns the type ids of all the keys in the map.
+    pub fn key_type_ids(&self) -> impl Iterator<Item = TypeId> + '_ {
+        self.map.keys().copied()
+    }
+
+    /// Checks if the key with the given type id exists in the map.
+    pub fn contains_type_id(&self, id: &TypeId) -> bool {
+        self.map.contains_key(id)
+    }
+
+    /// Removes the value of the key with the given type id.
+    ///
+    /// Returns true if the value was present.
+    pub fn remove_type_id(&mut self, id: &TypeId) -> bool {
+        self.map.remove(id).is_some()
+    }
+
     /// Returns an entry for the key `K` for in-place manipulation.
     ///
     /// This is analogous to `HashMap::entry` and allows for lazy initialization.
@@ -1,4 +1,4 @@
-use crate::{Rect, Size, TypeMapKey};
+use crate::{LayoutDirection, Rect, Size, TypeMapKey};
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum Alignment {
@@ -9,6 +9,21 @@ pub enum Alignment {
 }
 
 impl Alignment {
+    /// Swaps `Start` and `End` for the right-to-left direction.
+    pub fn mirrored(&self, direction: LayoutDirection) -> Alignment {
+        match self {
+            Alignment::Start if direction.is_right_to_left() => Alignment::End,
+            Alignment::End if direction.is_right_to_left() => Alignment::Start,
+            _ => *self,
+        }
+    }
+
+    ///
+    /// Positions the rect of the given `size` inside the `dest_rect`.
+    ///
+    /// The horizontal `Start` and `End` alignments are swapped
+    /// for the right-to-left `direction`.
+    ///
     pub fn apply(
         size: Size,
         dest_rect: Rect,
@@ -16,12 +31,14 @@ impl Alignment {
         vertical: Option<&Alignment>,
         default_horizontal_alignment: Alignment,
         default_vertical_alignment: Alignment,
+        direction: LayoutDirection,
     ) -> Rect {
         let horizontal = if let Some(h) = horizontal {
             *h
         } else {
             default_horizontal_alignment
-        };
+        }
+        .mirrored(direction);
 
         let vertical = if let Some(v) = vertical {
             *v
@@ -66,3 +83,15 @@ pub struct VerticalAlignment;
 impl TypeMapKey for VerticalAlignment {
     type Value = Alignment;
 }
+
+/// Default `HorizontalAlignment` of the control's children.
+pub struct HorizontalContentAlignment;
+impl TypeMapKey for HorizontalContentAlignment {
+    type Value = Alignment;
+}
+
+/// Default `VerticalAlignment` of the control's children.
+pub struct VerticalContentAlignment;
+impl TypeMapKey for VerticalContentAlignment {
+    type Value = Alignment;
+}
@@ -0,0 +1,108 @@
+use crate::{ControlContext, Property, TypeMapKey};
+
+//
+// Attached values
+//
+
+///
+/// Number of physical pixels per one logical pixel (inherited by all the children).
+///
+/// The window sets it on its root control. The layout works in logical pixels,
+/// the window scales the drawing and converts input positions.
+///
+/// Controls can watch the property to be notified when the window
+/// moves to a monitor with a different scale.
+///
+pub struct DpiScale;
+impl TypeMapKey for DpiScale {
+    type Value = Property<f32>;
+}
+
+impl DpiScale {
+    pub fn get(control_context: &ControlContext) -> f32 {
+        control_context
+            .get_inherited_value::<DpiScale>()
+            .map(|scale| scale.get())
+            .unwrap_or(1.0f32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::control::test_controls::{layout, FixedSize};
+    use crate::task::{run_local, run_pending_tasks};
+    use crate::{
+        Children, ControlBehavior, ControlObject, DpiScale, Property, Rect, Size, StyledControl,
+        TypeMap, Vertical, ViewContext,
+    };
+
+    fn create_tree(
+        dpi_scale: &Property<f32>,
+    ) -> (Rc<dyn ControlObject>, Vec<Rc<StyledControl<FixedSize>>>) {
+        let children: Vec<_> = (0..3)
+            .map(|_| FixedSize::new(20.0f32, 10.3f32, ViewContext::empty()))
+            .collect();
+        let mut attached_values = TypeMap::new();
+        attached_values.insert::<DpiScale>(dpi_scale.clone());
+        let root = Vertical::builder().build().to_view(
+            None,
+            ViewContext {
+                attached_values,
+                children: Children::MultipleStatic(
+                    children
+                        .iter()
+                        .map(|child| child.clone() as Rc<dyn ControlObject>)
+                        .collect(),
+                ),
+            },
+        );
+        (root, children)
+    }
+
+    #[test]
+    fn test_layout_is_rounded_to_physical_pixels() {
+        run_local(async {
+            let dpi_scale = Property::new(1.5f32);
+            let (root, children) = create_tree(&dpi_scale);
+            layout(&root, Size::new(100.0f32, 100.0f32));
+
+            assert_eq!(DpiScale::get(children[0].get_context()), 1.5f32);
+
+            // the edges are snapped to 1/1.5 of the logical pixel and the children still touch
+            assert_eq!(
+                children[0].get_rect(),
+                Rect::new(0.0f32, 0.0f32, 20.0f32, 15.0f32 / 1.5f32)
+            );
+            assert_eq!(
+                children[1].get_rect(),
+                Rect::new(
+                    0.0f32,
+                    15.0f32 / 1.5f32,
+                    20.0f32,
+                    31.0f32 / 1.5f32 - 15.0f32 / 1.5f32
+                )
+            );
+            assert_eq!(
+                children[2].get_rect(),
+                Rect::new(
+                    0.0f32,
+                    31.0f32 / 1.5f32,
+                    20.0f32,
+                    46.0f32 / 1.5f32 - 31.0f32 / 1.5f32
+                )
+            );
+
+            // the whole tree is laid out again for the new scale
+            dpi_scale.set(1.0f32);
+            run_pending_tasks().await;
+            layout(&root, Size::new(100.0f32, 100.0f32));
+
+            assert_eq!(
+                children[1].get_rect(),
+                Rect::new(0.0f32, 10.0f32, 20.0f32, 11.0f32)
+            );
+        });
+    }
+}
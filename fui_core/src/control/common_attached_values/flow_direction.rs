@@ -0,0 +1,158 @@
+use crate::{ControlContext, Keycode, Rect, TypeMapKey};
+
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum LayoutDirection {
+    #[default]
+    LeftToRight,
+    RightToLeft,
+}
+
+impl LayoutDirection {
+    pub fn is_right_to_left(&self) -> bool {
+        *self == LayoutDirection::RightToLeft
+    }
+
+    ///
+    /// Mirrors the `rect` horizontally inside the `container` for the right-to-left direction.
+    ///
+    /// Layouts can calculate positions of their children from left to right
+    /// and mirror them just before calling `set_rect()` on the child.
+    ///
+    pub fn mirror_rect(&self, rect: Rect, container: Rect) -> Rect {
+        if self.is_right_to_left() && container.width.is_finite() {
+            Rect::new(
+                container.x + container.width - (rect.x - container.x) - rect.width,
+                rect.y,
+                rect.width,
+                rect.height,
+            )
+        } else {
+            rect
+        }
+    }
+
+    /// Swaps the left and right arrow keys for the right-to-left direction.
+    pub fn mirror_keycode(&self, keycode: &Keycode) -> Keycode {
+        match keycode {
+            Keycode::Left if self.is_right_to_left() => Keycode::Right,
+            Keycode::Right if self.is_right_to_left() => Keycode::Left,
+            _ => keycode.clone(),
+        }
+    }
+}
+
+//
+// Attached values
+//
+
+/// Direction of the layout (inherited by all the children).
+/// Right-to-left direction mirrors positions of the children horizontally.
+pub struct FlowDirection;
+impl TypeMapKey for FlowDirection {
+    type Value = LayoutDirection;
+}
+
+impl FlowDirection {
+    pub fn get(control_context: &ControlContext) -> LayoutDirection {
+        control_context
+            .get_inherited_value::<FlowDirection>()
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::control::test_controls::{layout, FixedSize};
+    use crate::task::run_local;
+    use crate::{
+        Alignment, Children, ControlBehavior, ControlObject, FlowDirection, Grid, Horizontal,
+        HorizontalAlignment, LayoutDirection, Margin, Rect, Size, StyledControl, Thickness,
+        TypeMap, ViewContext,
+    };
+
+    fn create_right_to_left_context(children: &[Rc<StyledControl<FixedSize>>]) -> ViewContext {
+        let mut attached_values = TypeMap::new();
+        attached_values.insert::<FlowDirection>(LayoutDirection::RightToLeft);
+        ViewContext {
+            attached_values,
+            children: Children::MultipleStatic(
+                children
+                    .iter()
+                    .map(|child| child.clone() as Rc<dyn ControlObject>)
+                    .collect(),
+            ),
+        }
+    }
+
+    #[test]
+    fn test_mirror_rect() {
+        let container = Rect::new(10.0f32, 0.0f32, 100.0f32, 50.0f32);
+        let rect = Rect::new(20.0f32, 5.0f32, 30.0f32, 10.0f32);
+
+        assert_eq!(
+            LayoutDirection::LeftToRight.mirror_rect(rect, container),
+            rect
+        );
+        assert_eq!(
+            LayoutDirection::RightToLeft.mirror_rect(rect, container),
+            Rect::new(70.0f32, 5.0f32, 30.0f32, 10.0f32)
+        );
+    }
+
+    #[test]
+    fn test_children_are_placed_from_right_to_left() {
+        run_local(async {
+            let children = [
+                FixedSize::new(20.0f32, 10.0f32, ViewContext::empty()),
+                FixedSize::new(30.0f32, 10.0f32, ViewContext::empty()),
+            ];
+            let parent = Horizontal {}.to_view(None, create_right_to_left_context(&children));
+
+            layout(&parent, Size::new(100.0f32, 50.0f32));
+
+            assert_eq!(
+                FlowDirection::get(children[0].get_context()),
+                LayoutDirection::RightToLeft
+            );
+            assert_eq!(
+                children[0].get_rect(),
+                Rect::new(80.0f32, 0.0f32, 20.0f32, 10.0f32)
+            );
+            assert_eq!(
+                children[1].get_rect(),
+                Rect::new(50.0f32, 0.0f32, 30.0f32, 10.0f32)
+            );
+        });
+    }
+
+    #[test]
+    fn test_alignment_and_margin_are_mirrored() {
+        run_local(async {
+            let mut attached_values = TypeMap::new();
+            attached_values.insert::<HorizontalAlignment>(Alignment::Start);
+            attached_values.insert::<Margin>(Thickness::new(5.0f32, 0.0f32, 15.0f32, 0.0f32));
+            let child = FixedSize::new(
+                20.0f32,
+                10.0f32,
+                ViewContext {
+                    attached_values,
+                    children: Children::empty(),
+                },
+            );
+            let parent = Grid::builder().build().to_view(
+                None,
+                create_right_to_left_context(std::slice::from_ref(&child)),
+            );
+
+            layout(&parent, Size::new(100.0f32, 50.0f32));
+
+            // the start is on the right and the left margin is on the right side
+            assert_eq!(
+                child.get_rect(),
+                Rect::new(75.0f32, 0.0f32, 20.0f32, 50.0f32)
+            );
+        });
+    }
+}
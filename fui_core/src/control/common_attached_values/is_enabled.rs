@@ -0,0 +1,40 @@
+use crate::{ControlContext, Property, TypeMapKey};
+
+//
+// Attached values
+//
+
+///
+/// Disables the control and all its children when set to false.
+///
+/// Disabled controls don't get the input events, have the `is_disabled`
+/// visual state and are drawn semi-transparent.
+///
+pub struct IsEnabled;
+impl TypeMapKey for IsEnabled {
+    type Value = Property<bool>;
+}
+
+impl IsEnabled {
+    /// Returns false if the control or any of its ancestors is disabled.
+    pub fn get(control_context: &ControlContext) -> bool {
+        if let Some(is_enabled) = control_context.get_attached_value::<IsEnabled>()
+            && !is_enabled.get()
+        {
+            return false;
+        }
+
+        let mut current_parent = control_context.get_parent();
+        while let Some(parent) = current_parent {
+            let context = parent.get_context();
+            if let Some(is_enabled) = context.get_attached_value::<IsEnabled>()
+                && !is_enabled.get()
+            {
+                return false;
+            }
+            current_parent = context.get_parent();
+        }
+
+        true
+    }
+}
@@ -1,8 +1,29 @@
 mod alignment;
 pub use alignment::*;
 
+mod dpi_scale;
+pub use dpi_scale::*;
+
+mod flow_direction;
+pub use flow_direction::*;
+
+mod is_enabled;
+pub use is_enabled::*;
+
 mod margin;
 pub use margin::*;
 
+mod padding;
+pub use padding::*;
+
+mod size_constraints;
+pub use size_constraints::*;
+
+mod use_layout_rounding;
+pub use use_layout_rounding::*;
+
 mod visible;
 pub use visible::*;
+
+mod window_size;
+pub use window_size::*;
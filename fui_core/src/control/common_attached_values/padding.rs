@@ -0,0 +1,121 @@
+use crate::{Margin, Rect, Size, Thickness, TypeMapKey};
+
+//
+// Attached values
+//
+
+/// Space between the control's edges and its content.
+pub struct Padding;
+impl TypeMapKey for Padding {
+    type Value = Thickness;
+}
+
+impl Padding {
+    pub fn add_to_size(size: Size, padding: Option<&Thickness>) -> Size {
+        Margin::add_to_size(size, padding)
+    }
+
+    pub fn remove_from_size(size: Size, padding: Option<&Thickness>) -> Size {
+        Margin::remove_from_size(size, padding)
+    }
+
+    pub fn remove_from_rect(rect: Rect, padding: Option<&Thickness>) -> Rect {
+        Margin::remove_from_rect(rect, padding)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::control::test_controls::{layout, measure, FixedSize};
+    use crate::task::run_local;
+    use crate::{
+        Alignment, Children, ControlBehavior, ControlObject, Grid, HorizontalAlignment,
+        HorizontalContentAlignment, Padding, Rect, Size, StyledControl, Thickness, TypeMap,
+        VerticalContentAlignment, ViewContext,
+    };
+
+    fn create_parent(
+        attached_values: TypeMap,
+        children: &[Rc<StyledControl<FixedSize>>],
+    ) -> Rc<dyn ControlObject> {
+        Grid::builder().build().to_view(
+            None,
+            ViewContext {
+                attached_values,
+                children: Children::MultipleStatic(
+                    children
+                        .iter()
+                        .map(|child| child.clone() as Rc<dyn ControlObject>)
+                        .collect(),
+                ),
+            },
+        )
+    }
+
+    #[test]
+    fn test_padding_is_removed_from_content() {
+        run_local(async {
+            let child = FixedSize::new(30.0f32, 20.0f32, ViewContext::empty());
+            let mut attached_values = TypeMap::new();
+            attached_values.insert::<Padding>(Thickness::new(10.0f32, 5.0f32, 20.0f32, 15.0f32));
+            let parent = create_parent(attached_values, std::slice::from_ref(&child));
+
+            assert_eq!(
+                measure(&parent, Size::new(200.0f32, 100.0f32)),
+                Size::new(60.0f32, 40.0f32)
+            );
+
+            layout(&parent, Size::new(200.0f32, 100.0f32));
+            assert_eq!(
+                parent.get_rect(),
+                Rect::new(0.0f32, 0.0f32, 200.0f32, 100.0f32)
+            );
+            assert_eq!(
+                child.get_rect(),
+                Rect::new(10.0f32, 5.0f32, 170.0f32, 80.0f32)
+            );
+        });
+    }
+
+    #[test]
+    fn test_content_alignment() {
+        run_local(async {
+            let aligned_child = FixedSize::new(30.0f32, 20.0f32, ViewContext::empty());
+            let mut child_attached_values = TypeMap::new();
+            child_attached_values.insert::<HorizontalAlignment>(Alignment::Start);
+            let own_aligned_child = FixedSize::new(
+                30.0f32,
+                20.0f32,
+                ViewContext {
+                    attached_values: child_attached_values,
+                    children: Children::empty(),
+                },
+            );
+
+            let mut attached_values = TypeMap::new();
+            attached_values.insert::<Padding>(Thickness::all(10.0f32));
+            attached_values.insert::<HorizontalContentAlignment>(Alignment::End);
+            attached_values.insert::<VerticalContentAlignment>(Alignment::Center);
+            let parent = create_parent(
+                attached_values,
+                &[aligned_child.clone(), own_aligned_child.clone()],
+            );
+
+            layout(&parent, Size::new(200.0f32, 100.0f32));
+
+            // the content is aligned inside the padded rect
+            assert_eq!(
+                aligned_child.get_rect(),
+                Rect::new(160.0f32, 40.0f32, 30.0f32, 20.0f32)
+            );
+
+            // the child's own alignment wins over the content alignment
+            assert_eq!(
+                own_aligned_child.get_rect(),
+                Rect::new(10.0f32, 40.0f32, 30.0f32, 20.0f32)
+            );
+        });
+    }
+}
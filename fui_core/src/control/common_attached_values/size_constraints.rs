@@ -0,0 +1,241 @@
+use std::f32;
+
+use crate::{Alignment, ControlContext, Property, Rect, Size, TypeMapKey};
+
+//
+// Attached values
+//
+
+/// Explicit width of the control (without margin).
+pub struct Width;
+impl TypeMapKey for Width {
+    type Value = Property<f32>;
+}
+
+/// Explicit height of the control (without margin).
+pub struct Height;
+impl TypeMapKey for Height {
+    type Value = Property<f32>;
+}
+
+pub struct MinWidth;
+impl TypeMapKey for MinWidth {
+    type Value = Property<f32>;
+}
+
+pub struct MaxWidth;
+impl TypeMapKey for MaxWidth {
+    type Value = Property<f32>;
+}
+
+pub struct MinHeight;
+impl TypeMapKey for MinHeight {
+    type Value = Property<f32>;
+}
+
+pub struct MaxHeight;
+impl TypeMapKey for MaxHeight {
+    type Value = Property<f32>;
+}
+
+//
+// SizeConstraints
+//
+
+///
+/// Limits of the control size resolved from the `Width`, `Height`,
+/// `MinWidth`, `MaxWidth`, `MinHeight` and `MaxHeight` attached values.
+///
+/// The explicit size wins over the max value and the min value wins over both.
+///
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SizeConstraints {
+    pub min_width: f32,
+    pub max_width: f32,
+    pub min_height: f32,
+    pub max_height: f32,
+}
+
+impl SizeConstraints {
+    /// Returns `None` when the control has no size attached values.
+    pub fn from_context(control_context: &ControlContext) -> Option<SizeConstraints> {
+        let width = Self::get_value::<Width>(control_context);
+        let height = Self::get_value::<Height>(control_context);
+        let min_width = Self::get_value::<MinWidth>(control_context);
+        let max_width = Self::get_value::<MaxWidth>(control_context);
+        let min_height = Self::get_value::<MinHeight>(control_context);
+        let max_height = Self::get_value::<MaxHeight>(control_context);
+
+        if width.is_none()
+            && height.is_none()
+            && min_width.is_none()
+            && max_width.is_none()
+            && min_height.is_none()
+            && max_height.is_none()
+        {
+            return None;
+        }
+
+        let (min_width, max_width) = Self::resolve(
+            width,
+            min_width.unwrap_or(0.0f32),
+            max_width.unwrap_or(f32::INFINITY),
+        );
+        let (min_height, max_height) = Self::resolve(
+            height,
+            min_height.unwrap_or(0.0f32),
+            max_height.unwrap_or(f32::INFINITY),
+        );
+
+        Some(SizeConstraints {
+            min_width,
+            max_width,
+            min_height,
+            max_height,
+        })
+    }
+
+    fn get_value<K: TypeMapKey<Value = Property<f32>> + 'static>(
+        control_context: &ControlContext,
+    ) -> Option<f32> {
+        control_context
+            .get_attached_value::<K>()
+            .map(|value| value.get())
+    }
+
+    fn resolve(size: Option<f32>, min_size: f32, max_size: f32) -> (f32, f32) {
+        let max_size = size.unwrap_or(f32::INFINITY).min(max_size).max(min_size);
+        let min_size = size.unwrap_or(0.0f32).min(max_size).max(min_size);
+        (min_size, max_size)
+    }
+
+    pub fn constrain_size(&self, size: Size) -> Size {
+        Size::new(
+            size.width.min(self.max_width).max(self.min_width),
+            size.height.min(self.max_height).max(self.min_height),
+        )
+    }
+
+    ///
+    /// Limits the rect given to the control. When the control cannot fill
+    /// the rect, it is positioned according to its alignment
+    /// (stretched controls are centered).
+    ///
+    pub fn constrain_rect(
+        &self,
+        rect: Rect,
+        horizontal: Option<&Alignment>,
+        vertical: Option<&Alignment>,
+    ) -> Rect {
+        let size = self.constrain_size(Size::new(rect.width, rect.height));
+
+        let (x, width) = Self::position(rect.x, rect.width, size.width, horizontal);
+        let (y, height) = Self::position(rect.y, rect.height, size.height, vertical);
+
+        Rect::new(x, y, width, height)
+    }
+
+    fn position(pos: f32, space: f32, size: f32, alignment: Option<&Alignment>) -> (f32, f32) {
+        if size >= space || space.is_infinite() {
+            return (pos, size);
+        }
+        match alignment {
+            Some(Alignment::Start) => (pos, size),
+            Some(Alignment::End) => (pos + space - size, size),
+            _ => (pos + (space - size) / 2.0f32, size),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::control::test_controls::{layout, measure, FixedSize};
+    use crate::task::run_local;
+    use crate::{
+        Alignment, Children, ControlObject, Height, HorizontalAlignment, MaxHeight, MaxWidth,
+        MinHeight, MinWidth, Property, Rect, Size, TypeMap, VerticalAlignment, ViewContext, Width,
+    };
+
+    fn create_control(width: f32, height: f32, attached_values: TypeMap) -> Rc<dyn ControlObject> {
+        FixedSize::new(
+            width,
+            height,
+            ViewContext {
+                attached_values,
+                children: Children::empty(),
+            },
+        )
+    }
+
+    #[test]
+    fn test_explicit_size() {
+        run_local(async {
+            let mut attached_values = TypeMap::new();
+            attached_values.insert::<Width>(Property::new(50.0f32));
+            attached_values.insert::<Height>(Property::new(30.0f32));
+            let control = create_control(20.0f32, 10.0f32, attached_values);
+
+            assert_eq!(
+                measure(&control, Size::new(100.0f32, 100.0f32)),
+                Size::new(50.0f32, 30.0f32)
+            );
+
+            // the stretched control cannot fill the rect, so it is centered
+            layout(&control, Size::new(100.0f32, 100.0f32));
+            assert_eq!(
+                control.get_rect(),
+                Rect::new(25.0f32, 35.0f32, 50.0f32, 30.0f32)
+            );
+        });
+    }
+
+    #[test]
+    fn test_min_max_clamping() {
+        run_local(async {
+            let mut attached_values = TypeMap::new();
+            attached_values.insert::<MaxWidth>(Property::new(40.0f32));
+            attached_values.insert::<MinHeight>(Property::new(20.0f32));
+            attached_values.insert::<HorizontalAlignment>(Alignment::Start);
+            attached_values.insert::<VerticalAlignment>(Alignment::End);
+            let control = create_control(80.0f32, 5.0f32, attached_values);
+
+            assert_eq!(
+                measure(&control, Size::new(100.0f32, 100.0f32)),
+                Size::new(40.0f32, 20.0f32)
+            );
+
+            layout(&control, Size::new(100.0f32, 100.0f32));
+            assert_eq!(
+                control.get_rect(),
+                Rect::new(0.0f32, 80.0f32, 40.0f32, 20.0f32)
+            );
+
+            // the min height is kept in the lower rect, so the bottom aligned control overflows it
+            layout(&control, Size::new(50.0f32, 10.0f32));
+            assert_eq!(
+                control.get_rect(),
+                Rect::new(0.0f32, -10.0f32, 40.0f32, 20.0f32)
+            );
+        });
+    }
+
+    #[test]
+    fn test_min_wins_over_explicit_and_max() {
+        run_local(async {
+            let mut attached_values = TypeMap::new();
+            attached_values.insert::<Width>(Property::new(50.0f32));
+            attached_values.insert::<MaxWidth>(Property::new(30.0f32));
+            attached_values.insert::<MinWidth>(Property::new(40.0f32));
+            attached_values.insert::<Height>(Property::new(50.0f32));
+            attached_values.insert::<MaxHeight>(Property::new(30.0f32));
+            let control = create_control(10.0f32, 10.0f32, attached_values);
+
+            assert_eq!(
+                measure(&control, Size::new(100.0f32, 100.0f32)),
+                Size::new(40.0f32, 30.0f32)
+            );
+        });
+    }
+}
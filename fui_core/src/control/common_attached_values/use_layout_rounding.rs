@@ -0,0 +1,92 @@
+use crate::{ControlContext, TypeMapKey};
+
+//
+// Attached values
+//
+
+///
+/// Snaps the rects of the controls to the physical pixels (inherited by all the children,
+/// enabled by default).
+///
+/// The edges of the rects are rounded (not the sizes), so the children placed next to each
+/// other still touch and fill their parent exactly. It keeps the lines of the borders sharp.
+/// Disable it for the parts of the UI that are animated with sub-pixel precision.
+///
+pub struct UseLayoutRounding;
+impl TypeMapKey for UseLayoutRounding {
+    type Value = bool;
+}
+
+impl UseLayoutRounding {
+    pub fn get(control_context: &ControlContext) -> bool {
+        control_context
+            .get_inherited_value::<UseLayoutRounding>()
+            .unwrap_or(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::control::test_controls::{layout, FixedSize};
+    use crate::task::run_local;
+    use crate::{
+        round_layout_rect, Children, ControlBehavior, ControlObject, Rect, Size, TypeMap,
+        UseLayoutRounding, Vertical, ViewContext,
+    };
+
+    #[test]
+    fn test_shared_edge_is_kept() {
+        for dpi_scale in [1.0f32, 1.5f32] {
+            let left = Rect::new(0.0f32, 0.0f32, 10.3f32, 10.0f32);
+            let right = Rect::new(10.3f32, 0.0f32, 10.3f32, 10.0f32);
+
+            let rounded_left = round_layout_rect(left, dpi_scale);
+            let rounded_right = round_layout_rect(right, dpi_scale);
+
+            assert_eq!(rounded_left.x + rounded_left.width, rounded_right.x);
+            assert_eq!(
+                rounded_right.x + rounded_right.width,
+                (20.6f32 * dpi_scale).round() / dpi_scale
+            );
+        }
+
+        // the sizes differ, but the edges stay on the pixels
+        let rounded_left = round_layout_rect(Rect::new(0.0f32, 0.0f32, 10.4f32, 10.0f32), 1.0f32);
+        let rounded_right = round_layout_rect(Rect::new(10.4f32, 0.0f32, 10.4f32, 10.0f32), 1.0f32);
+        assert_eq!(rounded_left, Rect::new(0.0f32, 0.0f32, 10.0f32, 10.0f32));
+        assert_eq!(rounded_right, Rect::new(10.0f32, 0.0f32, 11.0f32, 10.0f32));
+    }
+
+    #[test]
+    fn test_rounding_can_be_disabled() {
+        run_local(async {
+            let children: Vec<_> = (0..2)
+                .map(|_| FixedSize::new(20.0f32, 10.3f32, ViewContext::empty()))
+                .collect();
+            let mut attached_values = TypeMap::new();
+            attached_values.insert::<UseLayoutRounding>(false);
+            let root = Vertical::builder().build().to_view(
+                None,
+                ViewContext {
+                    attached_values,
+                    children: Children::MultipleStatic(
+                        children
+                            .iter()
+                            .map(|child| child.clone() as Rc<dyn ControlObject>)
+                            .collect(),
+                    ),
+                },
+            );
+
+            layout(&root, Size::new(100.0f32, 100.0f32));
+
+            assert!(!UseLayoutRounding::get(children[1].get_context()));
+            assert_eq!(
+                children[1].get_rect(),
+                Rect::new(0.0f32, 10.3f32, 20.0f32, 10.3f32)
+            );
+        });
+    }
+}
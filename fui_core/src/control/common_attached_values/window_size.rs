@@ -0,0 +1,25 @@
+use crate::{ControlContext, Property, Size, TypeMapKey};
+
+//
+// Attached values
+//
+
+///
+/// Size of the window content in logical pixels (inherited by all the children).
+///
+/// The window sets it on its root control and updates it when the window is resized.
+/// Subscribe to the property to rearrange the UI for different window sizes
+/// (see `AdaptiveTrigger`).
+///
+pub struct WindowSize;
+impl TypeMapKey for WindowSize {
+    type Value = Property<Size>;
+}
+
+impl WindowSize {
+    pub fn get(control_context: &ControlContext) -> Option<Size> {
+        control_context
+            .get_inherited_value::<WindowSize>()
+            .map(|size| size.get())
+    }
+}
@@ -1,22 +1,35 @@
 use crate::view::ViewContext;
 use std::cell::{Cell, Ref, RefCell};
 use std::rc::{Rc, Weak};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::any::TypeId;
 
-use crate::{control::*, TypeMap, TypeMapKey};
-use crate::{observable::*, spawn_local_and_forget, Children, Rect, Services};
+use crate::{control::*, Styles, TypeMap, TypeMapKey};
+use crate::{
+    observable::*, spawn_local, spawn_local_and_forget, Children, Point, Rect, RenderTransform,
+    Services, Size,
+};
+use fui_drawing::DrawingDisplayList;
+use futures_signals::signal::{Signal, SignalExt};
 
 pub struct ControlContext {
     self_weak: RefCell<Option<Weak<dyn ControlObject>>>,
     parent: RefCell<Option<Weak<dyn ControlObject>>>,
     is_attached: Cell<bool>,
     children: Children,
+    realized_children: RefCell<Vec<Rc<dyn ControlObject>>>,
+    control_type: Cell<Option<TypeId>>,
 
     children_collection_changed_event_subscription: RefCell<Option<Subscription>>,
     dirty_event_subscriptions: RefCell<Vec<Subscription>>,
 
     attached_values: RefCell<TypeMap>,
+    style_sheet_values: RefCell<TypeMap>,
+    // ids of the style setters the style sheet values come from
+    style_sheet_setter_ids: RefCell<HashMap<TypeId, usize>>,
+    // defaults of the style, used when the value is neither given nor set by a style sheet
+    default_values: RefCell<TypeMap>,
+    has_visual_state_style_rules: Cell<bool>,
     inherited_values_cache: RefCell<TypeMap>,
     inherited_miss_cache: RefCell<HashSet<TypeId>>,
 
@@ -24,7 +37,25 @@ pub struct ControlContext {
 
     rect: Cell<Rect>,
 
+    // applied to the children when drawing (e.g. by Viewbox)
+    children_transform: Cell<Option<RenderTransform>>,
+
+    // layout caches: available size -> desired size, given rect -> final rect
+    measure_cache: Cell<Option<(Size, Size)>>,
+    arrange_cache: Cell<Option<(Rect, Rect)>>,
+
+    // content drawn by the control and its children (recorded for the rect)
+    display_list_cache: RefCell<Option<(Rect, Rc<DrawingDisplayList>)>>,
+
+    is_measure_dirty: Cell<bool>,
+    is_arrange_dirty: Cell<bool>,
+    is_measured_since_arrange: Cell<bool>,
     is_dirty: Cell<bool>,
+    is_repaint_requested: Cell<bool>,
+
+    visual_state: Property<VisualState>,
+    is_enabled_subscriptions: RefCell<Vec<Subscription>>,
+
     on_parent_attached: RefCell<Option<Box<dyn FnOnce(&ControlContext)>>>,
 }
 
@@ -35,14 +66,30 @@ impl ControlContext {
             parent: RefCell::new(None),
             is_attached: Cell::new(false),
             children: view_context.children,
+            realized_children: RefCell::new(Vec::new()),
+            control_type: Cell::new(None),
             children_collection_changed_event_subscription: RefCell::new(None),
             dirty_event_subscriptions: RefCell::new(Vec::new()),
             attached_values: RefCell::new(view_context.attached_values),
+            style_sheet_values: RefCell::new(TypeMap::new()),
+            style_sheet_setter_ids: RefCell::new(HashMap::new()),
+            default_values: RefCell::new(TypeMap::new()),
+            has_visual_state_style_rules: Cell::new(false),
             inherited_values_cache: RefCell::new(TypeMap::new()),
             inherited_miss_cache: RefCell::new(HashSet::new()),
             services: RefCell::new(None),
             rect: Cell::new(Rect::empty()),
+            children_transform: Cell::new(None),
+            measure_cache: Cell::new(None),
+            arrange_cache: Cell::new(None),
+            display_list_cache: RefCell::new(None),
+            is_measure_dirty: Cell::new(true),
+            is_arrange_dirty: Cell::new(true),
+            is_measured_since_arrange: Cell::new(false),
             is_dirty: Cell::new(true),
+            is_repaint_requested: Cell::new(false),
+            visual_state: Property::new(VisualState::default()),
+            is_enabled_subscriptions: RefCell::new(Vec::new()),
             on_parent_attached: RefCell::new(None),
         }
     }
@@ -59,6 +106,15 @@ impl ControlContext {
         *self.self_weak.borrow_mut() = Some(self_weak);
     }
 
+    /// Returns the type of the control data (e.g. `Button`) used by style sheet selectors.
+    pub fn get_control_type(&self) -> Option<TypeId> {
+        self.control_type.get()
+    }
+
+    pub fn set_control_type<T: 'static>(&self) {
+        self.control_type.set(Some(TypeId::of::<T>()));
+    }
+
     pub fn get_parent(&self) -> Option<Rc<dyn ControlObject>> {
         if let Some(ref test) = *self.parent.borrow() {
             test.upgrade()
@@ -69,8 +125,18 @@ impl ControlContext {
 
     pub fn set_parent(&self, parent_rc: &Rc<dyn ControlObject>) {
         *self.parent.borrow_mut() = Some(Rc::downgrade(parent_rc));
-        self.inherited_values_cache.borrow_mut().clear();
-        self.inherited_miss_cache.borrow_mut().clear();
+
+        // values inherited by the whole subtree may come from the new ancestors
+        self.clear_inherited_values_cache();
+
+        // the layout and the look may depend on inherited values
+        if self.measure_cache.get().is_some() {
+            // laid out before (e.g. a view reused by a virtualizing panel)
+            self.invalidate_descendants();
+        }
+        self.is_measure_dirty.set(true);
+        self.is_arrange_dirty.set(true);
+        self.is_dirty.set(true);
 
         if parent_rc.get_context().is_attached.get() {
             self.attach_tree();
@@ -81,6 +147,33 @@ impl ControlContext {
         &self.children
     }
 
+    ///
+    /// Returns the views created and placed by the control itself
+    /// outside of its children collection (see `set_realized_children()`).
+    ///
+    pub fn get_realized_children(&self) -> Vec<Rc<dyn ControlObject>> {
+        self.realized_children.borrow().clone()
+    }
+
+    ///
+    /// Sets the views created and placed by the control itself
+    /// outside of its children collection (e.g. the item views
+    /// realized by a virtualizing panel). The control is their parent.
+    ///
+    /// They are visited like the children when the tree is attached,
+    /// detached, invalidated, hit tested and when inherited values change.
+    ///
+    pub fn set_realized_children(&self, realized_children: Vec<Rc<dyn ControlObject>>) {
+        *self.realized_children.borrow_mut() = realized_children;
+    }
+
+    /// Returns the children followed by the realized children.
+    fn get_all_children(&self) -> Vec<Rc<dyn ControlObject>> {
+        let mut all_children: Vec<_> = self.children.into_iter().collect();
+        all_children.extend(self.realized_children.borrow().iter().cloned());
+        all_children
+    }
+
     pub fn set_children_collection_changed_event_subscription(&self, s: Option<Subscription>) {
         *self
             .children_collection_changed_event_subscription
@@ -92,7 +185,7 @@ impl ControlContext {
     }
 
     pub fn set_services(&self, services: Option<Services>) {
-        for child in self.children.into_iter() {
+        for child in self.get_all_children() {
             child.get_context().set_services(services.clone());
         }
         *self.services.borrow_mut() = services;
@@ -109,9 +202,12 @@ impl ControlContext {
             builder(self);
         }
 
+        self.apply_style_sheets();
+        self.watch_is_enabled();
+
         self.is_attached.set(true);
         self.get_self_rc().parent_attached();
-        for child in self.children.into_iter() {
+        for child in self.get_all_children() {
             child.get_context().attach_tree();
         }
     }
@@ -129,8 +225,10 @@ impl ControlContext {
             return;
         }
         self.is_attached.set(false);
+        self.is_enabled_subscriptions.borrow_mut().clear();
+        self.visual_state.set(VisualState::default());
         self.get_self_rc().parent_detached();
-        for child in self.children.into_iter() {
+        for child in self.get_all_children() {
             child.get_context().detach_tree();
         }
     }
@@ -139,8 +237,177 @@ impl ControlContext {
         self.is_attached.get()
     }
 
+    pub fn get_visual_state(&self) -> VisualState {
+        self.visual_state.get()
+    }
+
+    ///
+    /// Returns the property with the visual state (a clone sharing the state)
+    /// that can be watched or bound to.
+    ///
+    pub fn get_visual_state_property(&self) -> Property<VisualState> {
+        self.visual_state.clone()
+    }
+
+    ///
+    /// Changes the visual state of the control. It's called by `StyledControl`
+    /// when handling events, custom controls can use it to set the state themselves.
+    ///
+    /// The control is drawn again. Style sheets are applied again
+    /// if any of their rules depends on the visual state.
+    ///
+    pub fn set_visual_state(&self, visual_state: VisualState) {
+        if self.visual_state.get() == visual_state {
+            return;
+        }
+        self.visual_state.set(visual_state);
+
+        if self.has_visual_state_style_rules.get()
+            && self.is_attached.get()
+            && self.apply_style_sheets()
+        {
+            self.invalidate_measure();
+        } else {
+            self.invalidate_visual();
+        }
+    }
+
+    ///
+    /// Updates the `is_disabled` state when `IsEnabled` of the control
+    /// or any of its ancestors changes. Called when the control is attached.
+    ///
+    fn watch_is_enabled(&self) {
+        let mut properties = Vec::new();
+        if let Some(is_enabled) = self.get_attached_value::<IsEnabled>() {
+            properties.push(is_enabled.clone());
+        }
+        let mut current_parent = self.get_parent();
+        while let Some(parent) = current_parent {
+            let context = parent.get_context();
+            if let Some(is_enabled) = context.get_attached_value::<IsEnabled>() {
+                properties.push(is_enabled.clone());
+            }
+            current_parent = context.get_parent();
+        }
+
+        let self_weak = self.get_self_weak();
+        *self.is_enabled_subscriptions.borrow_mut() = properties
+            .iter()
+            .map(|is_enabled| {
+                let self_weak = self_weak.clone();
+                is_enabled.on_changed(move |_| {
+                    if let Some(control) = self_weak.upgrade() {
+                        let context = control.get_context();
+                        context.set_visual_state(VisualState {
+                            is_disabled: !IsEnabled::get(context),
+                            ..context.get_visual_state()
+                        });
+                    }
+                })
+            })
+            .collect();
+
+        // the state is known before the first layout
+        let is_disabled = !IsEnabled::get(self);
+        if self.visual_state.get().is_disabled != is_disabled {
+            self.visual_state.set(VisualState {
+                is_disabled,
+                ..self.visual_state.get()
+            });
+            if self.has_visual_state_style_rules.get() {
+                self.apply_style_sheets();
+            }
+        }
+    }
+
+    ///
+    /// Returns the value given to the control, the value set by a style sheet
+    /// or the default of the style (in this order).
+    ///
     pub fn get_attached_value<K: TypeMapKey + 'static>(&self) -> Option<Ref<'_, K::Value>> {
-        Ref::filter_map(self.attached_values.borrow(), |map| map.get::<K>()).ok()
+        Ref::filter_map(self.attached_values.borrow(), |map| map.get::<K>())
+            .or_else(|_| Ref::filter_map(self.style_sheet_values.borrow(), |map| map.get::<K>()))
+            .or_else(|_| Ref::filter_map(self.default_values.borrow(), |map| map.get::<K>()))
+            .ok()
+    }
+
+    fn has_attached_value(&self, key: &TypeId) -> bool {
+        self.attached_values.borrow().contains_type_id(key)
+            || self.style_sheet_values.borrow().contains_type_id(key)
+            || self.default_values.borrow().contains_type_id(key)
+    }
+
+    fn apply_style_sheets_to_tree(&self) {
+        if !self.is_attached.get() {
+            // applied when attached
+            return;
+        }
+
+        if self.apply_style_sheets() {
+            self.invalidate_measure();
+        }
+        for child in self.get_all_children() {
+            child.get_context().apply_style_sheets_to_tree();
+        }
+    }
+
+    ///
+    /// Sets the values of the style sheets of the control and its ancestors
+    /// (see `Styles`). Called when the control is attached
+    /// and when a style sheet of the control or its ancestor is replaced.
+    /// Returns true if any value has changed.
+    ///
+    fn apply_style_sheets(&self) -> bool {
+        let mut style_sheets = Vec::new();
+        if let Some(style_sheet) = self.attached_values.borrow().get::<Styles>() {
+            style_sheets.push(style_sheet.clone());
+        }
+        let mut current_parent = self.get_parent();
+        while let Some(parent) = current_parent {
+            let context = parent.get_context();
+            if let Some(style_sheet) = context.attached_values.borrow().get::<Styles>() {
+                style_sheets.push(style_sheet.clone());
+            }
+            current_parent = context.get_parent();
+        }
+
+        // the outer style sheets first, so the inner ones can override them
+        let mut values = TypeMap::new();
+        let mut setter_ids = HashMap::new();
+        for style_sheet in style_sheets.iter().rev() {
+            style_sheet.apply(self, &mut values, &mut setter_ids);
+        }
+        self.has_visual_state_style_rules.set(
+            style_sheets
+                .iter()
+                .any(|style_sheet| style_sheet.has_visual_state_rules()),
+        );
+
+        // only the values added, removed or set by another setter have changed
+        let changed_keys = {
+            let old_setter_ids = self.style_sheet_setter_ids.borrow();
+            setter_ids
+                .keys()
+                .chain(old_setter_ids.keys())
+                .filter(|key| setter_ids.get(key) != old_setter_ids.get(key))
+                .cloned()
+                .collect::<HashSet<_>>()
+        };
+        *self.style_sheet_values.borrow_mut() = values;
+        *self.style_sheet_setter_ids.borrow_mut() = setter_ids;
+
+        let is_changed = !changed_keys.is_empty();
+        if is_changed {
+            self.is_measure_dirty.set(true);
+            self.is_arrange_dirty.set(true);
+            self.is_dirty.set(true);
+        }
+        for key in changed_keys {
+            self.forget_inherited_value(&key);
+            self.notify_inherited_value_changed(key);
+        }
+
+        is_changed
     }
 
     pub fn get_inherited_value<K: TypeMapKey + 'static>(&self) -> Option<K::Value>
@@ -194,8 +461,87 @@ impl ControlContext {
         result
     }
 
+    ///
+    /// Replaces all the attached values of the control.
+    ///
+    /// Descendants that inherit any of the old or new values are notified
+    /// (see `notify_inherited_value_changed()`).
+    ///
     pub fn set_attached_values(&self, attached_values: TypeMap) {
-        *self.attached_values.borrow_mut() = attached_values;
+        let mut changed_keys = attached_values.key_type_ids().collect::<HashSet<_>>();
+        let old_attached_values =
+            std::mem::replace(&mut *self.attached_values.borrow_mut(), attached_values);
+        changed_keys.extend(old_attached_values.key_type_ids());
+
+        if changed_keys.contains(&TypeId::of::<Styles>()) {
+            self.apply_style_sheets_to_tree();
+        }
+        for key in changed_keys {
+            self.forget_inherited_value(&key);
+            self.notify_inherited_value_changed(key);
+        }
+    }
+
+    ///
+    /// Sets the default of the attached value (used by styles, e.g. for `Padding`).
+    /// The value given to the control and the value set by a style sheet take precedence.
+    ///
+    pub fn set_default_attached_value<K: TypeMapKey + 'static>(&self, value: K::Value) {
+        self.default_values.borrow_mut().insert::<K>(value);
+    }
+
+    ///
+    /// Replaces the attached value at runtime (e.g. by `AdaptiveTrigger`).
+    /// The parent is measured again, so it can read the new value.
+    /// Descendants that inherit the value are notified
+    /// (see `notify_inherited_value_changed()`).
+    ///
+    pub fn set_attached_value<K: TypeMapKey + 'static>(&self, value: K::Value) {
+        self.attached_values.borrow_mut().insert::<K>(value);
+        self.forget_inherited_value(&TypeId::of::<K>());
+        self.invalidate_measure();
+        self.notify_inherited_value_changed(TypeId::of::<K>());
+
+        if TypeId::of::<K>() == TypeId::of::<Styles>() {
+            self.apply_style_sheets_to_tree();
+        }
+    }
+
+    ///
+    /// The attached value with the given key type id has changed on this control.
+    ///
+    /// Descendants forget the cached value (or the cached miss) and the ones
+    /// that have read it are measured and drawn again. Subtrees of descendants
+    /// that set the value themselves are not affected.
+    ///
+    pub fn notify_inherited_value_changed(&self, key: TypeId) {
+        for child in self.get_all_children() {
+            let context = child.get_context();
+            if context.has_attached_value(&key) {
+                continue;
+            }
+
+            if context.forget_inherited_value(&key) {
+                context.invalidate_measure();
+            }
+
+            context.notify_inherited_value_changed(key);
+        }
+    }
+
+    /// Removes the key from the caches. Returns true if the value has been read.
+    fn forget_inherited_value(&self, key: &TypeId) -> bool {
+        let was_cached = self.inherited_values_cache.borrow_mut().remove_type_id(key);
+        let was_missed = self.inherited_miss_cache.borrow_mut().remove(key);
+        was_cached || was_missed
+    }
+
+    fn clear_inherited_values_cache(&self) {
+        self.inherited_values_cache.borrow_mut().clear();
+        self.inherited_miss_cache.borrow_mut().clear();
+        for child in self.get_all_children() {
+            child.get_context().clear_inherited_values_cache();
+        }
     }
 
     pub fn get_rect(&self) -> Rect {
@@ -206,32 +552,230 @@ impl ControlContext {
         self.rect.set(rect);
     }
 
+    pub fn get_children_transform(&self) -> Option<RenderTransform> {
+        self.children_transform.get()
+    }
+
+    ///
+    /// Sets the transform the control applies to its children when drawing.
+    /// It is used to map the positions of the events and hit testing.
+    ///
+    pub fn set_children_transform(&self, transform: Option<RenderTransform>) {
+        self.children_transform.set(transform);
+    }
+
+    ///
+    /// Converts the point from the window coordinates
+    /// to the coordinates of the control (the one its rect is in).
+    ///
+    pub fn map_point_from_window(&self, point: Point) -> Point {
+        match self.get_parent() {
+            Some(parent) => {
+                let parent_context = parent.get_context();
+                let point = parent_context.map_point_from_window(point);
+                match parent_context.get_children_transform() {
+                    Some(transform) => transform.inverse_transform_point(point),
+                    None => point,
+                }
+            }
+            None => point,
+        }
+    }
+
     pub fn is_dirty(&self) -> bool {
         self.is_dirty.get()
     }
 
+    ///
+    /// Marking control as dirty invalidates its measure
+    /// (see `invalidate_measure()`).
+    /// Clearing it marks the control as repainted.
+    ///
     pub fn set_is_dirty(&self, is_dirty: bool) {
-        let is_change = self.is_dirty.get() != is_dirty;
-        self.is_dirty.set(is_dirty);
-
         if is_dirty {
-            match self.get_parent() {
-                Some(ref parent) => parent.get_context().set_is_dirty(is_dirty),
-                _ => {
-                    // this is a root control
-                    if is_change {
-                        // post window repaint
-                        if let Some(services) = self.services.borrow().clone() {
-                            spawn_local_and_forget(async move {
-                                services.get_window_service().map(|s| s.repaint());
-                            });
-                        }
-                    }
-                }
+            self.invalidate_measure();
+        } else {
+            self.is_dirty.set(false);
+            self.is_repaint_requested.set(false);
+        }
+    }
+
+    pub fn is_measure_dirty(&self) -> bool {
+        self.is_measure_dirty.get()
+    }
+
+    pub fn is_arrange_dirty(&self) -> bool {
+        self.is_arrange_dirty.get()
+    }
+
+    ///
+    /// The desired size of the control may have changed.
+    ///
+    /// The control and all its ancestors will be measured and arranged again
+    /// during the next layout pass. Other controls reuse their cached sizes.
+    ///
+    pub fn invalidate_measure(&self) {
+        self.is_measure_dirty.set(true);
+        self.is_arrange_dirty.set(true);
+        self.is_dirty.set(true);
+        match self.get_parent() {
+            Some(ref parent) => parent.get_context().invalidate_measure(),
+            _ => self.request_repaint(),
+        }
+    }
+
+    ///
+    /// The position of the control children may have changed,
+    /// but its desired size stays the same.
+    ///
+    pub fn invalidate_arrange(&self) {
+        self.is_arrange_dirty.set(true);
+        self.is_dirty.set(true);
+        match self.get_parent() {
+            Some(ref parent) => parent.get_context().invalidate_arrange(),
+            _ => self.request_repaint(),
+        }
+    }
+
+    ///
+    /// Only the look of the control has changed. Requests repaint
+    /// without running the layout.
+    ///
+    /// The cached display lists of the control and its ancestors are dropped,
+    /// other controls reuse them.
+    ///
+    pub fn invalidate_visual(&self) {
+        self.is_dirty.set(true);
+        match self.get_parent() {
+            Some(ref parent) => parent.get_context().invalidate_visual(),
+            _ => self.request_repaint(),
+        }
+    }
+
+    ///
+    /// Everything may have changed (e.g. the DPI scale).
+    ///
+    /// The layout and drawing caches of the control and all its descendants are dropped.
+    ///
+    pub fn invalidate_tree(&self) {
+        self.invalidate_descendants();
+        self.invalidate_measure();
+    }
+
+    fn invalidate_descendants(&self) {
+        for child in self.get_all_children() {
+            let context = child.get_context();
+            context.is_measure_dirty.set(true);
+            context.is_arrange_dirty.set(true);
+            context.is_dirty.set(true);
+            context.invalidate_descendants();
+        }
+    }
+
+    // called on the root control
+    fn request_repaint(&self) {
+        if self.is_repaint_requested.get() {
+            return;
+        }
+        self.is_repaint_requested.set(true);
+
+        // post window repaint
+        if let Some(services) = self.services.borrow().clone() {
+            spawn_local_and_forget(async move {
+                services.get_window_service().map(|s| s.repaint());
+            });
+        }
+    }
+
+    ///
+    /// Returns the desired size remembered from the last measure,
+    /// if the control is not invalidated and was measured
+    /// with the same available size.
+    ///
+    pub fn get_cached_measure(&self, available_size: Size) -> Option<Size> {
+        if self.is_measure_dirty.get() {
+            return None;
+        }
+        match self.measure_cache.get() {
+            Some((size, desired_size)) if size == available_size => Some(desired_size),
+            _ => None,
+        }
+    }
+
+    ///
+    /// Returns the available size of the last measure.
+    ///
+    pub fn get_measure_available_size(&self) -> Option<Size> {
+        self.measure_cache.get().map(|(available_size, _)| available_size)
+    }
+
+    ///
+    /// Tells if the control was measured (not from the cache) after the last arrange.
+    /// If not, the rects of its children hold the final rects
+    /// instead of the desired sizes.
+    ///
+    pub fn is_measured_since_arrange(&self) -> bool {
+        self.is_measured_since_arrange.get()
+    }
+
+    pub fn set_measure_result(&self, available_size: Size, desired_size: Size) {
+        self.measure_cache.set(Some((available_size, desired_size)));
+        self.is_measure_dirty.set(false);
+        self.is_measured_since_arrange.set(true);
+
+        // children may need to be placed differently
+        self.is_arrange_dirty.set(true);
+
+        LayoutStatistics::add_measure();
+    }
+
+    ///
+    /// Returns the final rect remembered from the last arrange,
+    /// if the control is not invalidated and was arranged
+    /// with the same rect.
+    ///
+    pub fn get_cached_arrange(&self, rect: Rect) -> Option<Rect> {
+        if self.is_arrange_dirty.get() {
+            return None;
+        }
+        match self.arrange_cache.get() {
+            Some((given_rect, final_rect)) if given_rect == rect => Some(final_rect),
+            _ => None,
+        }
+    }
+
+    pub fn set_arrange_result(&self, rect: Rect, final_rect: Rect) {
+        self.arrange_cache.set(Some((rect, final_rect)));
+        self.is_arrange_dirty.set(false);
+        self.is_measured_since_arrange.set(false);
+
+        LayoutStatistics::add_arrange();
+    }
+
+    ///
+    /// Returns the display list recorded during the last draw,
+    /// if the control is not invalidated and has not moved since then.
+    ///
+    pub fn get_cached_display_list(&self, rect: Rect) -> Option<Rc<DrawingDisplayList>> {
+        if self.is_dirty.get() {
+            return None;
+        }
+        match *self.display_list_cache.borrow() {
+            Some((cached_rect, ref display_list)) if cached_rect == rect => {
+                Some(display_list.clone())
             }
+            _ => None,
         }
     }
 
+    pub fn set_cached_display_list(&self, rect: Rect, display_list: Rc<DrawingDisplayList>) {
+        *self.display_list_cache.borrow_mut() = Some((rect, display_list));
+        self.is_dirty.set(false);
+
+        LayoutStatistics::add_draw();
+    }
+
+    #[cfg_attr(feature = "diagnostics", track_caller)]
     pub fn dirty_watch_property<T>(&self, property: &Property<T>)
     where
         T: 'static + Clone + PartialEq,
@@ -246,6 +790,24 @@ impl ControlContext {
             }));
     }
 
+    /// Marks the control as dirty every time the signal emits a value.
+    pub(crate) fn dirty_watch_signal<S>(&self, signal: S)
+    where
+        S: 'static + Signal<Item = ()>,
+    {
+        let self_weak = self.self_weak.borrow().clone().unwrap();
+        self.dirty_event_subscriptions
+            .borrow_mut()
+            .push(Subscription::SpawnLocal(spawn_local(signal.for_each(
+                move |_| {
+                    if let Some(control) = self_weak.upgrade() {
+                        control.get_context().set_is_dirty(true);
+                    }
+                    async {}
+                },
+            ))));
+    }
+
     pub fn dirty_watch_attached_properties(&self) {
         let attached_values = self.attached_values.borrow();
         if let Some(visible) = attached_values.get::<Visible>() {
@@ -257,5 +819,114 @@ impl ControlContext {
                 });
             }));
         }
+
+        // set by the window on its root control
+        if let Some(dpi_scale) = attached_values.get::<DpiScale>() {
+            let self_weak = self.self_weak.borrow().clone().unwrap();
+            let mut subs = self.dirty_event_subscriptions.borrow_mut();
+            subs.push(dpi_scale.on_changed(move |_| {
+                self_weak.upgrade().map(|control| {
+                    control.get_context().invalidate_tree();
+                });
+            }));
+        }
+
+        // size limits
+        let sizes = [
+            attached_values.get::<Width>(),
+            attached_values.get::<Height>(),
+            attached_values.get::<MinWidth>(),
+            attached_values.get::<MaxWidth>(),
+            attached_values.get::<MinHeight>(),
+            attached_values.get::<MaxHeight>(),
+        ];
+        for size in sizes.into_iter().flatten() {
+            let self_weak = self.self_weak.borrow().clone().unwrap();
+            let mut subs = self.dirty_event_subscriptions.borrow_mut();
+            subs.push(size.on_changed(move |_| {
+                self_weak.upgrade().map(|control| {
+                    control.get_context().invalidate_measure();
+                });
+            }));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::control::test_controls::{layout, FixedSize};
+    use crate::task::run_local;
+    use crate::{Children, ControlObject, Size, TypeMap, TypeMapKey, Vertical, ViewContext};
+
+    struct TestValue;
+    impl TypeMapKey for TestValue {
+        type Value = i32;
+    }
+
+    #[test]
+    fn test_inherited_value_change_notifies_descendants() {
+        run_local(async {
+            let reader = FixedSize::new(10.0f32, 10.0f32, ViewContext::empty());
+            let other = FixedSize::new(10.0f32, 10.0f32, ViewContext::empty());
+            let mut attached_values = TypeMap::new();
+            attached_values.insert::<TestValue>(5);
+            let owner = FixedSize::new(
+                10.0f32,
+                10.0f32,
+                ViewContext {
+                    attached_values,
+                    children: Children::empty(),
+                },
+            );
+            let root = Vertical::builder().build().to_view(
+                None,
+                ViewContext {
+                    attached_values: TypeMap::new(),
+                    children: Children::MultipleStatic(vec![
+                        reader.clone() as Rc<dyn ControlObject>,
+                        other.clone(),
+                        owner.clone(),
+                    ]),
+                },
+            );
+
+            // the miss is cached
+            assert_eq!(
+                reader.get_context().get_inherited_value::<TestValue>(),
+                None
+            );
+            assert_eq!(
+                owner.get_context().get_inherited_value::<TestValue>(),
+                Some(5)
+            );
+            layout(&root, Size::new(100.0f32, 100.0f32));
+            assert!(!reader.get_context().is_measure_dirty());
+
+            root.get_context().set_attached_value::<TestValue>(1);
+
+            // only the descendant that has read the value is measured again
+            assert!(reader.get_context().is_measure_dirty());
+            assert!(!other.get_context().is_measure_dirty());
+            assert!(!owner.get_context().is_measure_dirty());
+            assert_eq!(
+                reader.get_context().get_inherited_value::<TestValue>(),
+                Some(1)
+            );
+            assert_eq!(
+                owner.get_context().get_inherited_value::<TestValue>(),
+                Some(5)
+            );
+
+            // the cached value is replaced too
+            layout(&root, Size::new(100.0f32, 100.0f32));
+            root.get_context().set_attached_value::<TestValue>(2);
+            assert!(reader.get_context().is_measure_dirty());
+            assert_eq!(
+                reader.get_context().get_inherited_value::<TestValue>(),
+                Some(2)
+            );
+        });
     }
 }
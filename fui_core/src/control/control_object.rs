@@ -20,9 +20,14 @@ pub trait ControlObject: ControlBehavior {
         let rect = self.get_rect();
         let mut res = Vec::new();
         if point.is_inside(&rect) {
-            let children = self.get_context().get_children();
-            for child in children {
-                res.append(&mut child.get_controls_at_point(point));
+            let child_point = match self.get_context().get_children_transform() {
+                Some(transform) => transform.inverse_transform_point(point),
+                None => point,
+            };
+            let context = self.get_context();
+            let children = context.get_children().into_iter();
+            for child in children.chain(context.get_realized_children()) {
+                res.append(&mut child.get_controls_at_point(child_point));
             }
             res.push(self.get_context().get_self_weak())
         }
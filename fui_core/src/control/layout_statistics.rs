@@ -0,0 +1,204 @@
+use std::cell::Cell;
+
+thread_local! {
+    static LAYOUT_STATISTICS: Cell<LayoutStatistics> = Cell::new(LayoutStatistics::default());
+}
+
+///
+/// Counts the controls that were really measured, arranged and drawn
+/// (not served from the layout and display list caches) on the current thread.
+///
+/// # Example
+///
+/// ```ignore
+/// LayoutStatistics::reset();
+/// text.set("changed".to_string());
+/// window.render();
+/// assert_eq!(LayoutStatistics::get().measure_count, 3);
+/// ```
+///
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct LayoutStatistics {
+    pub measure_count: usize,
+    pub arrange_count: usize,
+    pub draw_count: usize,
+}
+
+impl LayoutStatistics {
+    pub fn get() -> LayoutStatistics {
+        LAYOUT_STATISTICS.with(|statistics| statistics.get())
+    }
+
+    pub fn reset() {
+        LAYOUT_STATISTICS.with(|statistics| statistics.set(LayoutStatistics::default()));
+    }
+
+    pub(crate) fn add_measure() {
+        LAYOUT_STATISTICS.with(|statistics| {
+            let mut value = statistics.get();
+            value.measure_count += 1;
+            statistics.set(value);
+        });
+    }
+
+    pub(crate) fn add_arrange() {
+        LAYOUT_STATISTICS.with(|statistics| {
+            let mut value = statistics.get();
+            value.arrange_count += 1;
+            statistics.set(value);
+        });
+    }
+
+    pub(crate) fn add_draw() {
+        LAYOUT_STATISTICS.with(|statistics| {
+            let mut value = statistics.get();
+            value.draw_count += 1;
+            statistics.set(value);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::control::test_controls::{draw, layout, FixedSize};
+    use crate::task::run_local;
+    use crate::{
+        Children, ControlBehavior, ControlObject, LayoutStatistics, Rect, Size, StyledControl,
+        TypeMap, Vertical, ViewContext,
+    };
+
+    fn create_tree() -> (Rc<dyn ControlObject>, Vec<Rc<StyledControl<FixedSize>>>) {
+        let children: Vec<_> = (1..=3)
+            .map(|i| FixedSize::new(10.0f32 * i as f32, 20.0f32, ViewContext::empty()))
+            .collect();
+        let root = Vertical::builder().build().to_view(
+            None,
+            ViewContext {
+                attached_values: TypeMap::new(),
+                children: Children::MultipleStatic(
+                    children
+                        .iter()
+                        .map(|child| child.clone() as Rc<dyn ControlObject>)
+                        .collect(),
+                ),
+            },
+        );
+        (root, children)
+    }
+
+    fn statistics(
+        measure_count: usize,
+        arrange_count: usize,
+        draw_count: usize,
+    ) -> LayoutStatistics {
+        LayoutStatistics {
+            measure_count,
+            arrange_count,
+            draw_count,
+        }
+    }
+
+    #[test]
+    fn test_measure_cache() {
+        run_local(async {
+            let (root, children) = create_tree();
+            let size = Size::new(100.0f32, 100.0f32);
+
+            LayoutStatistics::reset();
+            layout(&root, size);
+            assert_eq!(LayoutStatistics::get(), statistics(4, 4, 0));
+
+            // nothing has changed
+            LayoutStatistics::reset();
+            layout(&root, size);
+            assert_eq!(LayoutStatistics::get(), statistics(0, 0, 0));
+
+            // the siblings of the invalidated control use their caches
+            children[1].get_context().invalidate_measure();
+            LayoutStatistics::reset();
+            layout(&root, size);
+            assert_eq!(LayoutStatistics::get(), statistics(2, 2, 0));
+            assert_eq!(
+                children[2].get_rect(),
+                Rect::new(0.0f32, 40.0f32, 30.0f32, 20.0f32)
+            );
+
+            // the new available size misses the caches of the whole tree
+            LayoutStatistics::reset();
+            layout(&root, Size::new(200.0f32, 100.0f32));
+            assert_eq!(LayoutStatistics::get(), statistics(4, 4, 0));
+        });
+    }
+
+    #[test]
+    fn test_arrange_cache() {
+        run_local(async {
+            let (root, children) = create_tree();
+            let size = Size::new(100.0f32, 100.0f32);
+            layout(&root, size);
+
+            // the measure comes from the cache, restoring the desired sizes
+            // of the children before the arrange is not counted
+            root.get_context().invalidate_arrange();
+            LayoutStatistics::reset();
+            layout(&root, size);
+            assert_eq!(LayoutStatistics::get(), statistics(0, 1, 0));
+            assert_eq!(
+                children[2].get_rect(),
+                Rect::new(0.0f32, 40.0f32, 30.0f32, 20.0f32)
+            );
+        });
+    }
+
+    #[test]
+    fn test_display_list_cache() {
+        run_local(async {
+            let (root, children) = create_tree();
+            layout(&root, Size::new(100.0f32, 100.0f32));
+
+            LayoutStatistics::reset();
+            draw(&root);
+            assert_eq!(LayoutStatistics::get(), statistics(0, 0, 4));
+
+            // nothing has changed
+            LayoutStatistics::reset();
+            draw(&root);
+            assert_eq!(LayoutStatistics::get(), statistics(0, 0, 0));
+
+            // only the control and its ancestors are recorded again, without layout
+            children[0].get_context().invalidate_visual();
+            LayoutStatistics::reset();
+            layout(&root, Size::new(100.0f32, 100.0f32));
+            draw(&root);
+            assert_eq!(LayoutStatistics::get(), statistics(0, 0, 2));
+        });
+    }
+
+    #[test]
+    fn test_display_list_cache_of_moved_control() {
+        run_local(async {
+            let (root, children) = create_tree();
+            layout(&root, Size::new(100.0f32, 100.0f32));
+            draw(&root);
+
+            // the resized child moves its siblings below, so they are recorded again
+            children[0].data.borrow_mut().size = Size::new(10.0f32, 30.0f32);
+            children[0].get_context().invalidate_measure();
+            LayoutStatistics::reset();
+            layout(&root, Size::new(100.0f32, 100.0f32));
+            draw(&root);
+            assert_eq!(LayoutStatistics::get().draw_count, 4);
+            assert_eq!(
+                children[2].get_rect(),
+                Rect::new(0.0f32, 50.0f32, 30.0f32, 20.0f32)
+            );
+
+            // the display lists recorded at the new positions are reused
+            LayoutStatistics::reset();
+            draw(&root);
+            assert_eq!(LayoutStatistics::get().draw_count, 0);
+        });
+    }
+}
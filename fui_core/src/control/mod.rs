@@ -13,5 +13,14 @@ pub use control_object::*;
 mod control_context;
 pub use control_context::*;
 
+mod layout_statistics;
+pub use layout_statistics::*;
+
 mod styled_control;
 pub use styled_control::*;
+
+mod visual_state;
+pub use visual_state::*;
+
+#[cfg(test)]
+pub(crate) mod test_controls;
@@ -1,5 +1,6 @@
 use crate::events::ControlEvent;
 use crate::FuiDrawingContext;
+use fui_drawing::{DisplayListBuilder, DrawingDisplayListBuilder};
 use std::any::Any;
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -23,11 +24,16 @@ impl<D: 'static> StyledControl<D> {
             style: RefCell::new(style),
             control_context: ControlContext::new(view_context),
         });
+        control.control_context.set_control_type::<D>();
 
         // set self
         let control_weak = Rc::downgrade(&control);
         control.control_context.set_self(control_weak);
 
+        // subscriptions created from here on belong to this control
+        #[cfg(feature = "diagnostics")]
+        let _diagnostics_scope = crate::diagnostics::ControlScope::enter(&control.control_context);
+
         let control_clone: Rc<dyn ControlObject> = control.clone();
         let handler = Box::new(move |changed_args: VecDiff<Rc<dyn ControlObject>>| {
             match changed_args {
@@ -84,6 +90,154 @@ impl<D: 'static> StyledControl<D> {
     pub fn get_context(&self) -> &ControlContext {
         &self.control_context
     }
+
+    /// Measures the control with its style and returns the desired size.
+    fn measure_style(&self, drawing_context: &mut FuiDrawingContext, available_size: Size) -> Size {
+        let mut size = Margin::remove_from_size(
+            available_size,
+            self.control_context
+                .get_attached_value::<Margin>()
+                .as_deref(),
+        );
+
+        let size_constraints = SizeConstraints::from_context(&self.control_context);
+        if let Some(size_constraints) = &size_constraints {
+            size = size_constraints.constrain_size(size);
+        }
+
+        let padding = self.get_padding(FlowDirection::get(&self.control_context));
+        size = Padding::remove_from_size(size, padding.as_ref());
+
+        let mut measured_size = self.style.borrow_mut().measure(
+            &mut *self.data.borrow_mut(),
+            &self.control_context,
+            drawing_context,
+            size,
+        );
+
+        measured_size = Padding::add_to_size(measured_size, padding.as_ref());
+
+        if let Some(size_constraints) = &size_constraints {
+            measured_size = size_constraints.constrain_size(measured_size);
+        }
+
+        measured_size = Margin::add_to_size(
+            measured_size,
+            self.control_context
+                .get_attached_value::<Margin>()
+                .as_deref(),
+        );
+
+        self.control_context.set_rect(Rect::new(
+            0.0f32,
+            0.0f32,
+            measured_size.width,
+            measured_size.height,
+        ));
+        measured_size
+    }
+
+    fn get_margin(&self, direction: LayoutDirection) -> Option<Thickness> {
+        self.control_context
+            .get_attached_value::<Margin>()
+            .map(|margin| Self::mirror_thickness(*margin, direction))
+    }
+
+    fn get_padding(&self, direction: LayoutDirection) -> Option<Thickness> {
+        self.control_context
+            .get_attached_value::<Padding>()
+            .map(|padding| Self::mirror_thickness(*padding, direction))
+    }
+
+    /// Tracks the visual state of the control from the events it gets.
+    fn update_visual_state(&self, event: &ControlEvent) {
+        let mut visual_state = self.control_context.get_visual_state();
+        match event {
+            ControlEvent::HoverChange(value) => {
+                visual_state.is_hover = *value;
+            }
+
+            ControlEvent::FocusChange(value) => {
+                visual_state.is_focused = *value && !visual_state.is_disabled;
+            }
+
+            ControlEvent::TapDown { .. } => {
+                visual_state.is_pressed = !visual_state.is_disabled;
+            }
+
+            ControlEvent::TapUp { .. } => {
+                visual_state.is_pressed = false;
+            }
+
+            ControlEvent::TapMove { position } => {
+                let self_rc = self.control_context.get_self_rc();
+                visual_state.is_pressed = !visual_state.is_disabled
+                    && self
+                        .hit_test(*position)
+                        .map(|hit_control| Rc::ptr_eq(&hit_control, &self_rc))
+                        .unwrap_or(false);
+            }
+
+            _ => return,
+        }
+        self.control_context.set_visual_state(visual_state);
+    }
+
+    /// Events that are not delivered to disabled controls.
+    fn is_input_event(event: &ControlEvent) -> bool {
+        matches!(
+            event,
+            ControlEvent::TapDown { .. }
+                | ControlEvent::TapUp { .. }
+                | ControlEvent::TapMove { .. }
+                | ControlEvent::PointerMove { .. }
+                | ControlEvent::ScrollWheel { .. }
+                | ControlEvent::KeyboardInput(..)
+        )
+    }
+
+    fn mirror_thickness(thickness: Thickness, direction: LayoutDirection) -> Thickness {
+        if direction.is_right_to_left() {
+            thickness.mirrored()
+        } else {
+            thickness
+        }
+    }
+
+    /// Returns the alignment of the control. Children are aligned
+    /// by the content alignment of their parent by default.
+    fn get_alignment(&self) -> (Alignment, Alignment) {
+        let parent = self.control_context.get_parent();
+        let parent_context = parent.as_ref().map(|parent| parent.get_context());
+
+        let horizontal = self
+            .control_context
+            .get_attached_value::<HorizontalAlignment>()
+            .map(|alignment| *alignment)
+            .or_else(|| {
+                parent_context.and_then(|context| {
+                    context
+                        .get_attached_value::<HorizontalContentAlignment>()
+                        .map(|alignment| *alignment)
+                })
+            })
+            .unwrap_or(Alignment::Stretch);
+
+        let vertical = self
+            .control_context
+            .get_attached_value::<VerticalAlignment>()
+            .map(|alignment| *alignment)
+            .or_else(|| {
+                parent_context.and_then(|context| {
+                    context
+                        .get_attached_value::<VerticalContentAlignment>()
+                        .map(|alignment| *alignment)
+                })
+            })
+            .unwrap_or(Alignment::Stretch);
+
+        (horizontal, vertical)
+    }
 }
 
 impl<D: 'static> ControlObject for StyledControl<D> {
@@ -105,6 +259,9 @@ impl<D: 'static> ControlBehavior for StyledControl<D> {
     }
 
     fn parent_attached(&self) {
+        #[cfg(feature = "diagnostics")]
+        let _diagnostics_scope = crate::diagnostics::ControlScope::enter(&self.control_context);
+
         self.style
             .borrow_mut()
             .parent_attached(&mut *self.data.borrow_mut(), &self.control_context);
@@ -122,6 +279,11 @@ impl<D: 'static> ControlBehavior for StyledControl<D> {
         event_context: &mut dyn EventContext,
         event: ControlEvent,
     ) {
+        self.update_visual_state(&event);
+        if self.control_context.get_visual_state().is_disabled && Self::is_input_event(&event) {
+            return;
+        }
+
         self.style.borrow_mut().handle_event(
             &mut *self.data.borrow_mut(),
             &self.control_context,
@@ -131,7 +293,7 @@ impl<D: 'static> ControlBehavior for StyledControl<D> {
         )
     }
 
-    fn measure(&self, drawing_context: &mut FuiDrawingContext, mut size: Size) {
+    fn measure(&self, drawing_context: &mut FuiDrawingContext, available_size: Size) {
         if let Some(visible) = self.control_context.get_attached_value::<Visible>() {
             if !visible.get() {
                 self.control_context.set_rect(Rect::empty());
@@ -139,33 +301,19 @@ impl<D: 'static> ControlBehavior for StyledControl<D> {
             }
         }
 
-        size = Margin::remove_from_size(
-            size,
-            self.control_context
-                .get_attached_value::<Margin>()
-                .as_deref(),
-        );
-
-        let mut measured_size = self.style.borrow_mut().measure(
-            &mut *self.data.borrow_mut(),
-            &self.control_context,
-            drawing_context,
-            size,
-        );
-
-        measured_size = Margin::add_to_size(
-            measured_size,
-            self.control_context
-                .get_attached_value::<Margin>()
-                .as_deref(),
-        );
+        if let Some(desired_size) = self.control_context.get_cached_measure(available_size) {
+            self.control_context.set_rect(Rect::new(
+                0.0f32,
+                0.0f32,
+                desired_size.width,
+                desired_size.height,
+            ));
+            return;
+        }
 
-        self.control_context.set_rect(Rect::new(
-            0.0f32,
-            0.0f32,
-            measured_size.width,
-            measured_size.height,
-        ));
+        let desired_size = self.measure_style(drawing_context, available_size);
+        self.control_context
+            .set_measure_result(available_size, desired_size);
     }
 
     fn set_rect(&self, drawing_context: &mut FuiDrawingContext, rect: Rect) {
@@ -176,35 +324,57 @@ impl<D: 'static> ControlBehavior for StyledControl<D> {
             }
         }
 
+        if let Some(final_rect) = self.control_context.get_cached_arrange(rect) {
+            self.control_context.set_rect(final_rect);
+            return;
+        }
+
+        if !self.control_context.is_measured_since_arrange() {
+            // the measure came from the cache, so the children still have
+            // the final rects - measure again (the children will use their caches)
+            // to bring back their desired sizes (it's not counted as a measure)
+            if let Some(available_size) = self.control_context.get_measure_available_size() {
+                self.measure_style(drawing_context, available_size);
+            }
+        }
+
         let control_rect = self.control_context.get_rect();
         let measured_size = Size::new(control_rect.width, control_rect.height);
 
+        let direction = FlowDirection::get(&self.control_context);
+        let (horizontal_alignment, vertical_alignment) = self.get_alignment();
+
         let mut new_rect = Alignment::apply(
             measured_size,
             rect,
-            self.control_context
-                .get_attached_value::<HorizontalAlignment>()
-                .as_deref(),
-            self.control_context
-                .get_attached_value::<VerticalAlignment>()
-                .as_deref(),
+            Some(&horizontal_alignment),
+            Some(&vertical_alignment),
             Alignment::Stretch,
             Alignment::Stretch,
+            direction,
         );
-        new_rect = Margin::remove_from_rect(
-            new_rect,
-            self.control_context
-                .get_attached_value::<Margin>()
-                .as_deref(),
-        );
+        new_rect = Margin::remove_from_rect(new_rect, self.get_margin(direction).as_ref());
+
+        if let Some(size_constraints) = SizeConstraints::from_context(&self.control_context) {
+            new_rect = size_constraints.constrain_rect(
+                new_rect,
+                Some(&horizontal_alignment.mirrored(direction)),
+                Some(&vertical_alignment),
+            );
+        }
+
+        if UseLayoutRounding::get(&self.control_context) {
+            new_rect = round_layout_rect(new_rect, DpiScale::get(&self.control_context));
+        }
 
         self.control_context.set_rect(new_rect);
         self.style.borrow_mut().set_rect(
             &mut *self.data.borrow_mut(),
             &self.control_context,
             drawing_context,
-            new_rect,
+            Padding::remove_from_rect(new_rect, self.get_padding(direction).as_ref()),
         );
+        self.control_context.set_arrange_result(rect, new_rect);
     }
 
     fn get_rect(&self) -> Rect {
@@ -228,8 +398,40 @@ impl<D: 'static> ControlBehavior for StyledControl<D> {
             return;
         }
 
-        self.style
-            .borrow_mut()
-            .draw(&self.data.borrow(), &self.control_context, drawing_context)
+        // the disabled subtree is faded once (by its root)
+        let opacity = match self.control_context.get_attached_value::<IsEnabled>() {
+            Some(ref is_enabled) if !is_enabled.get() => 0.5f32,
+            _ => 1.0f32,
+        };
+
+        if let Some(display_list) = self.control_context.get_cached_display_list(rect) {
+            drawing_context
+                .display
+                .draw_display_list(&display_list, opacity);
+            return;
+        }
+
+        // record the control (not bounded, shadows can go outside the rect)
+        let mut display = DrawingDisplayListBuilder::new(None);
+        self.style.borrow_mut().draw(
+            &self.data.borrow(),
+            &self.control_context,
+            &mut FuiDrawingContext {
+                fonts: &mut *drawing_context.fonts,
+                display: &mut display,
+            },
+        );
+
+        match display.build() {
+            Ok(display_list) => {
+                let display_list = Rc::new(display_list);
+                drawing_context
+                    .display
+                    .draw_display_list(&display_list, opacity);
+                self.control_context
+                    .set_cached_display_list(rect, display_list);
+            }
+            Err(err) => eprintln!("Cannot record control display list: {}", err),
+        }
     }
 }
@@ -0,0 +1,120 @@
+use std::rc::Rc;
+
+use fui_drawing::prelude::*;
+use fui_drawing::{DrawingDisplayListBuilder, DrawingFonts};
+
+use crate::{
+    ControlContext, ControlEvent, ControlObject, EventContext, FuiDrawingContext, Point, Rect,
+    Size, Style, StyledControl, ViewContext,
+};
+
+//
+// Controls and helpers used by the tests.
+//
+
+///
+/// Control with a fixed desired size that draws nothing
+/// (it can be hit by the pointer).
+///
+pub(crate) struct FixedSize {
+    pub size: Size,
+}
+
+impl FixedSize {
+    pub fn new(width: f32, height: f32, context: ViewContext) -> Rc<StyledControl<FixedSize>> {
+        StyledControl::new(
+            FixedSize {
+                size: Size::new(width, height),
+            },
+            Box::new(FixedSizeStyle),
+            context,
+        )
+    }
+}
+
+struct FixedSizeStyle;
+
+impl Style<FixedSize> for FixedSizeStyle {
+    fn setup(&mut self, _data: &mut FixedSize, _control_context: &ControlContext) {}
+
+    fn handle_event(
+        &mut self,
+        _data: &mut FixedSize,
+        _control_context: &ControlContext,
+        _drawing_context: &mut FuiDrawingContext,
+        _event_context: &mut dyn EventContext,
+        _event: ControlEvent,
+    ) {
+    }
+
+    fn measure(
+        &mut self,
+        data: &mut FixedSize,
+        _control_context: &ControlContext,
+        _drawing_context: &mut FuiDrawingContext,
+        _size: Size,
+    ) -> Size {
+        data.size
+    }
+
+    fn set_rect(
+        &mut self,
+        _data: &mut FixedSize,
+        _control_context: &ControlContext,
+        _drawing_context: &mut FuiDrawingContext,
+        _rect: Rect,
+    ) {
+    }
+
+    fn hit_test(
+        &self,
+        _data: &FixedSize,
+        control_context: &ControlContext,
+        point: Point,
+    ) -> Option<Rc<dyn ControlObject>> {
+        if point.is_inside(&control_context.get_rect()) {
+            Some(control_context.get_self_rc())
+        } else {
+            None
+        }
+    }
+
+    fn draw(
+        &mut self,
+        _data: &FixedSize,
+        _control_context: &ControlContext,
+        _drawing_context: &mut FuiDrawingContext,
+    ) {
+    }
+}
+
+/// Measures the control and returns its desired size.
+pub(crate) fn measure(control: &Rc<dyn ControlObject>, size: Size) -> Size {
+    with_drawing_context(|drawing_context| control.measure(drawing_context, size));
+    let rect = control.get_rect();
+    Size::new(rect.width, rect.height)
+}
+
+/// Measures and arranges the control in the rect at (0, 0) like the window does.
+pub(crate) fn layout(control: &Rc<dyn ControlObject>, size: Size) {
+    with_drawing_context(|drawing_context| {
+        control.measure(drawing_context, size);
+        control.set_rect(
+            drawing_context,
+            Rect::new(0.0f32, 0.0f32, size.width, size.height),
+        );
+    });
+}
+
+pub(crate) fn draw(control: &Rc<dyn ControlObject>) {
+    with_drawing_context(|drawing_context| control.draw(drawing_context));
+}
+
+fn with_drawing_context<F: FnOnce(&mut FuiDrawingContext)>(f: F) {
+    let mut fonts = DrawingFonts::default();
+    let mut display = DrawingDisplayListBuilder::new(None);
+    f(&mut FuiDrawingContext {
+        fonts: &mut fonts,
+        display: &mut display,
+    });
+}
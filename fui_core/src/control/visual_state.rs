@@ -0,0 +1,43 @@
+///
+/// Interaction state of the control.
+///
+/// The state is tracked by the `ControlContext` (see `get_visual_state()`)
+/// from the events delivered to the control and from the `IsEnabled` attached values,
+/// so styles don't need to track it by hand.
+///
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct VisualState {
+    /// The cursor is over the control (or one of its children).
+    pub is_hover: bool,
+
+    /// The control is tapped and the pointer is still over it.
+    pub is_pressed: bool,
+
+    /// The control has the keyboard focus.
+    pub is_focused: bool,
+
+    /// The control or one of its ancestors is disabled (see `IsEnabled`).
+    pub is_disabled: bool,
+}
+
+impl VisualState {
+    pub fn contains(&self, flag: VisualStateFlag) -> bool {
+        match flag {
+            VisualStateFlag::Hover => self.is_hover,
+            VisualStateFlag::Pressed => self.is_pressed,
+            VisualStateFlag::Focused => self.is_focused,
+            VisualStateFlag::Disabled => self.is_disabled,
+        }
+    }
+}
+
+///
+/// Single state of the `VisualState` (used by style sheet selectors).
+///
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum VisualStateFlag {
+    Hover,
+    Pressed,
+    Focused,
+    Disabled,
+}
@@ -0,0 +1,306 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::panic::Location;
+
+use crate::{ControlContext, ControlObject, JoinHandle, Property};
+
+//
+// Subscription diagnostics.
+//
+// Every task handle and event subscription registers itself here
+// together with the place it was created at. The registration is removed
+// when the handle is dropped, so the registry shows what is still alive.
+//
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum SubscriptionKind {
+    /// Task spawned with `spawn_local()` (property bindings and `on_changed` handlers).
+    Task,
+
+    /// Callback subscribed to an `Event`.
+    Event,
+}
+
+#[derive(Debug, Clone)]
+pub struct SubscriptionInfo {
+    pub id: u64,
+    pub kind: SubscriptionKind,
+    pub location: &'static Location<'static>,
+
+    /// Identifier of the observed property (see `property_id()`).
+    pub property: Option<usize>,
+
+    /// Identifier of the control that created the subscription (see `control_id()`).
+    pub control: Option<usize>,
+}
+
+struct Registry {
+    next_id: u64,
+    live: HashMap<u64, SubscriptionInfo>,
+    control_scope: Vec<usize>,
+}
+
+thread_local! {
+    static REGISTRY: RefCell<Registry> = RefCell::new(Registry {
+        next_id: 0,
+        live: HashMap::new(),
+        control_scope: Vec::new(),
+    });
+}
+
+///
+/// Keeps the subscription registered as long as it lives.
+///
+pub(crate) struct SubscriptionToken {
+    id: u64,
+}
+
+impl SubscriptionToken {
+    pub(crate) fn new(kind: SubscriptionKind, location: &'static Location<'static>) -> Self {
+        let id = REGISTRY.with(|registry| {
+            let mut registry = registry.borrow_mut();
+            let id = registry.next_id;
+            registry.next_id += 1;
+            let control = registry.control_scope.last().copied();
+            registry.live.insert(
+                id,
+                SubscriptionInfo {
+                    id,
+                    kind,
+                    location,
+                    property: None,
+                    control,
+                },
+            );
+            id
+        });
+        SubscriptionToken { id }
+    }
+
+    pub(crate) fn id(&self) -> u64 {
+        self.id
+    }
+}
+
+impl Drop for SubscriptionToken {
+    fn drop(&mut self) {
+        // the registry may be already destroyed on thread exit
+        let _ = REGISTRY.try_with(|registry| {
+            registry.borrow_mut().live.remove(&self.id);
+        });
+    }
+}
+
+///
+/// Marks the subscriptions created while it is alive
+/// as belonging to the control.
+///
+pub(crate) struct ControlScope;
+
+impl ControlScope {
+    pub(crate) fn enter(control_context: &ControlContext) -> Self {
+        REGISTRY.with(|registry| {
+            registry
+                .borrow_mut()
+                .control_scope
+                .push(control_context_id(control_context))
+        });
+        ControlScope
+    }
+}
+
+impl Drop for ControlScope {
+    fn drop(&mut self) {
+        let _ = REGISTRY.try_with(|registry| {
+            registry.borrow_mut().control_scope.pop();
+        });
+    }
+}
+
+pub(crate) fn set_source_property(handle: &JoinHandle<()>, property_id: usize) {
+    REGISTRY.with(|registry| {
+        if let Some(info) = registry.borrow_mut().live.get_mut(&handle.diagnostics_id()) {
+            info.property = Some(property_id);
+        }
+    });
+}
+
+fn control_context_id(control_context: &ControlContext) -> usize {
+    control_context as *const ControlContext as usize
+}
+
+/// Returns the identifier used to group subscriptions by control.
+pub fn control_id(control: &dyn ControlObject) -> usize {
+    control_context_id(control.get_context())
+}
+
+/// Returns the identifier used to group subscriptions by observed property.
+/// All the clones of the property share the same identifier.
+pub fn property_id<T: 'static + Clone + PartialEq>(property: &Property<T>) -> usize {
+    property.diagnostics_id()
+}
+
+/// Returns all the subscriptions alive on the current thread.
+pub fn live_subscriptions() -> Vec<SubscriptionInfo> {
+    REGISTRY.with(|registry| {
+        let mut subscriptions: Vec<_> = registry.borrow().live.values().cloned().collect();
+        subscriptions.sort_by_key(|info| info.id);
+        subscriptions
+    })
+}
+
+pub fn live_subscription_count() -> usize {
+    REGISTRY.with(|registry| registry.borrow().live.len())
+}
+
+/// Returns the alive subscriptions observing the property.
+pub fn property_subscriptions<T: 'static + Clone + PartialEq>(
+    property: &Property<T>,
+) -> Vec<SubscriptionInfo> {
+    let id = property_id(property);
+    live_subscriptions()
+        .into_iter()
+        .filter(|info| info.property == Some(id))
+        .collect()
+}
+
+/// Returns the alive subscriptions created by the control.
+pub fn control_subscriptions(control: &dyn ControlObject) -> Vec<SubscriptionInfo> {
+    let id = control_id(control);
+    live_subscriptions()
+        .into_iter()
+        .filter(|info| info.control == Some(id))
+        .collect()
+}
+
+/// Formats the subscriptions grouped by creation site
+/// (the most common first).
+pub fn format_subscription_report(subscriptions: &[SubscriptionInfo]) -> String {
+    let mut counts: HashMap<(&'static Location<'static>, SubscriptionKind), usize> =
+        HashMap::new();
+    for info in subscriptions {
+        *counts.entry((info.location, info.kind)).or_insert(0) += 1;
+    }
+
+    let mut counts: Vec<_> = counts.into_iter().collect();
+    counts.sort_by(|((location_a, _), count_a), ((location_b, _), count_b)| {
+        count_b
+            .cmp(count_a)
+            .then_with(|| location_a.file().cmp(location_b.file()))
+            .then_with(|| location_a.line().cmp(&location_b.line()))
+    });
+
+    let mut report = format!("{} live subscription(s)\n", subscriptions.len());
+    for ((location, kind), count) in counts {
+        let _ = writeln!(report, "{:>6} x {:?} at {}", count, kind, location);
+    }
+    report
+}
+
+/// Returns the report of all the subscriptions alive on the current thread.
+pub fn subscription_report() -> String {
+    format_subscription_report(&live_subscriptions())
+}
+
+///
+/// Remembers the moment it was created at, so it can be checked later
+/// that all the subscriptions created since then were released.
+///
+/// # Example
+///
+/// ```ignore
+/// let checkpoint = SubscriptionCheckpoint::new();
+/// {
+///     let window = Window::create(WindowOptions::new()).await?;
+///     window.set_vm(MainViewModel::new());
+/// }
+/// checkpoint.assert_released();
+/// ```
+///
+pub struct SubscriptionCheckpoint {
+    first_id: u64,
+}
+
+impl SubscriptionCheckpoint {
+    pub fn new() -> Self {
+        SubscriptionCheckpoint {
+            first_id: REGISTRY.with(|registry| registry.borrow().next_id),
+        }
+    }
+
+    /// Returns the subscriptions created after the checkpoint that are still alive.
+    pub fn live_subscriptions(&self) -> Vec<SubscriptionInfo> {
+        live_subscriptions()
+            .into_iter()
+            .filter(|info| info.id >= self.first_id)
+            .collect()
+    }
+
+    /// Panics with a report if any subscription created after the checkpoint is still alive.
+    #[track_caller]
+    pub fn assert_released(&self) {
+        let subscriptions = self.live_subscriptions();
+        if !subscriptions.is_empty() {
+            panic!(
+                "Not all subscriptions were released!\n{}",
+                format_subscription_report(&subscriptions)
+            );
+        }
+    }
+}
+
+impl Default for SubscriptionCheckpoint {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::task::{run_local, run_pending_tasks};
+    use crate::{property_subscriptions, Property, SubscriptionCheckpoint};
+
+    #[test]
+    fn test_dropped_binding_is_released() {
+        run_local(async {
+            let source = Property::<i32>::new(1);
+            let checkpoint = SubscriptionCheckpoint::new();
+
+            let target = Property::binded_from(&source);
+            run_pending_tasks().await;
+            assert_eq!(checkpoint.live_subscriptions().len(), 1);
+            assert_eq!(property_subscriptions(&source).len(), 1);
+
+            drop(target);
+            checkpoint.assert_released();
+        });
+    }
+
+    #[test]
+    fn test_operators_report_the_caller_location() {
+        run_local(async {
+            let source = Property::<i32>::new(1);
+            let checkpoint = SubscriptionCheckpoint::new();
+
+            let _mapped = source.map_value(|v| v * 2);
+            let _filtered = source.filter(|v| *v > 0);
+            assert_eq!(checkpoint.live_subscriptions().len(), 2);
+            for info in checkpoint.live_subscriptions() {
+                assert_eq!(info.location.file(), file!());
+            }
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Not all subscriptions were released!")]
+    fn test_alive_binding_is_reported() {
+        run_local(async {
+            let source = Property::<i32>::new(1);
+            let checkpoint = SubscriptionCheckpoint::new();
+
+            let _target = Property::binded_from(&source);
+            checkpoint.assert_released();
+        });
+    }
+}
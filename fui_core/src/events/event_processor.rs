@@ -275,10 +275,32 @@ impl EventProcessor {
         event: ControlEvent,
     ) {
         if let Some(ref control) = control {
+            let event = Self::map_event_position(control, event);
             control
                 .handle_event(drawing_context, self, event);
         };
     }
+
+    /// Converts the position of the event from the window coordinates
+    /// to the coordinates of the control (they differ inside a `Viewbox`).
+    fn map_event_position(control: &Rc<dyn ControlObject>, event: ControlEvent) -> ControlEvent {
+        let context = control.get_context();
+        match event {
+            ControlEvent::TapDown { position } => ControlEvent::TapDown {
+                position: context.map_point_from_window(position),
+            },
+            ControlEvent::TapUp { position } => ControlEvent::TapUp {
+                position: context.map_point_from_window(position),
+            },
+            ControlEvent::TapMove { position } => ControlEvent::TapMove {
+                position: context.map_point_from_window(position),
+            },
+            ControlEvent::PointerMove { position } => ControlEvent::PointerMove {
+                position: context.map_point_from_window(position),
+            },
+            event => event,
+        }
+    }
 }
 
 impl EventContext for EventProcessor {
@@ -28,9 +28,7 @@ impl GestureDetector {
     ) -> Option<Gesture> {
         match event {
             InputEvent::CursorMoved { position, .. } => {
-                //let physical_pos =
-                //    position.to_physical(window.get_drawing_target().get_window().hidpi_factor());
-                //self.mouse_pos = Point::new(physical_pos.x as f32, physical_pos.y as f32);
+                // positions are already in logical pixels (see `DpiScale`)
                 self.mouse_pos = *position;
                 return Some(Gesture::TapMove {
                     position: self.mouse_pos,
@@ -0,0 +1,262 @@
+use std::rc::Rc;
+
+use crate::{
+    ControlContext, ControlEvent, ControlObject, EventContext, FuiDrawingContext, Point, Property,
+    Rect, Size, Style, StyledControl, Subscription, TypeMapKey, ViewContext, WindowSize,
+};
+use typed_builder::TypedBuilder;
+
+///
+/// Attached value of the `AdaptiveTrigger` that depends on its state.
+///
+pub struct AdaptiveSetter {
+    apply: Box<ApplyFn>,
+}
+
+/// Sets the attached value for the given state (`true` - active).
+type ApplyFn = dyn Fn(&ControlContext, bool);
+
+impl AdaptiveSetter {
+    pub fn new<K: TypeMapKey + 'static>(active_value: K::Value, inactive_value: K::Value) -> Self
+    where
+        K::Value: Clone,
+    {
+        AdaptiveSetter {
+            apply: Box::new(move |control_context, is_active| {
+                control_context.set_attached_value::<K>(if is_active {
+                    active_value.clone()
+                } else {
+                    inactive_value.clone()
+                })
+            }),
+        }
+    }
+}
+
+//
+// AdaptiveTrigger
+//
+
+///
+/// Rearranges the UI when the window crosses the given size.
+///
+/// The trigger is active when the `WindowSize` is at least `min_window_width`
+/// by `min_window_height`. The first child is shown when the trigger is active,
+/// the second one (if given) when it is not. The `setters` change attached values
+/// of the trigger itself (e.g. its `Row` and `Column` in the parent `Grid`).
+///
+/// # Example
+///
+/// ```ignore
+/// ui! {
+///     Grid {
+///         columns: 2,
+///
+///         Text { text: "Content" },
+///
+///         AdaptiveTrigger {
+///             min_window_width: 800.0f32,
+///             setters: vec![
+///                 AdaptiveSetter::new::<Row>(0, 1),
+///                 AdaptiveSetter::new::<Column>(1, 0),
+///             ],
+///
+///             DetailsView { ... },
+///             CompactDetailsView { ... },
+///         },
+///     }
+/// }
+/// ```
+///
+#[derive(TypedBuilder)]
+pub struct AdaptiveTrigger {
+    #[builder(default = 0.0f32)]
+    pub min_window_width: f32,
+
+    #[builder(default = 0.0f32)]
+    pub min_window_height: f32,
+
+    /// Set by the trigger (can be shared with other controls, e.g. as `Visible`).
+    #[builder(default = Property::new(false))]
+    pub is_active: Property<bool>,
+
+    #[builder(default = Vec::new())]
+    pub setters: Vec<AdaptiveSetter>,
+}
+
+impl AdaptiveTrigger {
+    pub fn to_view(
+        self,
+        style: Option<Box<dyn Style<Self>>>,
+        context: ViewContext,
+    ) -> Rc<dyn ControlObject> {
+        StyledControl::new(
+            self,
+            style.unwrap_or_else(|| {
+                Box::new(DefaultAdaptiveTriggerStyle::new(
+                    DefaultAdaptiveTriggerStyleParams::builder().build(),
+                ))
+            }),
+            context,
+        )
+    }
+
+    fn is_active_for(&self, window_size: Size) -> bool {
+        window_size.width >= self.min_window_width && window_size.height >= self.min_window_height
+    }
+
+    fn get_content(&self, control_context: &ControlContext) -> Option<Rc<dyn ControlObject>> {
+        let children = control_context.get_children();
+        if self.is_active.get() {
+            children.get(0)
+        } else {
+            children.get(1).or_else(|| children.get(0))
+        }
+    }
+}
+
+//
+// Default AdaptiveTrigger Style
+//
+
+#[derive(TypedBuilder)]
+pub struct DefaultAdaptiveTriggerStyleParams {}
+
+pub struct DefaultAdaptiveTriggerStyle {
+    setters: Rc<Vec<AdaptiveSetter>>,
+    is_active_subscription: Option<Subscription>,
+    window_size_subscription: Option<Subscription>,
+}
+
+impl DefaultAdaptiveTriggerStyle {
+    pub fn new(_params: DefaultAdaptiveTriggerStyleParams) -> Self {
+        DefaultAdaptiveTriggerStyle {
+            setters: Rc::new(Vec::new()),
+            is_active_subscription: None,
+            window_size_subscription: None,
+        }
+    }
+
+    fn apply_setters(
+        setters: &[AdaptiveSetter],
+        control_context: &ControlContext,
+        is_active: bool,
+    ) {
+        for setter in setters {
+            (setter.apply)(control_context, is_active);
+        }
+    }
+}
+
+impl Style<AdaptiveTrigger> for DefaultAdaptiveTriggerStyle {
+    fn setup(&mut self, data: &mut AdaptiveTrigger, control_context: &ControlContext) {
+        self.setters = Rc::new(std::mem::take(&mut data.setters));
+
+        let setters = self.setters.clone();
+        let self_weak = control_context.get_self_weak();
+        self.is_active_subscription = Some(data.is_active.on_changed(move |is_active| {
+            if let Some(control) = self_weak.upgrade() {
+                Self::apply_setters(&setters, control.get_context(), is_active);
+                control.get_context().invalidate_measure();
+            }
+        }));
+    }
+
+    fn parent_attached(&mut self, data: &mut AdaptiveTrigger, control_context: &ControlContext) {
+        let window_size = control_context.get_inherited_value::<WindowSize>();
+
+        // the state is known before the first layout
+        if let Some(ref window_size) = window_size {
+            data.is_active.set(data.is_active_for(window_size.get()));
+        }
+        Self::apply_setters(&self.setters, control_context, data.is_active.get());
+
+        if let Some(window_size) = window_size {
+            let is_active = data.is_active.clone();
+            let min_window_width = data.min_window_width;
+            let min_window_height = data.min_window_height;
+            self.window_size_subscription = Some(window_size.on_changed(move |size| {
+                is_active.set(size.width >= min_window_width && size.height >= min_window_height);
+            }));
+        }
+    }
+
+    fn parent_detached(&mut self, _data: &mut AdaptiveTrigger, _control_context: &ControlContext) {
+        self.window_size_subscription = None;
+    }
+
+    fn handle_event(
+        &mut self,
+        _data: &mut AdaptiveTrigger,
+        _control_context: &ControlContext,
+        _drawing_context: &mut FuiDrawingContext,
+        _event_context: &mut dyn EventContext,
+        _event: ControlEvent,
+    ) {
+    }
+
+    fn measure(
+        &mut self,
+        data: &mut AdaptiveTrigger,
+        control_context: &ControlContext,
+        drawing_context: &mut FuiDrawingContext,
+        size: Size,
+    ) -> Size {
+        match data.get_content(control_context) {
+            Some(ref content) => {
+                content.measure(drawing_context, size);
+                let rect = content.get_rect();
+                Size::new(rect.width, rect.height)
+            }
+            _ => Size::new(0.0f32, 0.0f32),
+        }
+    }
+
+    fn set_rect(
+        &mut self,
+        data: &mut AdaptiveTrigger,
+        control_context: &ControlContext,
+        drawing_context: &mut FuiDrawingContext,
+        rect: Rect,
+    ) {
+        let content = data.get_content(control_context);
+        for child in control_context.get_children().into_iter() {
+            match content {
+                Some(ref content) if Rc::ptr_eq(content, &child) => {
+                    child.set_rect(drawing_context, rect)
+                }
+                // the hidden child must not be hit
+                _ => child.get_context().set_rect(Rect::empty()),
+            }
+        }
+    }
+
+    fn hit_test(
+        &self,
+        data: &AdaptiveTrigger,
+        control_context: &ControlContext,
+        point: Point,
+    ) -> Option<Rc<dyn ControlObject>> {
+        if point.is_inside(&control_context.get_rect()) {
+            if let Some(ref content) = data.get_content(control_context)
+                && point.is_inside(&content.get_rect())
+            {
+                return content.hit_test(point);
+            }
+            None
+        } else {
+            None
+        }
+    }
+
+    fn draw(
+        &mut self,
+        data: &AdaptiveTrigger,
+        control_context: &ControlContext,
+        drawing_context: &mut FuiDrawingContext,
+    ) {
+        if let Some(content) = data.get_content(control_context) {
+            content.draw(drawing_context);
+        }
+    }
+}
@@ -0,0 +1,235 @@
+use std::f32;
+use std::rc::Rc;
+
+use crate::{
+    ControlContext, ControlEvent, ControlObject, EventContext, FuiDrawingContext, Point, Property,
+    Rect, Size, Style, StyledControl, ViewContext,
+};
+use typed_builder::TypedBuilder;
+
+//
+// AspectRatio
+//
+
+///
+/// Constrains its child to the given ratio of width to height.
+///
+/// The child gets the biggest rect with the ratio that fits the available space
+/// (centered).
+///
+/// # Example
+///
+/// ```ignore
+/// ui! {
+///     AspectRatio {
+///         ratio: 16.0f32 / 9.0f32,
+///
+///         VideoPlayer { ... },
+///     }
+/// }
+/// ```
+///
+#[derive(TypedBuilder)]
+pub struct AspectRatio {
+    /// Width divided by height.
+    #[builder(default = Property::new(1.0f32))]
+    pub ratio: Property<f32>,
+}
+
+impl AspectRatio {
+    pub fn to_view(
+        self,
+        style: Option<Box<dyn Style<Self>>>,
+        context: ViewContext,
+    ) -> Rc<dyn ControlObject> {
+        StyledControl::new(
+            self,
+            style.unwrap_or_else(|| {
+                Box::new(DefaultAspectRatioStyle::new(
+                    DefaultAspectRatioStyleParams::builder().build(),
+                ))
+            }),
+            context,
+        )
+    }
+
+    fn get_ratio(&self) -> f32 {
+        let ratio = self.ratio.get();
+        if ratio.is_finite() && ratio > 0.0f32 {
+            ratio
+        } else {
+            1.0f32
+        }
+    }
+}
+
+/// Returns the biggest size with the `ratio` that fits the `size`
+/// (infinite when `size` is not constrained in both directions).
+fn fit_to_ratio(ratio: f32, size: Size) -> Size {
+    let width = size.width.min(size.height * ratio);
+    Size::new(width, width / ratio)
+}
+
+//
+// Default AspectRatio Style
+//
+
+#[derive(TypedBuilder)]
+pub struct DefaultAspectRatioStyleParams {}
+
+pub struct DefaultAspectRatioStyle {}
+
+impl DefaultAspectRatioStyle {
+    pub fn new(_params: DefaultAspectRatioStyleParams) -> Self {
+        DefaultAspectRatioStyle {}
+    }
+}
+
+impl Style<AspectRatio> for DefaultAspectRatioStyle {
+    fn setup(&mut self, data: &mut AspectRatio, control_context: &ControlContext) {
+        control_context.dirty_watch_property(&data.ratio);
+    }
+
+    fn handle_event(
+        &mut self,
+        _data: &mut AspectRatio,
+        _control_context: &ControlContext,
+        _drawing_context: &mut FuiDrawingContext,
+        _event_context: &mut dyn EventContext,
+        _event: ControlEvent,
+    ) {
+    }
+
+    fn measure(
+        &mut self,
+        data: &mut AspectRatio,
+        control_context: &ControlContext,
+        drawing_context: &mut FuiDrawingContext,
+        size: Size,
+    ) -> Size {
+        let ratio = data.get_ratio();
+        let max_size = fit_to_ratio(ratio, size);
+
+        let children = control_context.get_children();
+        let content_size = match children.into_iter().next() {
+            Some(ref content) => {
+                content.measure(drawing_context, max_size);
+                let rect = content.get_rect();
+                Size::new(rect.width, rect.height)
+            }
+            _ => Size::new(0.0f32, 0.0f32),
+        };
+
+        // expand the desired size of the child to the ratio
+        let width = content_size.width.max(content_size.height * ratio);
+        let desired_size = Size::new(width, width / ratio);
+
+        if max_size.width.is_finite() {
+            Size::new(
+                desired_size.width.min(max_size.width),
+                desired_size.height.min(max_size.height),
+            )
+        } else {
+            desired_size
+        }
+    }
+
+    fn set_rect(
+        &mut self,
+        data: &mut AspectRatio,
+        control_context: &ControlContext,
+        drawing_context: &mut FuiDrawingContext,
+        rect: Rect,
+    ) {
+        let size = fit_to_ratio(data.get_ratio(), Size::new(rect.width, rect.height));
+
+        let children = control_context.get_children();
+        if let Some(ref content) = children.into_iter().next() {
+            content.set_rect(
+                drawing_context,
+                Rect::new(
+                    rect.x + (rect.width - size.width) / 2.0f32,
+                    rect.y + (rect.height - size.height) / 2.0f32,
+                    size.width,
+                    size.height,
+                ),
+            );
+        }
+    }
+
+    fn hit_test(
+        &self,
+        _data: &AspectRatio,
+        control_context: &ControlContext,
+        point: Point,
+    ) -> Option<Rc<dyn ControlObject>> {
+        if point.is_inside(&control_context.get_rect()) {
+            let children = control_context.get_children();
+            if let Some(ref content) = children.into_iter().next()
+                && point.is_inside(&content.get_rect())
+            {
+                return content.hit_test(point);
+            }
+            None
+        } else {
+            None
+        }
+    }
+
+    fn draw(
+        &mut self,
+        _data: &AspectRatio,
+        control_context: &ControlContext,
+        drawing_context: &mut FuiDrawingContext,
+    ) {
+        let children = control_context.get_children();
+        if let Some(content) = children.into_iter().next() {
+            content.draw(drawing_context);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::control::test_controls::{layout, FixedSize};
+    use crate::task::run_local;
+    use crate::{AspectRatio, Children, ControlObject, Property, Rect, Size, TypeMap, ViewContext};
+
+    fn create_aspect_ratio(ratio: f32, content: &Rc<dyn ControlObject>) -> Rc<dyn ControlObject> {
+        AspectRatio::builder()
+            .ratio(Property::new(ratio))
+            .build()
+            .to_view(
+                None,
+                ViewContext {
+                    attached_values: TypeMap::new(),
+                    children: Children::SingleStatic(content.clone()),
+                },
+            )
+    }
+
+    #[test]
+    fn test_letterboxing() {
+        run_local(async {
+            let content: Rc<dyn ControlObject> =
+                FixedSize::new(10.0f32, 10.0f32, ViewContext::empty());
+
+            // wide ratio in a square - bars at the top and bottom
+            let aspect_ratio = create_aspect_ratio(2.0f32, &content);
+            layout(&aspect_ratio, Size::new(100.0f32, 100.0f32));
+            assert_eq!(
+                content.get_context().get_rect(),
+                Rect::new(0.0f32, 25.0f32, 100.0f32, 50.0f32)
+            );
+
+            // wide rect - bars on the left and right
+            layout(&aspect_ratio, Size::new(200.0f32, 50.0f32));
+            assert_eq!(
+                content.get_context().get_rect(),
+                Rect::new(50.0f32, 0.0f32, 100.0f32, 50.0f32)
+            );
+        });
+    }
+}
@@ -0,0 +1,405 @@
+use std::cell::RefCell;
+use std::f32;
+use std::rc::{Rc, Weak};
+
+use crate::{
+    ControlContext, ControlEvent, ControlObject, EventContext, FlowDirection, FuiDrawingContext,
+    ObservableCollection, Point, Property, Rect, Size, Style, StyledControl, Subscription,
+    TypeMapKey, VecDiff, ViewContext,
+};
+use typed_builder::TypedBuilder;
+
+//
+// Attached values
+//
+
+/// Distance of the child's left edge from the left edge of the `Canvas`
+/// (the right edges are used for the right-to-left `FlowDirection`).
+pub struct Left;
+impl TypeMapKey for Left {
+    type Value = Property<f32>;
+}
+
+/// Distance of the child's top edge from the top edge of the `Canvas`.
+pub struct Top;
+impl TypeMapKey for Top {
+    type Value = Property<f32>;
+}
+
+/// Distance of the child's right edge from the right edge of the `Canvas`
+/// (used when `Left` is not set).
+pub struct Right;
+impl TypeMapKey for Right {
+    type Value = Property<f32>;
+}
+
+/// Distance of the child's bottom edge from the bottom edge of the `Canvas`
+/// (used when `Top` is not set).
+pub struct Bottom;
+impl TypeMapKey for Bottom {
+    type Value = Property<f32>;
+}
+
+/// Children with higher values are drawn over the ones with lower values.
+/// Children with the same value are drawn in the order they are declared.
+pub struct ZIndex;
+impl TypeMapKey for ZIndex {
+    type Value = Property<f32>;
+}
+
+//
+// Canvas
+//
+
+///
+/// Places children at explicit coordinates. Children have their desired sizes
+/// and can overlap each other.
+///
+/// # Example
+///
+/// ```ignore
+/// ui! {
+///     Canvas {
+///         Text { Left: 10.0f32, Top: 20.0f32, text: "Node 1" },
+///         Text { Left: &self.node_x, Top: &self.node_y, ZIndex: 1.0f32, text: "Node 2" },
+///     }
+/// }
+/// ```
+///
+#[derive(TypedBuilder)]
+pub struct Canvas {}
+
+impl Canvas {
+    pub fn to_view(
+        self,
+        style: Option<Box<dyn Style<Self>>>,
+        context: ViewContext,
+    ) -> Rc<dyn ControlObject> {
+        StyledControl::new(
+            self,
+            style.unwrap_or_else(|| {
+                Box::new(DefaultCanvasStyle::new(
+                    DefaultCanvasStyleParams::builder().build(),
+                ))
+            }),
+            context,
+        )
+    }
+}
+
+//
+// Default Canvas Style
+//
+
+#[derive(TypedBuilder)]
+pub struct DefaultCanvasStyleParams {}
+
+pub struct DefaultCanvasStyle {
+    /// Subscriptions to the attached values of every child (in the children order).
+    child_subscriptions: Rc<RefCell<Vec<Vec<Subscription>>>>,
+    children_subscription: Option<Subscription>,
+}
+
+impl DefaultCanvasStyle {
+    pub fn new(_params: DefaultCanvasStyleParams) -> Self {
+        DefaultCanvasStyle {
+            child_subscriptions: Rc::new(RefCell::new(Vec::new())),
+            children_subscription: None,
+        }
+    }
+
+    ///
+    /// The size of the canvas depends on the positions of its children
+    /// and the drawing order on their z-indexes.
+    ///
+    fn watch_child(
+        child: &Rc<dyn ControlObject>,
+        canvas_weak: &Weak<dyn ControlObject>,
+    ) -> Vec<Subscription> {
+        let context = child.get_context();
+        let mut subscriptions = Vec::new();
+
+        let positions = [
+            context
+                .get_attached_value::<Left>()
+                .map(|value| value.clone()),
+            context
+                .get_attached_value::<Top>()
+                .map(|value| value.clone()),
+            context
+                .get_attached_value::<Right>()
+                .map(|value| value.clone()),
+            context
+                .get_attached_value::<Bottom>()
+                .map(|value| value.clone()),
+        ];
+        for position in positions.into_iter().flatten() {
+            let canvas_weak = canvas_weak.clone();
+            subscriptions.push(position.on_changed(move |_| {
+                if let Some(canvas) = canvas_weak.upgrade() {
+                    canvas.get_context().invalidate_measure();
+                }
+            }));
+        }
+
+        if let Some(z_index) = context
+            .get_attached_value::<ZIndex>()
+            .map(|value| value.clone())
+        {
+            let canvas_weak = canvas_weak.clone();
+            subscriptions.push(z_index.on_changed(move |_| {
+                if let Some(canvas) = canvas_weak.upgrade() {
+                    canvas.get_context().invalidate_visual();
+                }
+            }));
+        }
+
+        subscriptions
+    }
+
+    fn get_position<K: TypeMapKey<Value = Property<f32>> + 'static>(
+        child: &Rc<dyn ControlObject>,
+    ) -> Option<f32> {
+        child
+            .get_context()
+            .get_attached_value::<K>()
+            .map(|value| value.get())
+    }
+
+    /// Returns children in the drawing order (the top-most last).
+    fn get_children_in_z_order(control_context: &ControlContext) -> Vec<Rc<dyn ControlObject>> {
+        let mut children: Vec<(f32, Rc<dyn ControlObject>)> = control_context
+            .get_children()
+            .into_iter()
+            .map(|child| {
+                let z_index = Self::get_position::<ZIndex>(&child).unwrap_or(0.0f32);
+                (z_index, child)
+            })
+            .collect();
+
+        // stable sort keeps the declaration order for the same z-index
+        children.sort_by(|(z1, _), (z2, _)| z1.total_cmp(z2));
+
+        children.into_iter().map(|(_, child)| child).collect()
+    }
+}
+
+impl Style<Canvas> for DefaultCanvasStyle {
+    fn setup(&mut self, _data: &mut Canvas, control_context: &ControlContext) {
+        let canvas_weak = control_context.get_self_weak();
+        *self.child_subscriptions.borrow_mut() = control_context
+            .get_children()
+            .into_iter()
+            .map(|child| Self::watch_child(&child, &canvas_weak))
+            .collect();
+
+        // children can be added and removed later
+        let child_subscriptions = self.child_subscriptions.clone();
+        self.children_subscription = control_context.get_children().on_changed(Box::new(
+            move |changed_args: VecDiff<Rc<dyn ControlObject>>| {
+                let mut child_subscriptions = child_subscriptions.borrow_mut();
+                match changed_args {
+                    VecDiff::Clear { .. } => {
+                        child_subscriptions.clear();
+                    }
+                    VecDiff::InsertAt { index, value } => {
+                        child_subscriptions.insert(index, Self::watch_child(&value, &canvas_weak));
+                    }
+                    VecDiff::RemoveAt { index, .. } => {
+                        child_subscriptions.remove(index);
+                    }
+                    VecDiff::Move {
+                        old_index,
+                        new_index,
+                    } => {
+                        let subscriptions = child_subscriptions.remove(old_index);
+                        child_subscriptions.insert(new_index, subscriptions);
+                    }
+                    VecDiff::Pop { .. } => {
+                        child_subscriptions.pop();
+                    }
+                    VecDiff::Push { value } => {
+                        child_subscriptions.push(Self::watch_child(&value, &canvas_weak));
+                    }
+                }
+            },
+        ));
+    }
+
+    fn handle_event(
+        &mut self,
+        _data: &mut Canvas,
+        _control_context: &ControlContext,
+        _drawing_context: &mut FuiDrawingContext,
+        _event_context: &mut dyn EventContext,
+        _event: ControlEvent,
+    ) {
+    }
+
+    fn measure(
+        &mut self,
+        _data: &mut Canvas,
+        control_context: &ControlContext,
+        drawing_context: &mut FuiDrawingContext,
+        _size: Size,
+    ) -> Size {
+        // the size of the area covered by positioned children
+        // (including their distances from the edges)
+        let mut result = Size::new(0.0f32, 0.0f32);
+
+        let children = control_context.get_children();
+        for child in children.into_iter() {
+            child.measure(drawing_context, Size::new(f32::INFINITY, f32::INFINITY));
+            let child_size = child.get_rect();
+
+            if let Some(offset) =
+                Self::get_position::<Left>(&child).or_else(|| Self::get_position::<Right>(&child))
+            {
+                result.width = result.width.max(offset + child_size.width);
+            }
+            if let Some(offset) =
+                Self::get_position::<Top>(&child).or_else(|| Self::get_position::<Bottom>(&child))
+            {
+                result.height = result.height.max(offset + child_size.height);
+            }
+        }
+
+        result
+    }
+
+    fn set_rect(
+        &mut self,
+        _data: &mut Canvas,
+        control_context: &ControlContext,
+        drawing_context: &mut FuiDrawingContext,
+        rect: Rect,
+    ) {
+        let direction = FlowDirection::get(control_context);
+
+        let children = control_context.get_children();
+        for child in children.into_iter() {
+            let child_size = child.get_rect();
+
+            let x = match Self::get_position::<Left>(&child) {
+                Some(left) => rect.x + left,
+                None => match Self::get_position::<Right>(&child) {
+                    Some(right) => rect.x + rect.width - right - child_size.width,
+                    None => rect.x,
+                },
+            };
+
+            let y = match Self::get_position::<Top>(&child) {
+                Some(top) => rect.y + top,
+                None => match Self::get_position::<Bottom>(&child) {
+                    Some(bottom) => rect.y + rect.height - bottom - child_size.height,
+                    None => rect.y,
+                },
+            };
+
+            let child_rect = Rect::new(x, y, child_size.width, child_size.height);
+            child.set_rect(drawing_context, direction.mirror_rect(child_rect, rect));
+        }
+    }
+
+    fn hit_test(
+        &self,
+        _data: &Canvas,
+        control_context: &ControlContext,
+        point: Point,
+    ) -> Option<Rc<dyn ControlObject>> {
+        // children can be placed outside of the canvas
+        // (the top-most child first)
+        let children = Self::get_children_in_z_order(control_context);
+        for child in children.iter().rev() {
+            let rect = child.get_rect();
+            if point.is_inside(&rect) {
+                let hit_control = child.hit_test(point);
+                if hit_control.is_some() {
+                    return hit_control;
+                }
+            }
+        }
+        None
+    }
+
+    fn draw(
+        &mut self,
+        _data: &Canvas,
+        control_context: &ControlContext,
+        drawing_context: &mut FuiDrawingContext,
+    ) {
+        for child in Self::get_children_in_z_order(control_context) {
+            child.draw(drawing_context);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::control::test_controls::{layout, measure, FixedSize};
+    use crate::task::run_local;
+    use crate::{
+        Bottom, Canvas, Children, ControlObject, Left, Point, Property, Rect, Right, Size, Top,
+        TypeMap, ViewContext,
+    };
+
+    fn create_child(attached_values: TypeMap) -> Rc<dyn ControlObject> {
+        FixedSize::new(
+            20.0f32,
+            10.0f32,
+            ViewContext {
+                attached_values,
+                children: Children::empty(),
+            },
+        )
+    }
+
+    fn create_canvas(children: Vec<Rc<dyn ControlObject>>) -> Rc<dyn ControlObject> {
+        Canvas::builder().build().to_view(
+            None,
+            ViewContext {
+                attached_values: TypeMap::new(),
+                children: Children::MultipleStatic(children),
+            },
+        )
+    }
+
+    #[test]
+    fn test_measure_includes_right_and_bottom_anchored_children() {
+        run_local(async {
+            let mut attached_values = TypeMap::new();
+            attached_values.insert::<Right>(Property::new(5.0f32));
+            attached_values.insert::<Bottom>(Property::new(7.0f32));
+            let child = create_child(attached_values);
+            let canvas = create_canvas(vec![child.clone()]);
+
+            assert_eq!(
+                measure(&canvas, Size::new(100.0f32, 100.0f32)),
+                Size::new(25.0f32, 17.0f32)
+            );
+
+            layout(&canvas, Size::new(100.0f32, 100.0f32));
+            assert_eq!(
+                child.get_context().get_rect(),
+                Rect::new(75.0f32, 83.0f32, 20.0f32, 10.0f32)
+            );
+        });
+    }
+
+    #[test]
+    fn test_hit_test_finds_children_outside_of_canvas() {
+        run_local(async {
+            let mut attached_values = TypeMap::new();
+            attached_values.insert::<Left>(Property::new(-10.0f32));
+            attached_values.insert::<Top>(Property::new(5.0f32));
+            let child = create_child(attached_values);
+            let canvas = create_canvas(vec![child.clone()]);
+
+            layout(&canvas, Size::new(100.0f32, 100.0f32));
+            let hit_control = canvas.hit_test(Point::new(-5.0f32, 10.0f32));
+            assert!(hit_control.is_some_and(|control| Rc::ptr_eq(&control, &child)));
+        });
+    }
+}
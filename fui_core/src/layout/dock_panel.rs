@@ -0,0 +1,306 @@
+use std::rc::Rc;
+
+use crate::{
+    ControlContext, ControlEvent, ControlObject, EventContext, FlowDirection, FuiDrawingContext,
+    Point, Rect, Size, Style, StyledControl, TypeMapKey, ViewContext,
+};
+use typed_builder::TypedBuilder;
+
+//
+// Attached values
+//
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum DockPosition {
+    Left,
+    Top,
+    Right,
+    Bottom,
+}
+
+/// Side of the `DockPanel` the child sticks to (`DockPosition::Left` by default).
+/// Left and right sides are swapped for the right-to-left `FlowDirection`.
+pub struct Dock;
+impl TypeMapKey for Dock {
+    type Value = DockPosition;
+}
+
+//
+// DockPanel
+//
+
+///
+/// Places children at the sides of the panel in the order they are declared.
+/// Every child takes the space from what is left by the previous ones.
+///
+/// # Example
+///
+/// ```ignore
+/// ui! {
+///     DockPanel {
+///         Menu { Dock: DockPosition::Top, ... },
+///         Text { Dock: DockPosition::Bottom, text: "Status" },
+///         Vertical { Dock: DockPosition::Left, ... },
+///         Text { text: "Content" },
+///     }
+/// }
+/// ```
+///
+#[derive(TypedBuilder)]
+pub struct DockPanel {
+    /// The last child fills the space left (its `Dock` value is ignored).
+    #[builder(default = true)]
+    pub last_child_fill: bool,
+}
+
+impl DockPanel {
+    pub fn to_view(
+        self,
+        style: Option<Box<dyn Style<Self>>>,
+        context: ViewContext,
+    ) -> Rc<dyn ControlObject> {
+        StyledControl::new(
+            self,
+            style.unwrap_or_else(|| {
+                Box::new(DefaultDockPanelStyle::new(
+                    DefaultDockPanelStyleParams::builder().build(),
+                ))
+            }),
+            context,
+        )
+    }
+}
+
+//
+// Default DockPanel Style
+//
+
+#[derive(TypedBuilder)]
+pub struct DefaultDockPanelStyleParams {}
+
+pub struct DefaultDockPanelStyle;
+
+impl DefaultDockPanelStyle {
+    pub fn new(_params: DefaultDockPanelStyleParams) -> Self {
+        DefaultDockPanelStyle {}
+    }
+
+    fn get_dock(child: &Rc<dyn ControlObject>) -> DockPosition {
+        child
+            .get_context()
+            .get_attached_value::<Dock>()
+            .map(|dock| *dock)
+            .unwrap_or(DockPosition::Left)
+    }
+}
+
+impl Style<DockPanel> for DefaultDockPanelStyle {
+    fn setup(&mut self, _data: &mut DockPanel, _control_context: &ControlContext) {}
+
+    fn handle_event(
+        &mut self,
+        _data: &mut DockPanel,
+        _control_context: &ControlContext,
+        _drawing_context: &mut FuiDrawingContext,
+        _event_context: &mut dyn EventContext,
+        _event: ControlEvent,
+    ) {
+    }
+
+    fn measure(
+        &mut self,
+        _data: &mut DockPanel,
+        control_context: &ControlContext,
+        drawing_context: &mut FuiDrawingContext,
+        size: Size,
+    ) -> Size {
+        // size taken by the docked children
+        let mut used_width = 0.0f32;
+        let mut used_height = 0.0f32;
+
+        // size needed by the children across the docking direction
+        let mut max_width = 0.0f32;
+        let mut max_height = 0.0f32;
+
+        let children = control_context.get_children();
+        for child in children.into_iter() {
+            let available_size = Size::new(
+                (size.width - used_width).max(0.0f32),
+                (size.height - used_height).max(0.0f32),
+            );
+            child.measure(drawing_context, available_size);
+            let child_size = child.get_rect();
+
+            match Self::get_dock(&child) {
+                DockPosition::Left | DockPosition::Right => {
+                    max_height = max_height.max(used_height + child_size.height);
+                    used_width += child_size.width;
+                }
+                DockPosition::Top | DockPosition::Bottom => {
+                    max_width = max_width.max(used_width + child_size.width);
+                    used_height += child_size.height;
+                }
+            }
+        }
+
+        Size::new(max_width.max(used_width), max_height.max(used_height))
+    }
+
+    fn set_rect(
+        &mut self,
+        data: &mut DockPanel,
+        control_context: &ControlContext,
+        drawing_context: &mut FuiDrawingContext,
+        rect: Rect,
+    ) {
+        let children = control_context.get_children();
+        let children_count = children.into_iter().count();
+        let direction = FlowDirection::get(control_context);
+
+        let mut left = 0.0f32;
+        let mut top = 0.0f32;
+        let mut right = 0.0f32;
+        let mut bottom = 0.0f32;
+
+        for (index, child) in children.into_iter().enumerate() {
+            let child_size = child.get_rect();
+
+            let mut child_rect = Rect::new(
+                rect.x + left,
+                rect.y + top,
+                (rect.width - left - right).max(0.0f32),
+                (rect.height - top - bottom).max(0.0f32),
+            );
+
+            let is_fill = data.last_child_fill && index + 1 == children_count;
+            if !is_fill {
+                match Self::get_dock(&child) {
+                    DockPosition::Left => {
+                        child_rect.width = child_size.width;
+                        left += child_size.width;
+                    }
+                    DockPosition::Top => {
+                        child_rect.height = child_size.height;
+                        top += child_size.height;
+                    }
+                    DockPosition::Right => {
+                        right += child_size.width;
+                        child_rect.x = (rect.x + rect.width - right).max(child_rect.x);
+                        child_rect.width = child_size.width;
+                    }
+                    DockPosition::Bottom => {
+                        bottom += child_size.height;
+                        child_rect.y = (rect.y + rect.height - bottom).max(child_rect.y);
+                        child_rect.height = child_size.height;
+                    }
+                }
+            }
+
+            child.set_rect(drawing_context, direction.mirror_rect(child_rect, rect));
+        }
+    }
+
+    fn hit_test(
+        &self,
+        _data: &DockPanel,
+        control_context: &ControlContext,
+        point: Point,
+    ) -> Option<Rc<dyn ControlObject>> {
+        if point.is_inside(&control_context.get_rect()) {
+            let children = control_context.get_children();
+            for child in children.into_iter() {
+                let rect = child.get_rect();
+                if point.is_inside(&rect) {
+                    let hit_control = child.hit_test(point);
+                    if hit_control.is_some() {
+                        return hit_control;
+                    }
+                }
+            }
+            None
+        } else {
+            None
+        }
+    }
+
+    fn draw(
+        &mut self,
+        _data: &DockPanel,
+        control_context: &ControlContext,
+        drawing_context: &mut FuiDrawingContext,
+    ) {
+        let children = control_context.get_children();
+        for child in children.into_iter() {
+            child.draw(drawing_context);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::control::test_controls::{layout, measure, FixedSize};
+    use crate::{
+        Children, ControlObject, Dock, DockPanel, DockPosition, Rect, Size, TypeMap, ViewContext,
+    };
+
+    fn create_child(width: f32, height: f32, dock: Option<DockPosition>) -> Rc<dyn ControlObject> {
+        let mut attached_values = TypeMap::new();
+        if let Some(dock) = dock {
+            attached_values.insert::<Dock>(dock);
+        }
+        FixedSize::new(
+            width,
+            height,
+            ViewContext {
+                attached_values,
+                children: Children::empty(),
+            },
+        )
+    }
+
+    fn create_dock_panel(children: &[Rc<dyn ControlObject>]) -> Rc<dyn ControlObject> {
+        DockPanel::builder().build().to_view(
+            None,
+            ViewContext {
+                attached_values: TypeMap::new(),
+                children: Children::MultipleStatic(children.to_vec()),
+            },
+        )
+    }
+
+    #[test]
+    fn test_docked_children_and_last_child_fill() {
+        let children = vec![
+            create_child(30.0f32, 10.0f32, Some(DockPosition::Top)),
+            create_child(20.0f32, 15.0f32, Some(DockPosition::Left)),
+            create_child(25.0f32, 15.0f32, Some(DockPosition::Right)),
+            create_child(30.0f32, 5.0f32, Some(DockPosition::Bottom)),
+            create_child(10.0f32, 10.0f32, None),
+        ];
+        let dock_panel = create_dock_panel(&children);
+
+        assert_eq!(
+            measure(&dock_panel, Size::new(200.0f32, 100.0f32)),
+            Size::new(75.0f32, 25.0f32)
+        );
+
+        layout(&dock_panel, Size::new(200.0f32, 100.0f32));
+        let rects: Vec<Rect> = children
+            .iter()
+            .map(|child| child.get_context().get_rect())
+            .collect();
+        assert_eq!(
+            rects,
+            vec![
+                Rect::new(0.0f32, 0.0f32, 200.0f32, 10.0f32),
+                Rect::new(0.0f32, 10.0f32, 20.0f32, 90.0f32),
+                Rect::new(175.0f32, 10.0f32, 25.0f32, 90.0f32),
+                Rect::new(20.0f32, 95.0f32, 155.0f32, 5.0f32),
+                // the last child fills the space left
+                Rect::new(20.0f32, 10.0f32, 155.0f32, 85.0f32),
+            ]
+        );
+    }
+}
@@ -30,9 +30,11 @@ use std::f32;
 use std::rc::Rc;
 
 use crate::{
-    round_layout_value, Children, ControlContext, ControlEvent, ControlObject, EventContext,
-    FuiDrawingContext, Point, Rect, Size, Style, StyledControl, TypeMapKey, ViewContext,
+    round_layout_value, Children, ControlContext, ControlEvent, ControlObject, DpiScale,
+    EventContext, FlowDirection, FuiDrawingContext, IntoProperty, IsSharedSizeScope, Point,
+    Property, Rect, Size, Style, StyledControl, TypeMapKey, UseLayoutRounding, ViewContext,
 };
+use futures_signals::map_ref;
 use typed_builder::TypedBuilder;
 
 //
@@ -58,7 +60,7 @@ pub enum Length {
 struct DefinitionBase {
     pub user_size: Length,
     pub size_type: Length,
-    //pub user_min_size: f32,
+    pub user_min_size: f32,
     pub user_max_size: f32,
 
     //  used during measure to accumulate size for "Auto" and "Fill" DefinitionBase's
@@ -72,6 +74,9 @@ struct DefinitionBase {
 
     // offset of the DefinitionBase from left / top corner (assuming LTR case)
     pub final_offset: f32,
+
+    // name of the shared size group (when there is a shared size scope)
+    pub shared_size_group: Option<String>,
 }
 
 impl DefinitionBase {
@@ -81,12 +86,12 @@ impl DefinitionBase {
         user_max_size: f32,
         treat_fill_as_auto: bool,
     ) -> DefinitionBase {
-        let mut user_min_size = user_min_size;
+        let mut min_size = user_min_size;
         let user_size_value;
         let size_type = match user_size {
             Length::Exact(v) => {
                 user_size_value = v;
-                user_min_size = user_min_size.max(user_size_value.min(user_max_size));
+                min_size = min_size.max(user_size_value.min(user_max_size));
                 Length::Exact(v)
             }
             Length::Auto => {
@@ -106,12 +111,13 @@ impl DefinitionBase {
         DefinitionBase {
             user_size: user_size,
             size_type: size_type,
-            //user_min_size: user_min_size,
+            user_min_size: user_min_size,
             user_max_size: user_max_size,
-            min_size: user_min_size,
-            measure_size: user_min_size.max(user_size_value.min(user_max_size)),
+            min_size: min_size,
+            measure_size: min_size.max(user_size_value.min(user_max_size)),
             size_cache: 0.0f32,
             final_offset: 0.0f32,
+            shared_size_group: None,
         }
     }
 
@@ -120,7 +126,6 @@ impl DefinitionBase {
     }
 
     pub fn get_preferred_size(&self) -> f32 {
-        // may require change when SharedSizeGroup attribute is implemented
         if let Length::Auto = self.user_size {
             self.min_size
         } else {
@@ -129,13 +134,12 @@ impl DefinitionBase {
     }
 
     pub fn get_min_size_for_arrange(&self) -> f32 {
-        // may require change when SharedSizeGroup attribute is implemented
+        // the size of the shared size group is already included in min_size
         self.min_size
     }
 
     pub fn is_shared(&self) -> bool {
-        // may require change when SharedSizeGroup attribute is implemented
-        false
+        self.shared_size_group.is_some()
     }
 }
 
@@ -185,11 +189,11 @@ impl TypeMapKey for ColumnSpan {
 
 #[derive(TypedBuilder)]
 pub struct Grid {
-    #[builder(default = 0)]
-    pub rows: i32,
+    #[builder(default = Property::new(0))]
+    pub rows: Property<i32>,
 
-    #[builder(default = 0)]
-    pub columns: i32,
+    #[builder(default = Property::new(0))]
+    pub columns: Property<i32>,
 
     #[builder(default = Length::Fill(1.0f32))]
     pub default_width: Length,
@@ -197,11 +201,11 @@ pub struct Grid {
     #[builder(default = Length::Fill(1.0f32))]
     pub default_height: Length,
 
-    #[builder(default = Vec::new())]
-    pub widths: Vec<(i32, Length)>,
+    #[builder(default = Property::new(Vec::new()))]
+    pub widths: Property<Vec<(i32, Length)>>,
 
-    #[builder(default = Vec::new())]
-    pub heights: Vec<(i32, Length)>,
+    #[builder(default = Property::new(Vec::new()))]
+    pub heights: Property<Vec<(i32, Length)>>,
 
     #[builder(default = 0.0f32)]
     pub default_min_width: f32,
@@ -215,17 +219,43 @@ pub struct Grid {
     #[builder(default = f32::INFINITY)]
     pub default_max_height: f32,
 
-    #[builder(default = Vec::new())]
-    pub min_widths: Vec<(i32, f32)>,
+    #[builder(default = Property::new(Vec::new()))]
+    pub min_widths: Property<Vec<(i32, f32)>>,
+
+    #[builder(default = Property::new(Vec::new()))]
+    pub min_heights: Property<Vec<(i32, f32)>>,
+
+    #[builder(default = Property::new(Vec::new()))]
+    pub max_widths: Property<Vec<(i32, f32)>>,
+
+    #[builder(default = Property::new(Vec::new()))]
+    pub max_heights: Property<Vec<(i32, f32)>>,
+
+    /// Columns that have the same width as the columns of other grids
+    /// in the same group (see `IsSharedSizeScope`).
+    #[builder(default = Property::new(Vec::new()))]
+    pub shared_width_groups: Property<Vec<(i32, String)>>,
 
-    #[builder(default = Vec::new())]
-    pub min_heights: Vec<(i32, f32)>,
+    /// Rows that have the same height as the rows of other grids
+    /// in the same group (see `IsSharedSizeScope`).
+    #[builder(default = Property::new(Vec::new()))]
+    pub shared_height_groups: Property<Vec<(i32, String)>>,
 
-    #[builder(default = Vec::new())]
-    pub max_widths: Vec<(i32, f32)>,
+    /// Column sizes changed at runtime (by `GridSplitter`).
+    /// They override `widths` and can be bound to persist the layout.
+    #[builder(default = Property::new(Vec::new()))]
+    pub resized_widths: Property<Vec<(i32, Length)>>,
 
-    #[builder(default = Vec::new())]
-    pub max_heights: Vec<(i32, f32)>,
+    /// Row sizes changed at runtime (by `GridSplitter`).
+    /// They override `heights` and can be bound to persist the layout.
+    #[builder(default = Property::new(Vec::new()))]
+    pub resized_heights: Property<Vec<(i32, Length)>>,
+
+    #[builder(setter(skip), default = Vec::new())]
+    actual_columns: Vec<GridDefinition>,
+
+    #[builder(setter(skip), default = Vec::new())]
+    actual_rows: Vec<GridDefinition>,
 }
 
 impl Grid {
@@ -244,8 +274,51 @@ impl Grid {
             context,
         )
     }
+
+    /// Columns as they were arranged by the last layout pass.
+    pub fn get_actual_columns(&self) -> &[GridDefinition] {
+        &self.actual_columns
+    }
+
+    /// Rows as they were arranged by the last layout pass.
+    pub fn get_actual_rows(&self) -> &[GridDefinition] {
+        &self.actual_rows
+    }
+}
+
+///
+/// Row or column of the grid after the layout pass.
+///
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct GridDefinition {
+    /// Requested length (including the resized value).
+    pub length: Length,
+
+    pub min_size: f32,
+    pub max_size: f32,
+
+    /// Size given to the definition.
+    pub actual_size: f32,
+}
+
+impl GridDefinition {
+    fn from_definitions(definitions: &[DefinitionBase]) -> Vec<GridDefinition> {
+        definitions
+            .iter()
+            .map(|def| GridDefinition {
+                length: def.user_size,
+                min_size: def.user_min_size,
+                max_size: def.user_max_size,
+                actual_size: def.size_cache,
+            })
+            .collect()
+    }
 }
 
+impl IntoProperty for Vec<(i32, Length)> {}
+impl IntoProperty for Vec<(i32, f32)> {}
+impl IntoProperty for Vec<(i32, String)> {}
+
 //
 // Default Grid Style
 //
@@ -310,25 +383,27 @@ impl DefaultGridStyle {
             max_column_from_attached = max_column_from_attached.max(max_column);
         }
 
-        let is_horizontal_flow = data.columns > 0;
+        let rows = data.rows.get();
+        let columns = data.columns.get();
+        let is_horizontal_flow = columns > 0;
 
         let number_of_columns;
         let number_of_rows;
         if max_row_from_attached >= 0 || max_column_from_attached >= 0 {
-            number_of_rows = data.rows.max(max_row_from_attached + 1).max(0);
-            number_of_columns = data.columns.max(max_column_from_attached + 1).max(0);
+            number_of_rows = rows.max(max_row_from_attached + 1).max(0);
+            number_of_columns = columns.max(max_column_from_attached + 1).max(0);
         } else {
             if is_horizontal_flow {
-                number_of_columns = data.columns;
-                number_of_rows = if data.rows > 0 {
-                    data.rows
+                number_of_columns = columns;
+                number_of_rows = if rows > 0 {
+                    rows
                 } else {
                     (children.len() as i32 - 1) / number_of_columns + 1
                 };
-            } else if data.rows > 0 {
-                number_of_rows = data.rows;
-                number_of_columns = if data.columns > 0 {
-                    data.columns
+            } else if rows > 0 {
+                number_of_rows = rows;
+                number_of_columns = if columns > 0 {
+                    columns
                 } else {
                     (children.len() as i32 - 1) / number_of_rows + 1
                 }
@@ -344,11 +419,11 @@ impl DefaultGridStyle {
     fn prepare_definitions(
         &mut self,
         data: &Grid,
-        _children: &Children,
         number_of_rows: usize,
         number_of_columns: usize,
         size_to_content_u: bool,
         size_to_content_v: bool,
+        has_shared_size_scope: bool,
     ) {
         let mut widths = Vec::with_capacity(number_of_columns);
         let mut min_widths = Vec::with_capacity(number_of_columns);
@@ -366,56 +441,124 @@ impl DefaultGridStyle {
             min_heights.push(data.default_min_height);
             max_heights.push(data.default_max_height);
         }
-        for (column, width) in &data.widths {
-            if *column >= 0 && *column <= widths.len() as i32 {
+        for (column, width) in data.widths.get().iter() {
+            if *column >= 0 && *column < widths.len() as i32 {
                 widths[*column as usize] = *width;
             }
         }
-        for (column, min_width) in &data.min_widths {
-            if *column >= 0 && *column <= min_widths.len() as i32 {
+        for (column, min_width) in data.min_widths.get().iter() {
+            if *column >= 0 && *column < min_widths.len() as i32 {
                 min_widths[*column as usize] = *min_width;
             }
         }
-        for (column, max_width) in &data.max_widths {
-            if *column >= 0 && *column <= max_widths.len() as i32 {
+        for (column, max_width) in data.max_widths.get().iter() {
+            if *column >= 0 && *column < max_widths.len() as i32 {
                 max_widths[*column as usize] = *max_width;
             }
         }
-        for (row, height) in &data.heights {
-            if *row >= 0 && *row <= heights.len() as i32 {
+        for (column, width) in data.resized_widths.get().iter() {
+            if *column >= 0 && *column < widths.len() as i32 {
+                widths[*column as usize] = *width;
+            }
+        }
+        for (row, height) in data.heights.get().iter() {
+            if *row >= 0 && *row < heights.len() as i32 {
+                heights[*row as usize] = *height;
+            }
+        }
+        for (row, height) in data.resized_heights.get().iter() {
+            if *row >= 0 && *row < heights.len() as i32 {
                 heights[*row as usize] = *height;
             }
         }
-        for (row, min_height) in &data.min_heights {
-            if *row >= 0 && *row <= min_heights.len() as i32 {
+        for (row, min_height) in data.min_heights.get().iter() {
+            if *row >= 0 && *row < min_heights.len() as i32 {
                 min_heights[*row as usize] = *min_height;
             }
         }
-        for (row, max_height) in &data.max_heights {
-            if *row >= 0 && *row <= max_heights.len() as i32 {
+        for (row, max_height) in data.max_heights.get().iter() {
+            if *row >= 0 && *row < max_heights.len() as i32 {
                 max_heights[*row as usize] = *max_height;
             }
         }
 
+        let mut shared_width_groups = vec![None; number_of_columns];
+        let mut shared_height_groups = vec![None; number_of_rows];
+        if has_shared_size_scope {
+            for (column, group) in data.shared_width_groups.get() {
+                if column >= 0 && column < number_of_columns as i32 {
+                    shared_width_groups[column as usize] = Some(group);
+                }
+            }
+            for (row, group) in data.shared_height_groups.get() {
+                if row >= 0 && row < number_of_rows as i32 {
+                    shared_height_groups[row as usize] = Some(group);
+                }
+            }
+        }
+
         self.definitions_u = Vec::new();
-        for i in 0..number_of_columns {
-            let definition =
-                DefinitionBase::new(widths[i], min_widths[i], max_widths[i], size_to_content_u);
+        for (i, shared_size_group) in shared_width_groups.into_iter().enumerate() {
+            let mut definition = DefinitionBase::new(
+                Self::get_shared_length(widths[i], &shared_size_group),
+                min_widths[i],
+                max_widths[i],
+                size_to_content_u,
+            );
+            definition.shared_size_group = shared_size_group;
             self.definitions_u.push(definition);
         }
 
         self.definitions_v = Vec::new();
-        for i in 0..number_of_rows {
-            let definition = DefinitionBase::new(
-                heights[i],
+        for (i, shared_size_group) in shared_height_groups.into_iter().enumerate() {
+            let mut definition = DefinitionBase::new(
+                Self::get_shared_length(heights[i], &shared_size_group),
                 min_heights[i],
                 max_heights[i],
                 size_to_content_v,
             );
+            definition.shared_size_group = shared_size_group;
             self.definitions_v.push(definition);
         }
     }
 
+    // definitions in shared size groups are sized to content instead of filling the space
+    fn get_shared_length(length: Length, shared_size_group: &Option<String>) -> Length {
+        match (length, shared_size_group) {
+            (Length::Fill(_), Some(_)) => Length::Auto,
+            (length, _) => length,
+        }
+    }
+
+    // gives the shared definitions the size of the largest definition in their groups
+    fn update_shared_sizes(&mut self, control_context: &ControlContext) {
+        let Some(scope) = control_context.get_inherited_value::<IsSharedSizeScope>() else {
+            return;
+        };
+
+        let sizes: Vec<(String, f32)> = self
+            .definitions_u
+            .iter()
+            .chain(self.definitions_v.iter())
+            .filter_map(|def| {
+                def.shared_size_group
+                    .as_ref()
+                    .map(|group| (group.clone(), def.min_size))
+            })
+            .collect();
+
+        let shared_sizes = scope.set_sizes(&control_context.get_self_weak(), &sizes);
+
+        let shared_definitions = self
+            .definitions_u
+            .iter_mut()
+            .chain(self.definitions_v.iter_mut())
+            .filter(|def| def.is_shared());
+        for (def, shared_size) in shared_definitions.zip(shared_sizes) {
+            def.update_min_size(shared_size);
+        }
+    }
+
     fn prepare_cell_cache(&mut self, data: &Grid, children: &Children) {
         self.has_fill_cells_u = false;
         self.has_fill_cells_v = false;
@@ -512,7 +655,7 @@ impl DefaultGridStyle {
 
             child_index += 1;
 
-            let is_horizontal_flow = data.columns > 0;
+            let is_horizontal_flow = data.columns.get() > 0;
             if is_horizontal_flow {
                 column_index += 1;
                 if column_index >= self.definitions_u.len() as i32 {
@@ -1056,7 +1199,12 @@ impl DefaultGridStyle {
         }
     }
 
-    fn set_final_size(definitions: &mut Vec<DefinitionBase>, final_size: f32, columns: bool) {
+    fn set_final_size(
+        definitions: &mut Vec<DefinitionBase>,
+        final_size: f32,
+        use_layout_rounding: bool,
+        dpi_scale: f32,
+    ) {
         if definitions.is_empty() {
             return;
         }
@@ -1323,13 +1471,7 @@ impl DefaultGridStyle {
         }
 
         // Phase 5.  Apply layout rounding.
-        let use_layout_rounding = true;
         if use_layout_rounding {
-            // TODO: get dpi from system
-            let dpi_scale_x = 1.0f32;
-            let dpi_scale_y = 1.0f32;
-            let dpi_scale = if columns { dpi_scale_x } else { dpi_scale_y };
-
             let mut rounding_errors = Vec::with_capacity(definitions.len());
             let mut rounded_taken_size = 0.0f32;
             for def in definitions.iter_mut() {
@@ -1460,7 +1602,31 @@ impl DefaultGridStyle {
 }
 
 impl Style<Grid> for DefaultGridStyle {
-    fn setup(&mut self, _data: &mut Grid, _control_context: &ControlContext) {}
+    fn setup(&mut self, data: &mut Grid, control_context: &ControlContext) {
+        // a single task watches all the definition properties
+        control_context.dirty_watch_signal(map_ref! {
+            let _rows = data.rows.change_signal(),
+            let _columns = data.columns.change_signal(),
+            let _widths = data.widths.change_signal(),
+            let _heights = data.heights.change_signal(),
+            let _min_widths = data.min_widths.change_signal(),
+            let _min_heights = data.min_heights.change_signal(),
+            let _max_widths = data.max_widths.change_signal(),
+            let _max_heights = data.max_heights.change_signal(),
+            let _shared_width_groups = data.shared_width_groups.change_signal(),
+            let _shared_height_groups = data.shared_height_groups.change_signal(),
+            let _resized_widths = data.resized_widths.change_signal(),
+            let _resized_heights = data.resized_heights.change_signal() =>
+            ()
+        });
+    }
+
+    fn parent_detached(&mut self, _data: &mut Grid, control_context: &ControlContext) {
+        // stop affecting the sizes of other grids in the scope
+        if let Some(scope) = control_context.get_inherited_value::<IsSharedSizeScope>() {
+            scope.set_sizes(&control_context.get_self_weak(), &[]);
+        }
+    }
 
     fn handle_event(
         &mut self,
@@ -1497,17 +1663,22 @@ impl Style<Grid> for DefaultGridStyle {
                 grid_desired_size.width = grid_desired_size.width.max(child_rc.width);
                 grid_desired_size.height = grid_desired_size.height.max(child_rc.height);
             }
+
+            self.update_shared_sizes(control_context);
         } else {
             let size_to_content_u = size.width == f32::INFINITY;
             let size_to_content_v = size.height == f32::INFINITY;
+            let has_shared_size_scope = control_context
+                .get_inherited_value::<IsSharedSizeScope>()
+                .is_some();
 
             self.prepare_definitions(
                 &data,
-                &children,
                 number_of_rows,
                 number_of_columns,
                 size_to_content_u,
                 size_to_content_v,
+                has_shared_size_scope,
             );
             self.prepare_cell_cache(&data, &children);
 
@@ -1637,6 +1808,8 @@ impl Style<Grid> for DefaultGridStyle {
                 false,
             );
 
+            self.update_shared_sizes(control_context);
+
             grid_desired_size.width = Self::calculate_desired_size(&self.definitions_u);
             grid_desired_size.height = Self::calculate_desired_size(&self.definitions_v);
         }
@@ -1646,7 +1819,7 @@ impl Style<Grid> for DefaultGridStyle {
 
     fn set_rect(
         &mut self,
-        _data: &mut Grid,
+        data: &mut Grid,
         control_context: &ControlContext,
         drawing_context: &mut FuiDrawingContext,
         rect: Rect,
@@ -1654,12 +1827,33 @@ impl Style<Grid> for DefaultGridStyle {
         let children = control_context.get_children();
 
         if self.definitions_u.len() == 0 && self.definitions_v.len() == 0 {
+            data.actual_columns = Vec::new();
+            data.actual_rows = Vec::new();
+
             for child in children.into_iter() {
                 child.set_rect(drawing_context, rect);
             }
         } else {
-            Self::set_final_size(&mut self.definitions_u, rect.width, true);
-            Self::set_final_size(&mut self.definitions_v, rect.height, false);
+            let use_layout_rounding = UseLayoutRounding::get(control_context);
+            let dpi_scale = DpiScale::get(control_context);
+            Self::set_final_size(
+                &mut self.definitions_u,
+                rect.width,
+                use_layout_rounding,
+                dpi_scale,
+            );
+            Self::set_final_size(
+                &mut self.definitions_v,
+                rect.height,
+                use_layout_rounding,
+                dpi_scale,
+            );
+
+            data.actual_columns = GridDefinition::from_definitions(&self.definitions_u);
+            data.actual_rows = GridDefinition::from_definitions(&self.definitions_v);
+
+            // the first column is on the right side for the right-to-left direction
+            let direction = FlowDirection::get(control_context);
 
             for cell in self
                 .cell_group_1
@@ -1692,7 +1886,7 @@ impl Style<Grid> for DefaultGridStyle {
                     Self::get_final_size_for_range(&mut self.definitions_v, row_index, row_span),
                 );
 
-                child.set_rect(drawing_context, rc);
+                child.set_rect(drawing_context, direction.mirror_rect(rc, rect));
             }
         }
     }
@@ -1733,3 +1927,47 @@ impl Style<Grid> for DefaultGridStyle {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::control::test_controls::{layout, FixedSize};
+    use crate::task::{run_local, run_pending_tasks};
+    use crate::{Children, Grid, Length, Property, Rect, Size, ViewContext};
+
+    #[test]
+    fn test_definition_changes_invalidate_layout() {
+        run_local(async {
+            let widths = Property::new(Vec::<(i32, Length)>::new());
+            let child = FixedSize::new(20.0f32, 10.0f32, ViewContext::empty());
+            let grid = Grid::builder()
+                .columns(Property::new(2))
+                .widths(widths.clone())
+                .build()
+                .to_view(
+                    None,
+                    ViewContext {
+                        attached_values: Default::default(),
+                        children: Children::SingleStatic(child.clone()),
+                    },
+                );
+            run_pending_tasks().await;
+
+            layout(&grid, Size::new(100.0f32, 100.0f32));
+            assert!(!grid.get_context().is_measure_dirty());
+            assert_eq!(
+                child.get_context().get_rect(),
+                Rect::new(0.0f32, 0.0f32, 50.0f32, 100.0f32)
+            );
+
+            widths.set(vec![(0, Length::Exact(30.0f32))]);
+            run_pending_tasks().await;
+            assert!(grid.get_context().is_measure_dirty());
+
+            layout(&grid, Size::new(100.0f32, 100.0f32));
+            assert_eq!(
+                child.get_context().get_rect(),
+                Rect::new(0.0f32, 0.0f32, 30.0f32, 100.0f32)
+            );
+        });
+    }
+}
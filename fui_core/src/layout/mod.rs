@@ -1,14 +1,35 @@
 mod horizontal;
 pub use horizontal::*;
 
+mod adaptive_trigger;
+pub use adaptive_trigger::*;
+
+mod aspect_ratio;
+pub use aspect_ratio::*;
+
+mod canvas;
+pub use canvas::*;
+
+mod dock_panel;
+pub use dock_panel::*;
+
 mod grid;
 pub use grid::*;
 
 mod relative_layout;
 pub use relative_layout::*;
 
+mod shared_size_scope;
+pub use shared_size_scope::*;
+
 mod stack_panel;
 pub use stack_panel::*;
 
 mod vertical;
 pub use vertical::*;
+
+mod viewbox;
+pub use viewbox::*;
+
+mod wrap_panel;
+pub use wrap_panel::*;
@@ -4,8 +4,8 @@ use std::{
 };
 
 use crate::{
-    Callback, ControlContext, ControlEvent, ControlObject, EventContext, FuiDrawingContext, Point,
-    Rect, Size, Style, StyledControl, ViewContext,
+    Callback, ControlContext, ControlEvent, ControlObject, EventContext, FlowDirection,
+    FuiDrawingContext, Point, Rect, Size, Style, StyledControl, ViewContext,
 };
 use typed_builder::TypedBuilder;
 
@@ -192,6 +192,7 @@ impl Style<RelativeLayout> for DefaultRelativeLayoutStyle {
         rect: Rect,
     ) {
         let children = control_context.get_children();
+        let direction = FlowDirection::get(control_context);
 
         let mut is_above = false;
         let mut is_left = false;
@@ -228,8 +229,14 @@ impl Style<RelativeLayout> for DefaultRelativeLayoutStyle {
                         let width_right = rect.width
                             - (self.relative_control_rect.x + self.relative_control_rect.width);
 
-                        if width_left > width_right {
-                            is_left = true;
+                        // the reading direction side wins when both sides are equal
+                        is_left = if direction.is_right_to_left() {
+                            width_left >= width_right
+                        } else {
+                            width_left > width_right
+                        };
+
+                        if is_left {
                             Size::new(width_left, rect.height)
                         } else {
                             Size::new(width_right, rect.height)
@@ -255,18 +262,27 @@ impl Style<RelativeLayout> for DefaultRelativeLayoutStyle {
             }
 
             RelativePlacement::BelowOrAboveControl(_) => {
+                let width = content_size.width.max(available_size.width);
+
+                // aligned to the right edge of the control for the right-to-left direction
+                let pos_x = if direction.is_right_to_left() {
+                    self.relative_control_rect.x + self.relative_control_rect.width - width
+                } else {
+                    self.relative_control_rect.x
+                };
+
                 if is_above {
                     Rect::new(
-                        self.relative_control_rect.x,
+                        pos_x,
                         self.relative_control_rect.y - content_size.height,
-                        content_size.width.max(available_size.width),
+                        width,
                         content_size.height,
                     )
                 } else {
                     Rect::new(
-                        self.relative_control_rect.x,
+                        pos_x,
                         self.relative_control_rect.y + self.relative_control_rect.height,
-                        content_size.width.max(available_size.width),
+                        width,
                         content_size.height,
                     )
                 }
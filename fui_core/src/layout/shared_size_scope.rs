@@ -0,0 +1,167 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::{Rc, Weak};
+
+use crate::{spawn_local_and_forget, ControlObject, TypeMapKey};
+
+//
+// Attached values
+//
+
+/// Makes the control a scope for the shared size groups
+/// of all the grids inside it.
+pub struct IsSharedSizeScope;
+impl TypeMapKey for IsSharedSizeScope {
+    type Value = SharedSizeScope;
+}
+
+//
+// SharedSizeScope
+//
+
+///
+/// Keeps the sizes of the grid rows and columns that belong to the same
+/// shared size group (see `Grid::shared_width_groups` and `Grid::shared_height_groups`).
+/// All definitions in the group get the size of the largest one.
+///
+/// # Example
+///
+/// ```ignore
+/// ui! {
+///     Vertical {
+///         IsSharedSizeScope: SharedSizeScope::new(),
+///
+///         Grid {
+///             columns: 2,
+///             shared_width_groups: vec![(0, "Label".to_string())],
+///             Text { text: "Name" },
+///             TextBox { text: &vm.name },
+///         },
+///         Grid {
+///             columns: 2,
+///             shared_width_groups: vec![(0, "Label".to_string())],
+///             Text { text: "Phone number" },
+///             TextBox { text: &vm.phone },
+///         },
+///     }
+/// }
+/// ```
+///
+#[derive(Clone, Default)]
+pub struct SharedSizeScope {
+    groups: Rc<RefCell<HashMap<String, Vec<SharedSizeMember>>>>,
+}
+
+/// Size of the definition measured by its grid (without the shared size applied).
+struct SharedSizeMember {
+    grid: Weak<dyn ControlObject>,
+    size: f32,
+}
+
+impl SharedSizeScope {
+    pub fn new() -> Self {
+        SharedSizeScope::default()
+    }
+
+    ///
+    /// Replaces the sizes measured by the `grid` with new ones
+    /// (group name, size) and returns the sizes of the groups in the same order.
+    ///
+    /// Other grids of the groups which size has changed are measured again.
+    ///
+    pub(crate) fn set_sizes(
+        &self,
+        grid: &Weak<dyn ControlObject>,
+        sizes: &[(String, f32)],
+    ) -> Vec<f32> {
+        let mut grids_to_invalidate: Vec<Weak<dyn ControlObject>> = Vec::new();
+
+        let result = {
+            let mut groups = self.groups.borrow_mut();
+
+            // groups the grid belonged to and the ones it joins
+            let mut names: Vec<String> = groups
+                .iter()
+                .filter(|(_, members)| {
+                    members
+                        .iter()
+                        .any(|member| Weak::ptr_eq(&member.grid, grid))
+                })
+                .map(|(name, _)| name.clone())
+                .collect();
+            for (name, _) in sizes {
+                if !names.contains(name) {
+                    names.push(name.clone());
+                }
+            }
+
+            for name in &names {
+                let members = groups.entry(name.clone()).or_default();
+                let old_size = Self::get_group_size(members);
+
+                members.retain(|member| {
+                    member.grid.strong_count() > 0 && !Weak::ptr_eq(&member.grid, grid)
+                });
+                for (_, size) in sizes.iter().filter(|(group, _)| group == name) {
+                    members.push(SharedSizeMember {
+                        grid: grid.clone(),
+                        size: *size,
+                    });
+                }
+
+                if Self::get_group_size(members) != old_size {
+                    for member in members.iter() {
+                        if !Weak::ptr_eq(&member.grid, grid)
+                            && !grids_to_invalidate
+                                .iter()
+                                .any(|other| Weak::ptr_eq(other, &member.grid))
+                        {
+                            grids_to_invalidate.push(member.grid.clone());
+                        }
+                    }
+                }
+
+                if members.is_empty() {
+                    groups.remove(name);
+                }
+            }
+
+            sizes
+                .iter()
+                .map(|(name, _)| {
+                    groups
+                        .get(name)
+                        .map(|members| Self::get_group_size(members))
+                        .unwrap_or(0.0f32)
+                })
+                .collect()
+        };
+
+        Self::invalidate_grids(grids_to_invalidate);
+
+        result
+    }
+
+    fn get_group_size(members: &[SharedSizeMember]) -> f32 {
+        members
+            .iter()
+            .map(|member| member.size)
+            .fold(0.0f32, f32::max)
+    }
+
+    fn invalidate_grids(grids: Vec<Weak<dyn ControlObject>>) {
+        if grids.is_empty() {
+            return;
+        }
+
+        // post invalidate_measure()
+        // (the grids can be in the middle of the layout pass)
+        spawn_local_and_forget(async move {
+            for grid in grids {
+                if let Some(grid) = grid.upgrade() {
+                    grid.get_context().invalidate_measure();
+                }
+            }
+        });
+    }
+}
@@ -2,9 +2,9 @@ use std::f32;
 use std::rc::Rc;
 
 use crate::{
-    Alignment, ControlContext, ControlEvent, ControlObject, EventContext, FuiDrawingContext,
-    HorizontalAlignment, Orientation, Point, Rect, Size, Style, StyledControl, TypeMapKey,
-    VerticalAlignment, ViewContext,
+    Alignment, ControlContext, ControlEvent, ControlObject, EventContext, FlowDirection,
+    FuiDrawingContext, HorizontalAlignment, Orientation, Point, Rect, Size, Style, StyledControl,
+    TypeMapKey, VerticalAlignment, ViewContext,
 };
 use typed_builder::TypedBuilder;
 
@@ -193,6 +193,8 @@ impl Style<StackPanel> for DefaultStackPanelStyle {
 
         match data.orientation {
             Orientation::Horizontal => {
+                let direction = FlowDirection::get(control_context);
+
                 let child_sizes_sum: f32 = children
                     .into_iter()
                     .map(|child| {
@@ -244,7 +246,7 @@ impl Style<StackPanel> for DefaultStackPanelStyle {
                     let dest_rect =
                         Rect::new(child_rect.x, child_rect.y, child_rect.width, rect.height);
 
-                                        child.set_rect(drawing_context, dest_rect);
+                    child.set_rect(drawing_context, direction.mirror_rect(dest_rect, rect));
 
                     child_rect.x += child_rect.width;
                 }
@@ -0,0 +1,321 @@
+use std::f32;
+use std::rc::Rc;
+
+use crate::{
+    ControlContext, ControlEvent, ControlObject, EventContext, FuiDrawingContext, Point, Rect,
+    RenderTransform, Size, Style, StyledControl, ViewContext,
+};
+use fui_drawing::prelude::*;
+use typed_builder::TypedBuilder;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Stretch {
+    /// The content keeps its natural size.
+    None,
+
+    /// The content fills the whole area (the aspect ratio is not preserved).
+    Fill,
+
+    /// The content is as big as possible while fitting the area
+    /// (the aspect ratio is preserved).
+    Uniform,
+
+    /// The content fills the whole area while preserving the aspect ratio
+    /// (the parts outside the area are clipped).
+    UniformToFill,
+}
+
+//
+// Viewbox
+//
+
+///
+/// Measures its child at the natural size and scales it to fit the available space.
+///
+/// The child is arranged at the natural size and only its drawing is scaled,
+/// so the content looks the same at any size (texts included).
+///
+/// # Example
+///
+/// ```ignore
+/// ui! {
+///     Viewbox {
+///         stretch: Stretch::Uniform,
+///
+///         Grid {
+///             columns: 2,
+///             Text { text: "Temperature" },
+///             Text { text: &vm.temperature },
+///         },
+///     }
+/// }
+/// ```
+///
+#[derive(TypedBuilder)]
+pub struct Viewbox {
+    #[builder(default = Stretch::Uniform)]
+    pub stretch: Stretch,
+}
+
+impl Viewbox {
+    pub fn to_view(
+        self,
+        style: Option<Box<dyn Style<Self>>>,
+        context: ViewContext,
+    ) -> Rc<dyn ControlObject> {
+        StyledControl::new(
+            self,
+            style.unwrap_or_else(|| {
+                Box::new(DefaultViewboxStyle::new(
+                    DefaultViewboxStyleParams::builder().build(),
+                ))
+            }),
+            context,
+        )
+    }
+}
+
+/// Returns the horizontal and vertical scale of the `content_size`
+/// for the `available_size` (infinite when not constrained).
+fn get_scale(stretch: Stretch, available_size: Size, content_size: Size) -> (f32, f32) {
+    let is_width_constrained = available_size.width.is_finite();
+    let is_height_constrained = available_size.height.is_finite();
+
+    if stretch == Stretch::None
+        || (!is_width_constrained && !is_height_constrained)
+        || content_size.width <= 0.0f32
+        || content_size.height <= 0.0f32
+    {
+        return (1.0f32, 1.0f32);
+    }
+
+    let scale_x = available_size.width / content_size.width;
+    let scale_y = available_size.height / content_size.height;
+
+    if !is_width_constrained {
+        (scale_y, scale_y)
+    } else if !is_height_constrained {
+        (scale_x, scale_x)
+    } else {
+        match stretch {
+            Stretch::Uniform => {
+                let scale = scale_x.min(scale_y);
+                (scale, scale)
+            }
+            Stretch::UniformToFill => {
+                let scale = scale_x.max(scale_y);
+                (scale, scale)
+            }
+            _ => (scale_x, scale_y),
+        }
+    }
+}
+
+//
+// Default Viewbox Style
+//
+
+#[derive(TypedBuilder)]
+pub struct DefaultViewboxStyleParams {}
+
+pub struct DefaultViewboxStyle {
+    /// Natural size of the child.
+    content_size: Size,
+}
+
+impl DefaultViewboxStyle {
+    pub fn new(_params: DefaultViewboxStyleParams) -> Self {
+        DefaultViewboxStyle {
+            content_size: Size::new(0.0f32, 0.0f32),
+        }
+    }
+}
+
+impl Style<Viewbox> for DefaultViewboxStyle {
+    fn setup(&mut self, _data: &mut Viewbox, _control_context: &ControlContext) {}
+
+    fn handle_event(
+        &mut self,
+        _data: &mut Viewbox,
+        _control_context: &ControlContext,
+        _drawing_context: &mut FuiDrawingContext,
+        _event_context: &mut dyn EventContext,
+        _event: ControlEvent,
+    ) {
+    }
+
+    fn measure(
+        &mut self,
+        data: &mut Viewbox,
+        control_context: &ControlContext,
+        drawing_context: &mut FuiDrawingContext,
+        size: Size,
+    ) -> Size {
+        let children = control_context.get_children();
+        self.content_size = match children.into_iter().next() {
+            Some(ref content) => {
+                content.measure(drawing_context, Size::new(f32::INFINITY, f32::INFINITY));
+                let rect = content.get_rect();
+                Size::new(rect.width, rect.height)
+            }
+            _ => Size::new(0.0f32, 0.0f32),
+        };
+
+        let (scale_x, scale_y) = get_scale(data.stretch, size, self.content_size);
+        Size::new(
+            self.content_size.width * scale_x,
+            self.content_size.height * scale_y,
+        )
+    }
+
+    fn set_rect(
+        &mut self,
+        data: &mut Viewbox,
+        control_context: &ControlContext,
+        drawing_context: &mut FuiDrawingContext,
+        rect: Rect,
+    ) {
+        let (scale_x, scale_y) = get_scale(
+            data.stretch,
+            Size::new(rect.width, rect.height),
+            self.content_size,
+        );
+
+        // the scaled content is centered
+        let scaled_width = self.content_size.width * scale_x;
+        let scaled_height = self.content_size.height * scale_y;
+        control_context.set_children_transform(Some(RenderTransform::new(
+            rect.x + (rect.width - scaled_width) / 2.0f32,
+            rect.y + (rect.height - scaled_height) / 2.0f32,
+            scale_x,
+            scale_y,
+        )));
+
+        let children = control_context.get_children();
+        if let Some(ref content) = children.into_iter().next() {
+            content.set_rect(
+                drawing_context,
+                Rect::new(
+                    0.0f32,
+                    0.0f32,
+                    self.content_size.width,
+                    self.content_size.height,
+                ),
+            );
+        }
+    }
+
+    fn hit_test(
+        &self,
+        _data: &Viewbox,
+        control_context: &ControlContext,
+        point: Point,
+    ) -> Option<Rc<dyn ControlObject>> {
+        if point.is_inside(&control_context.get_rect()) {
+            let point = match control_context.get_children_transform() {
+                Some(transform) => transform.inverse_transform_point(point),
+                None => point,
+            };
+
+            let children = control_context.get_children();
+            if let Some(ref content) = children.into_iter().next()
+                && point.is_inside(&content.get_rect())
+            {
+                return content.hit_test(point);
+            }
+            None
+        } else {
+            None
+        }
+    }
+
+    fn draw(
+        &mut self,
+        _data: &Viewbox,
+        control_context: &ControlContext,
+        drawing_context: &mut FuiDrawingContext,
+    ) {
+        let r = control_context.get_rect();
+
+        drawing_context.display.save();
+        drawing_context
+            .display
+            .clip_rect(rect(r.x, r.y, r.width, r.height), ClipOperation::Intersect);
+        if let Some(transform) = control_context.get_children_transform() {
+            drawing_context
+                .display
+                .translate(transform.offset_x, transform.offset_y);
+            drawing_context
+                .display
+                .scale(transform.scale_x, transform.scale_y);
+        }
+
+        let children = control_context.get_children();
+        if let Some(content) = children.into_iter().next() {
+            content.draw(drawing_context);
+        }
+
+        drawing_context.display.restore();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::control::test_controls::{layout, measure, FixedSize};
+    use crate::{
+        Children, ControlObject, Point, Rect, RenderTransform, Size, Stretch, TypeMap, ViewContext,
+        Viewbox,
+    };
+
+    fn create_viewbox(stretch: Stretch, content: &Rc<dyn ControlObject>) -> Rc<dyn ControlObject> {
+        Viewbox::builder().stretch(stretch).build().to_view(
+            None,
+            ViewContext {
+                attached_values: TypeMap::new(),
+                children: Children::SingleStatic(content.clone()),
+            },
+        )
+    }
+
+    #[test]
+    fn test_uniform_scaling() {
+        let content: Rc<dyn ControlObject> = FixedSize::new(20.0f32, 10.0f32, ViewContext::empty());
+        let viewbox = create_viewbox(Stretch::Uniform, &content);
+
+        assert_eq!(
+            measure(&viewbox, Size::new(100.0f32, 100.0f32)),
+            Size::new(100.0f32, 50.0f32)
+        );
+
+        layout(&viewbox, Size::new(100.0f32, 100.0f32));
+
+        // the content keeps its natural size and is scaled (centered)
+        assert_eq!(
+            content.get_context().get_rect(),
+            Rect::new(0.0f32, 0.0f32, 20.0f32, 10.0f32)
+        );
+        assert_eq!(
+            viewbox.get_context().get_children_transform(),
+            Some(RenderTransform::new(0.0f32, 25.0f32, 5.0f32, 5.0f32))
+        );
+
+        // the pointer position is mapped to the content coordinates
+        let hit_control = viewbox.hit_test(Point::new(95.0f32, 70.0f32));
+        assert!(hit_control.is_some_and(|control| Rc::ptr_eq(&control, &content)));
+        assert!(viewbox.hit_test(Point::new(50.0f32, 80.0f32)).is_none());
+    }
+
+    #[test]
+    fn test_fill_scaling() {
+        let content: Rc<dyn ControlObject> = FixedSize::new(20.0f32, 10.0f32, ViewContext::empty());
+        let viewbox = create_viewbox(Stretch::Fill, &content);
+
+        layout(&viewbox, Size::new(100.0f32, 100.0f32));
+        assert_eq!(
+            viewbox.get_context().get_children_transform(),
+            Some(RenderTransform::new(0.0f32, 0.0f32, 5.0f32, 10.0f32))
+        );
+    }
+}
@@ -0,0 +1,366 @@
+use std::f32;
+use std::rc::Rc;
+
+use crate::{
+    Alignment, ControlContext, ControlEvent, ControlObject, EventContext, FlowDirection,
+    FuiDrawingContext, HorizontalAlignment, Orientation, Point, Rect, Size, Style, StyledControl,
+    VerticalAlignment, ViewContext,
+};
+use typed_builder::TypedBuilder;
+
+//
+// WrapPanel
+//
+
+///
+/// Places children one after another (left to right for horizontal orientation,
+/// top to bottom for vertical) and wraps them to the next line
+/// when there is no more space left.
+///
+#[derive(TypedBuilder)]
+pub struct WrapPanel {
+    #[builder(default = Orientation::Horizontal)]
+    pub orientation: Orientation,
+
+    /// Space between the items in the line.
+    #[builder(default = 0.0f32)]
+    pub item_spacing: f32,
+
+    /// Space between the lines.
+    #[builder(default = 0.0f32)]
+    pub line_spacing: f32,
+
+    /// Position of the items in the line.
+    /// `Alignment::Stretch` distributes the space left between the items.
+    #[builder(default = Alignment::Start)]
+    pub line_alignment: Alignment,
+
+    /// Size of every item. When `None` the items have their desired sizes.
+    #[builder(default = None)]
+    pub item_size: Option<Size>,
+}
+
+impl WrapPanel {
+    pub fn to_view(
+        self,
+        style: Option<Box<dyn Style<Self>>>,
+        mut context: ViewContext,
+    ) -> Rc<dyn ControlObject> {
+        // set default alignment to Start
+        context
+            .attached_values
+            .entry::<HorizontalAlignment>()
+            .or_insert(Alignment::Start);
+        context
+            .attached_values
+            .entry::<VerticalAlignment>()
+            .or_insert(Alignment::Start);
+
+        StyledControl::new(
+            self,
+            style.unwrap_or_else(|| {
+                Box::new(DefaultWrapPanelStyle::new(
+                    DefaultWrapPanelStyleParams::builder().build(),
+                ))
+            }),
+            context,
+        )
+    }
+}
+
+//
+// Default WrapPanel Style
+//
+
+#[derive(TypedBuilder)]
+pub struct DefaultWrapPanelStyleParams {}
+
+pub struct DefaultWrapPanelStyle;
+
+impl DefaultWrapPanelStyle {
+    pub fn new(_params: DefaultWrapPanelStyleParams) -> Self {
+        DefaultWrapPanelStyle {}
+    }
+}
+
+/// Children in the line (range of indices), the number of the non-empty ones
+/// and the size of the line (along the orientation and across it).
+struct Line {
+    start: usize,
+    end: usize,
+    item_count: usize,
+    main_size: f32,
+    cross_size: f32,
+}
+
+/// Converts size to (along the orientation, across the orientation).
+fn to_main_cross(orientation: &Orientation, size: Size) -> (f32, f32) {
+    match orientation {
+        Orientation::Horizontal => (size.width, size.height),
+        Orientation::Vertical => (size.height, size.width),
+    }
+}
+
+fn from_main_cross(orientation: &Orientation, main: f32, cross: f32) -> Size {
+    match orientation {
+        Orientation::Horizontal => Size::new(main, cross),
+        Orientation::Vertical => Size::new(cross, main),
+    }
+}
+
+/// Zero-size (e.g. invisible) items take no space and have no spacing around them.
+fn is_empty_item(main: f32, cross: f32) -> bool {
+    main == 0.0f32 && cross == 0.0f32
+}
+
+impl DefaultWrapPanelStyle {
+    fn get_item_sizes(data: &WrapPanel, control_context: &ControlContext) -> Vec<(f32, f32)> {
+        control_context
+            .get_children()
+            .into_iter()
+            .map(|child| {
+                let rect = child.get_rect();
+                let size = match data.item_size {
+                    // invisible children are measured as empty
+                    Some(item_size) if rect.width > 0.0f32 || rect.height > 0.0f32 => item_size,
+                    _ => Size::new(rect.width, rect.height),
+                };
+                to_main_cross(&data.orientation, size)
+            })
+            .collect()
+    }
+
+    fn split_into_lines(data: &WrapPanel, item_sizes: &[(f32, f32)], max_main: f32) -> Vec<Line> {
+        let mut lines = Vec::new();
+        let mut line = Line {
+            start: 0,
+            end: 0,
+            item_count: 0,
+            main_size: 0.0f32,
+            cross_size: 0.0f32,
+        };
+
+        for (index, &(main, cross)) in item_sizes.iter().enumerate() {
+            if is_empty_item(main, cross) {
+                line.end = index + 1;
+                continue;
+            }
+
+            let spacing = if line.item_count > 0 {
+                data.item_spacing
+            } else {
+                0.0f32
+            };
+
+            if line.item_count > 0 && line.main_size + spacing + main > max_main {
+                let next_line = Line {
+                    start: index,
+                    end: index,
+                    item_count: 0,
+                    main_size: 0.0f32,
+                    cross_size: 0.0f32,
+                };
+                lines.push(std::mem::replace(&mut line, next_line));
+                line.main_size = main;
+            } else {
+                line.main_size += spacing + main;
+            }
+
+            line.cross_size = line.cross_size.max(cross);
+            line.item_count += 1;
+            line.end = index + 1;
+        }
+
+        if line.end > line.start {
+            lines.push(line);
+        }
+
+        lines
+    }
+}
+
+impl Style<WrapPanel> for DefaultWrapPanelStyle {
+    fn setup(&mut self, _data: &mut WrapPanel, _control_context: &ControlContext) {}
+
+    fn handle_event(
+        &mut self,
+        _data: &mut WrapPanel,
+        _control_context: &ControlContext,
+        _drawing_context: &mut FuiDrawingContext,
+        _event_context: &mut dyn EventContext,
+        _event: ControlEvent,
+    ) {
+    }
+
+    fn measure(
+        &mut self,
+        data: &mut WrapPanel,
+        control_context: &ControlContext,
+        drawing_context: &mut FuiDrawingContext,
+        size: Size,
+    ) -> Size {
+        let child_available_size = data.item_size.unwrap_or(size);
+        for child in control_context.get_children().into_iter() {
+            child.measure(drawing_context, child_available_size);
+        }
+
+        let (max_main, _) = to_main_cross(&data.orientation, size);
+        let item_sizes = Self::get_item_sizes(data, control_context);
+        let lines = Self::split_into_lines(data, &item_sizes, max_main);
+
+        let main = lines
+            .iter()
+            .map(|line| line.main_size)
+            .fold(0.0f32, f32::max);
+        let cross = lines.iter().map(|line| line.cross_size).sum::<f32>()
+            + data.line_spacing * (lines.len().max(1) - 1) as f32;
+
+        from_main_cross(&data.orientation, main, cross)
+    }
+
+    fn set_rect(
+        &mut self,
+        data: &mut WrapPanel,
+        control_context: &ControlContext,
+        drawing_context: &mut FuiDrawingContext,
+        rect: Rect,
+    ) {
+        let (max_main, _) = to_main_cross(&data.orientation, Size::new(rect.width, rect.height));
+        let item_sizes = Self::get_item_sizes(data, control_context);
+        let lines = Self::split_into_lines(data, &item_sizes, max_main);
+
+        let children: Vec<Rc<dyn ControlObject>> =
+            control_context.get_children().into_iter().collect();
+
+        let direction = FlowDirection::get(control_context);
+
+        let mut cross_pos = 0.0f32;
+        for line in lines {
+            let space_left = (max_main - line.main_size).max(0.0f32);
+            let item_count = line.item_count;
+
+            let (mut main_pos, extra_spacing) = match data.line_alignment {
+                Alignment::Start => (0.0f32, 0.0f32),
+                Alignment::Center => (space_left / 2.0f32, 0.0f32),
+                Alignment::End => (space_left, 0.0f32),
+                Alignment::Stretch => {
+                    if item_count > 1 && max_main.is_finite() {
+                        (0.0f32, space_left / (item_count - 1) as f32)
+                    } else {
+                        (0.0f32, 0.0f32)
+                    }
+                }
+            };
+
+            let line_children = &children[line.start..line.end];
+            let line_item_sizes = &item_sizes[line.start..line.end];
+            for (child, &(main, cross)) in line_children.iter().zip(line_item_sizes) {
+                let child_size = from_main_cross(&data.orientation, main, line.cross_size);
+                let (x, y) = match data.orientation {
+                    Orientation::Horizontal => (main_pos, cross_pos),
+                    Orientation::Vertical => (cross_pos, main_pos),
+                };
+
+                let child_rect =
+                    Rect::new(rect.x + x, rect.y + y, child_size.width, child_size.height);
+                child.set_rect(drawing_context, direction.mirror_rect(child_rect, rect));
+
+                if !is_empty_item(main, cross) {
+                    main_pos += main + data.item_spacing + extra_spacing;
+                }
+            }
+
+            cross_pos += line.cross_size + data.line_spacing;
+        }
+    }
+
+    fn hit_test(
+        &self,
+        _data: &WrapPanel,
+        control_context: &ControlContext,
+        point: Point,
+    ) -> Option<Rc<dyn ControlObject>> {
+        if point.is_inside(&control_context.get_rect()) {
+            let children = control_context.get_children();
+            for child in children.into_iter() {
+                let rect = child.get_rect();
+                if point.is_inside(&rect) {
+                    let hit_control = child.hit_test(point);
+                    if hit_control.is_some() {
+                        return hit_control;
+                    }
+                }
+            }
+            None
+        } else {
+            None
+        }
+    }
+
+    fn draw(
+        &mut self,
+        _data: &WrapPanel,
+        control_context: &ControlContext,
+        drawing_context: &mut FuiDrawingContext,
+    ) {
+        let children = control_context.get_children();
+        for child in children.into_iter() {
+            child.draw(drawing_context);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::control::test_controls::{layout, measure, FixedSize};
+    use crate::task::run_local;
+    use crate::{
+        Children, ControlObject, Property, Rect, Size, StyledControl, TypeMap, ViewContext,
+        Visible, WrapPanel,
+    };
+
+    #[test]
+    fn test_invisible_children_have_no_spacing() {
+        run_local(async {
+            let children: Vec<Rc<StyledControl<FixedSize>>> = (0..3)
+                .map(|index| {
+                    let mut attached_values = TypeMap::new();
+                    attached_values.insert::<Visible>(Property::new(index != 1));
+                    FixedSize::new(
+                        20.0f32,
+                        10.0f32,
+                        ViewContext {
+                            attached_values,
+                            children: Children::empty(),
+                        },
+                    )
+                })
+                .collect();
+            let panel = WrapPanel::builder().item_spacing(10.0f32).build().to_view(
+                None,
+                ViewContext {
+                    attached_values: TypeMap::new(),
+                    children: Children::MultipleStatic(
+                        children
+                            .iter()
+                            .map(|child| child.clone() as Rc<dyn ControlObject>)
+                            .collect(),
+                    ),
+                },
+            );
+
+            assert_eq!(
+                measure(&panel, Size::new(100.0f32, 100.0f32)),
+                Size::new(50.0f32, 10.0f32)
+            );
+
+            layout(&panel, Size::new(100.0f32, 100.0f32));
+            assert_eq!(
+                children[2].get_context().get_rect(),
+                Rect::new(30.0f32, 0.0f32, 20.0f32, 10.0f32)
+            );
+        });
+    }
+}
@@ -14,9 +14,14 @@ pub use view::*;
 
 pub use crate::drawing::*;
 
+#[cfg(feature = "diagnostics")]
+pub use crate::diagnostics::*;
+
 mod children;
 mod common;
 mod control;
+#[cfg(feature = "diagnostics")]
+mod diagnostics;
 mod drawing;
 mod events;
 mod layout;
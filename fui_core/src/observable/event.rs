@@ -15,16 +15,26 @@ use crate::Callback;
 ///
 pub struct EventSubscription {
     _callbacks: Vec<Rc<dyn CallbackObject>>,
+
+    #[cfg(feature = "diagnostics")]
+    _diagnostics_tokens: Vec<crate::diagnostics::SubscriptionToken>,
 }
 
 impl EventSubscription {
     pub fn from_many(event_subscriptions: Vec<EventSubscription>) -> Self {
         let mut callbacks = Vec::new();
+        #[cfg(feature = "diagnostics")]
+        let mut diagnostics_tokens = Vec::new();
         for mut subscription in event_subscriptions.into_iter() {
             callbacks.append(&mut subscription._callbacks);
+            #[cfg(feature = "diagnostics")]
+            diagnostics_tokens.append(&mut subscription._diagnostics_tokens);
         }
         EventSubscription {
             _callbacks: callbacks,
+
+            #[cfg(feature = "diagnostics")]
+            _diagnostics_tokens: diagnostics_tokens,
         }
     }
 }
@@ -47,6 +57,7 @@ impl<A: 'static + Clone> Event<A> {
         }
     }
 
+    #[cfg_attr(feature = "diagnostics", track_caller)]
     pub fn subscribe<F: 'static + FnMut(A)>(&mut self, f: F) -> EventSubscription {
         let mut callback = Callback::<A>::empty();
         callback.set_sync(f);
@@ -56,6 +67,12 @@ impl<A: 'static + Clone> Event<A> {
 
         EventSubscription {
             _callbacks: vec![rc_callback],
+
+            #[cfg(feature = "diagnostics")]
+            _diagnostics_tokens: vec![crate::diagnostics::SubscriptionToken::new(
+                crate::diagnostics::SubscriptionKind::Event,
+                std::panic::Location::caller(),
+            )],
         }
     }
 
@@ -7,6 +7,19 @@ pub use event::*;
 mod property;
 pub use property::*;
 
+mod property_operators;
+
+mod resource;
+pub use resource::*;
+
+#[cfg(feature = "serde")]
+mod serialization;
+
+#[cfg(feature = "serde")]
+mod state_snapshot;
+#[cfg(feature = "serde")]
+pub use state_snapshot::*;
+
 mod observable_collection;
 pub use observable_collection::*;
 
@@ -24,3 +37,6 @@ pub use observable_vec::*;
 
 mod subscription;
 pub use subscription::*;
+
+mod undo_manager;
+pub use undo_manager::*;
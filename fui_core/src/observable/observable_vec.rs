@@ -32,6 +32,7 @@ impl<T: 'static + Clone> ObservableVec<T> {
         self.items.lock_ref().get(index).map(|el| el.clone())
     }
 
+    #[cfg_attr(feature = "diagnostics", track_caller)]
     pub fn on_changed<F>(&self, mut f: F) -> Subscription
     where
         F: 'static + FnMut(VecDiff<T>),
@@ -1,4 +1,4 @@
-use futures_signals::signal::{Mutable, MutableLockMut, MutableLockRef, SignalExt};
+use futures_signals::signal::{Mutable, MutableLockMut, MutableLockRef, Signal, SignalExt};
 use std::ops::{Deref, DerefMut};
 use std::rc::Rc;
 use std::sync::{Arc, RwLock};
@@ -71,7 +71,7 @@ impl<'a, T> DerefMut for PropertyWriteGuard<'a, T> {
 }
 
 pub struct Property<T> {
-    data: Mutable<T>,
+    pub(crate) data: Mutable<T>,
     bind_handles: Arc<RwLock<Vec<Subscription>>>,
 }
 
@@ -83,6 +83,7 @@ impl<T: 'static + Clone + PartialEq> Property<T> {
         }
     }
 
+    #[cfg_attr(feature = "diagnostics", track_caller)]
     pub fn binded_from(src_property: &Property<T>) -> Self {
         let new_property = Property {
             data: Mutable::new(src_property.get()),
@@ -92,6 +93,7 @@ impl<T: 'static + Clone + PartialEq> Property<T> {
         new_property
     }
 
+    #[cfg_attr(feature = "diagnostics", track_caller)]
     pub fn binded_c_from<TSrc: 'static + Clone + PartialEq, F: 'static + Fn(TSrc) -> T>(
         src_property: &Property<TSrc>,
         f: F,
@@ -101,12 +103,14 @@ impl<T: 'static + Clone + PartialEq> Property<T> {
         new_property
     }
 
+    #[cfg_attr(feature = "diagnostics", track_caller)]
     pub fn binded_to(dst_property: &Property<T>, init_value: T) -> Self {
         let property = Property::new(init_value);
         dst_property.bind(&property);
         property
     }
 
+    #[cfg_attr(feature = "diagnostics", track_caller)]
     pub fn binded_c_to<TDst: 'static + Clone + PartialEq, F: 'static + Fn(T) -> TDst>(
         dst_property: &Property<TDst>,
         f: F,
@@ -121,6 +125,7 @@ impl<T: 'static + Clone + PartialEq> Property<T> {
         other_property.clone()
     }
 
+    #[cfg_attr(feature = "diagnostics", track_caller)]
     pub fn binded_c_two_way<TOther, F1, F2>(
         other_property: &Property<TOther>,
         f1: F1,
@@ -163,6 +168,7 @@ impl<T: 'static + Clone + PartialEq> Property<T> {
         }
     }
 
+    #[cfg_attr(feature = "diagnostics", track_caller)]
     pub fn bind(&self, src_property: &Property<T>) {
         let handle = spawn_local(src_property.data.signal_cloned().for_each({
             let data = self.data.clone();
@@ -171,12 +177,15 @@ impl<T: 'static + Clone + PartialEq> Property<T> {
                 async {}
             }
         }));
+        #[cfg(feature = "diagnostics")]
+        crate::diagnostics::set_source_property(&handle, src_property.diagnostics_id());
         self.bind_handles
             .write()
             .unwrap()
             .push(Subscription::SpawnLocal(handle));
     }
 
+    #[cfg_attr(feature = "diagnostics", track_caller)]
     pub fn bind_c<TSrc: 'static + Clone + PartialEq, F: 'static + Fn(TSrc) -> T>(
         &self,
         src_property: &Property<TSrc>,
@@ -189,6 +198,8 @@ impl<T: 'static + Clone + PartialEq> Property<T> {
                 async {}
             }
         }));
+        #[cfg(feature = "diagnostics")]
+        crate::diagnostics::set_source_property(&handle, src_property.diagnostics_id());
         self.bind_handles
             .write()
             .unwrap()
@@ -255,11 +266,22 @@ impl<T: 'static + Clone + PartialEq> Property<T> {
         result_prop
     }
 
+    #[cfg_attr(feature = "diagnostics", track_caller)]
     pub fn on_changed<F: 'static + FnMut(T)>(&self, mut f: F) -> Subscription {
-        Subscription::SpawnLocal(spawn_local(self.data.signal_cloned().for_each(move |v| {
+        let handle = spawn_local(self.data.signal_cloned().for_each(move |v| {
             f(v);
             async {}
-        })))
+        }));
+        #[cfg(feature = "diagnostics")]
+        crate::diagnostics::set_source_property(&handle, self.diagnostics_id());
+        Subscription::SpawnLocal(handle)
+    }
+
+    /// Returns a signal emitting `()` for the current value and then
+    /// on every change. Signals of properties of different types
+    /// can be combined with `map_ref!` and watched by a single task.
+    pub(crate) fn change_signal(&self) -> impl Signal<Item = ()> + use<T> {
+        self.data.signal_ref(|_| ())
     }
 
     /// Adds a subscription to the internal bind_handles collection.
@@ -267,6 +289,19 @@ impl<T: 'static + Clone + PartialEq> Property<T> {
     pub fn add_bind_subscription(&self, subscription: Subscription) {
         self.bind_handles.write().unwrap().push(subscription);
     }
+
+    /// Returns the number of subscriptions kept by the property
+    /// (bindings to other properties).
+    #[cfg(feature = "diagnostics")]
+    pub fn bind_subscription_count(&self) -> usize {
+        self.bind_handles.read().unwrap().len()
+    }
+
+    /// All the clones of the property share the same bind handles.
+    #[cfg(feature = "diagnostics")]
+    pub(crate) fn diagnostics_id(&self) -> usize {
+        Arc::as_ptr(&self.bind_handles) as usize
+    }
 }
 
 impl<T: 'static + Clone + PartialEq> Clone for Property<T> {
@@ -0,0 +1,310 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use crate::{spawn_local, JoinHandle, Property};
+
+///
+/// Reactive operators for Property.
+///
+/// Every operator returns a new Property that keeps its subscriptions
+/// to the source properties in its bind handles, so they are cancelled
+/// when the last clone of the resulting property is dropped.
+///
+impl<T: 'static + Clone + PartialEq> Property<T> {
+    /// Creates a new Property with a value computed from this one.
+    ///
+    /// It is named `map_value` to not collide with `ObservableCollectionExt::map`
+    /// which maps a property to an observable collection.
+    #[cfg_attr(feature = "diagnostics", track_caller)]
+    pub fn map_value<TDst, F>(&self, f: F) -> Property<TDst>
+    where
+        TDst: 'static + Clone + PartialEq,
+        F: 'static + Fn(T) -> TDst,
+    {
+        Property::binded_c_from(self, f)
+    }
+
+    /// Creates a new Property that follows only the values accepted by the predicate.
+    ///
+    /// The initial value is always taken from the source property.
+    #[cfg_attr(feature = "diagnostics", track_caller)]
+    pub fn filter<F>(&self, predicate: F) -> Property<T>
+    where
+        F: 'static + Fn(&T) -> bool,
+    {
+        let result = Property::new(self.get());
+        let data = result.data.clone();
+        result.add_bind_subscription(self.on_changed(move |v| {
+            if predicate(&v) {
+                data.set_neq(v);
+            }
+        }));
+        result
+    }
+
+    /// Creates a new Property that is updated only when the key
+    /// computed from the source value changes.
+    #[cfg_attr(feature = "diagnostics", track_caller)]
+    pub fn distinct_until_changed_by<K, F>(&self, key: F) -> Property<T>
+    where
+        K: 'static + PartialEq,
+        F: 'static + Fn(&T) -> K,
+    {
+        let result = Property::new(self.get());
+        let data = result.data.clone();
+        let mut last_key = key(&*self.read());
+        result.add_bind_subscription(self.on_changed(move |v| {
+            let new_key = key(&v);
+            if new_key != last_key {
+                last_key = new_key;
+                data.set_neq(v);
+            }
+        }));
+        result
+    }
+
+    /// Creates a new Property that is updated with the latest source value
+    /// after the source has not changed for the `duration` time.
+    ///
+    /// Useful for search-as-you-type fields.
+    #[cfg_attr(feature = "diagnostics", track_caller)]
+    pub fn debounce(&self, duration: Duration) -> Property<T> {
+        let result = Property::new(self.get());
+        let data = result.data.clone();
+
+        // dropping the previous handle aborts the pending update
+        let mut pending: Option<JoinHandle<()>> = None;
+        result.add_bind_subscription(self.on_changed(move |v| {
+            let data = data.clone();
+            pending.replace(spawn_local(async move {
+                tokio::time::sleep(duration).await;
+                data.set_neq(v);
+            }));
+        }));
+        result
+    }
+
+    /// Creates a new Property that is updated at most once per `duration` time.
+    ///
+    /// The first change is passed immediately, the last change made
+    /// during the throttling period is passed when the period ends.
+    #[cfg_attr(feature = "diagnostics", track_caller)]
+    pub fn throttle(&self, duration: Duration) -> Property<T> {
+        struct ThrottleState<T> {
+            is_throttling: bool,
+            pending_value: Option<T>,
+            timer: Option<JoinHandle<()>>,
+        }
+
+        let result = Property::new(self.get());
+        let data = result.data.clone();
+        let state = Rc::new(RefCell::new(ThrottleState {
+            is_throttling: false,
+            pending_value: None,
+            timer: None,
+        }));
+
+        result.add_bind_subscription(self.on_changed(move |v| {
+            let mut state_ref = state.borrow_mut();
+            if state_ref.is_throttling {
+                state_ref.pending_value = Some(v);
+                return;
+            }
+
+            data.set_neq(v);
+            state_ref.is_throttling = true;
+
+            let data = data.clone();
+            let state_weak = Rc::downgrade(&state);
+            state_ref.timer = Some(spawn_local(async move {
+                loop {
+                    tokio::time::sleep(duration).await;
+
+                    let Some(state) = state_weak.upgrade() else {
+                        return;
+                    };
+                    let mut state = state.borrow_mut();
+                    match state.pending_value.take() {
+                        Some(v) => data.set_neq(v),
+                        None => {
+                            state.is_throttling = false;
+                            return;
+                        }
+                    }
+                }
+            }));
+        }));
+        result
+    }
+
+    /// Creates a new Property of pairs made from the changes of both properties.
+    ///
+    /// The pair is updated when both properties have changed since the last pair.
+    /// Changes are observed through the property signals, which coalesce changes
+    /// made one after another, so the pair holds the latest values of both
+    /// properties (the n-th change of one is not guaranteed to be paired
+    /// with the n-th change of the other).
+    #[cfg_attr(feature = "diagnostics", track_caller)]
+    pub fn zip<U>(&self, other: &Property<U>) -> Property<(T, U)>
+    where
+        U: 'static + Clone + PartialEq,
+    {
+        let result: Property<(T, U)> = Property::new((self.get(), other.get()));
+        let latest = Rc::new(RefCell::new((None::<T>, None::<U>)));
+
+        let emit_pair = {
+            let data = result.data.clone();
+            let latest = latest.clone();
+            Rc::new(move || {
+                let mut latest = latest.borrow_mut();
+                if latest.0.is_some() && latest.1.is_some() {
+                    let pair = (latest.0.take().unwrap(), latest.1.take().unwrap());
+                    data.set_neq(pair);
+                }
+            })
+        };
+
+        result.add_bind_subscription(self.on_changed({
+            let latest = latest.clone();
+            let emit_pair = emit_pair.clone();
+            move |v| {
+                latest.borrow_mut().0 = Some(v);
+                emit_pair();
+            }
+        }));
+        result.add_bind_subscription(other.on_changed(move |v| {
+            latest.borrow_mut().1 = Some(v);
+            emit_pair();
+        }));
+        result
+    }
+
+    /// Creates a new Property of pairs made from the latest values
+    /// of both properties. It is updated when any of them changes.
+    #[cfg_attr(feature = "diagnostics", track_caller)]
+    pub fn combine_latest<U>(&self, other: &Property<U>) -> Property<(T, U)>
+    where
+        U: 'static + Clone + PartialEq,
+    {
+        let result: Property<(T, U)> = Property::new((self.get(), other.get()));
+
+        result.add_bind_subscription(self.on_changed({
+            let data = result.data.clone();
+            move |v| {
+                let mut pair = data.lock_mut();
+                if pair.0 != v {
+                    pair.0 = v;
+                }
+            }
+        }));
+        result.add_bind_subscription(other.on_changed({
+            let data = result.data.clone();
+            move |v| {
+                let mut pair = data.lock_mut();
+                if pair.1 != v {
+                    pair.1 = v;
+                }
+            }
+        }));
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::task::{run_local, run_pending_tasks};
+    use crate::Property;
+
+    #[test]
+    fn test_debounce() {
+        run_local(async {
+            let source = Property::new(0);
+            let debounced = source.debounce(Duration::from_millis(100));
+            run_pending_tasks().await;
+
+            source.set(1);
+            run_pending_tasks().await;
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            source.set(2);
+            run_pending_tasks().await;
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            assert_eq!(debounced.get(), 0);
+
+            tokio::time::sleep(Duration::from_millis(60)).await;
+            assert_eq!(debounced.get(), 2);
+        });
+    }
+
+    #[test]
+    fn test_throttle() {
+        run_local(async {
+            let source = Property::new(0);
+            let throttled = source.throttle(Duration::from_millis(100));
+
+            // the initial value starts the throttling period
+            run_pending_tasks().await;
+            source.set(1);
+            source.set(2);
+            run_pending_tasks().await;
+            assert_eq!(throttled.get(), 0);
+
+            tokio::time::sleep(Duration::from_millis(110)).await;
+            assert_eq!(throttled.get(), 2);
+
+            // the value passed at the end of the period starts the next one
+            source.set(3);
+            run_pending_tasks().await;
+            assert_eq!(throttled.get(), 2);
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            assert_eq!(throttled.get(), 3);
+
+            // the first change after the quiet period is passed immediately
+            tokio::time::sleep(Duration::from_millis(300)).await;
+            source.set(4);
+            run_pending_tasks().await;
+            assert_eq!(throttled.get(), 4);
+        });
+    }
+
+    #[test]
+    fn test_distinct_until_changed_by() {
+        run_local(async {
+            let source = Property::new("apple".to_string());
+            let distinct = source.distinct_until_changed_by(|s: &String| s.chars().next());
+            run_pending_tasks().await;
+
+            source.set("avocado".to_string());
+            run_pending_tasks().await;
+            assert_eq!(distinct.get(), "apple");
+
+            source.set("banana".to_string());
+            run_pending_tasks().await;
+            assert_eq!(distinct.get(), "banana");
+        });
+    }
+
+    #[test]
+    fn test_zip() {
+        run_local(async {
+            let left = Property::new(1);
+            let right = Property::new("a");
+            let zipped = left.zip(&right);
+            run_pending_tasks().await;
+            assert_eq!(zipped.get(), (1, "a"));
+
+            // waits for the change of the other property
+            left.set(2);
+            run_pending_tasks().await;
+            assert_eq!(zipped.get(), (1, "a"));
+
+            // subsequent changes are coalesced
+            right.set("b");
+            right.set("c");
+            run_pending_tasks().await;
+            assert_eq!(zipped.get(), (2, "c"));
+        });
+    }
+}
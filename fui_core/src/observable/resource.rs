@@ -0,0 +1,278 @@
+use std::cell::RefCell;
+use std::fmt;
+use std::future::Future;
+use std::rc::Rc;
+
+use crate::{spawn_local, JoinHandle, Property, Subscription};
+
+///
+/// Error of the failed Resource request.
+///
+/// It is cheap to clone and two errors are equal only
+/// if they come from the same failed request.
+///
+#[derive(Clone)]
+pub struct ResourceError(Rc<anyhow::Error>);
+
+impl ResourceError {
+    pub fn new(error: anyhow::Error) -> Self {
+        ResourceError(Rc::new(error))
+    }
+
+    pub fn error(&self) -> &anyhow::Error {
+        &self.0
+    }
+}
+
+impl PartialEq for ResourceError {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl fmt::Debug for ResourceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&*self.0, f)
+    }
+}
+
+impl fmt::Display for ResourceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&*self.0, f)
+    }
+}
+
+impl From<anyhow::Error> for ResourceError {
+    fn from(error: anyhow::Error) -> Self {
+        ResourceError::new(error)
+    }
+}
+
+///
+/// State of the Resource.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum ResourceState<T> {
+    /// The request is in progress (or was not started yet).
+    Loading,
+
+    /// The last request succeeded.
+    Ready(T),
+
+    /// The last request failed.
+    Failed(ResourceError),
+}
+
+impl<T> ResourceState<T> {
+    pub fn is_loading(&self) -> bool {
+        matches!(self, ResourceState::Loading)
+    }
+
+    pub fn value(&self) -> Option<&T> {
+        match self {
+            ResourceState::Ready(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn error(&self) -> Option<&ResourceError> {
+        match self {
+            ResourceState::Failed(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+///
+/// Resource runs a future on the local thread and exposes
+/// its progress as the `state` property.
+///
+/// Starting a new request cancels the previous one,
+/// so a stale response never overwrites a newer one.
+///
+/// The `is_loading`, `has_error` and `error_message` properties
+/// can be bound directly to `BusyIndicator` and an error view.
+///
+/// # Example
+///
+/// ```ignore
+/// use fui_core::{Property, Resource};
+///
+/// let query = Property::new("".to_string());
+/// let results = Resource::from_property(&query.debounce(Duration::from_millis(300)), |query| {
+///     async move { search(&query).await }
+/// });
+///
+/// ui! {
+///     BusyIndicator {
+///         is_busy: &results.is_loading,
+///
+///         Text { Visible: &results.has_error, text: &results.error_message },
+///     }
+/// }
+/// ```
+///
+#[derive(Clone)]
+pub struct Resource<T: 'static + Clone + PartialEq> {
+    pub state: Property<ResourceState<T>>,
+    pub is_loading: Property<bool>,
+    pub has_error: Property<bool>,
+    pub error_message: Property<String>,
+
+    request_handle: Rc<RefCell<Option<JoinHandle<()>>>>,
+    input_subscription: Option<Rc<Subscription>>,
+}
+
+impl<T: 'static + Clone + PartialEq> Resource<T> {
+    /// Creates a resource in the `Loading` state with no request running.
+    pub fn new() -> Self {
+        let state = Property::new(ResourceState::Loading);
+        let is_loading = state.map_value(|s: ResourceState<T>| s.is_loading());
+        let has_error = state.map_value(|s: ResourceState<T>| s.error().is_some());
+        let error_message = state
+            .map_value(|s: ResourceState<T>| s.error().map(|e| e.to_string()).unwrap_or_default());
+
+        Resource {
+            state,
+            is_loading,
+            has_error,
+            error_message,
+            request_handle: Rc::new(RefCell::new(None)),
+            input_subscription: None,
+        }
+    }
+
+    /// Creates a resource that runs the `loader` every time
+    /// the `input` property changes (including its initial value).
+    pub fn from_property<TIn, F, Fut>(input: &Property<TIn>, loader: F) -> Self
+    where
+        TIn: 'static + Clone + PartialEq,
+        F: 'static + Fn(TIn) -> Fut,
+        Fut: 'static + Future<Output = anyhow::Result<T>>,
+    {
+        let mut resource = Resource::new();
+
+        let subscription = input.on_changed({
+            let state = resource.state.clone();
+            let request_handle = resource.request_handle.clone();
+            move |v| Self::start_request(&state, &request_handle, loader(v))
+        });
+        resource.input_subscription = Some(Rc::new(subscription));
+
+        resource
+    }
+
+    /// Starts a new request cancelling the previous one.
+    pub fn load<Fut>(&self, future: Fut)
+    where
+        Fut: 'static + Future<Output = anyhow::Result<T>>,
+    {
+        Self::start_request(&self.state, &self.request_handle, future);
+    }
+
+    /// Cancels the running request (if any).
+    /// The state is left unchanged.
+    pub fn cancel(&self) {
+        self.request_handle.borrow_mut().take();
+    }
+
+    pub fn get(&self) -> ResourceState<T> {
+        self.state.get()
+    }
+
+    fn start_request<Fut>(
+        state: &Property<ResourceState<T>>,
+        request_handle: &Rc<RefCell<Option<JoinHandle<()>>>>,
+        future: Fut,
+    ) where
+        Fut: 'static + Future<Output = anyhow::Result<T>>,
+    {
+        state.set(ResourceState::Loading);
+
+        let state = state.clone();
+        // dropping the previous handle aborts the previous request
+        request_handle.borrow_mut().replace(spawn_local(async move {
+            let new_state = match future.await {
+                Ok(value) => ResourceState::Ready(value),
+                Err(error) => ResourceState::Failed(error.into()),
+            };
+            state.set(new_state);
+        }));
+    }
+}
+
+impl<T: 'static + Clone + PartialEq> Default for Resource<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use anyhow::anyhow;
+
+    use crate::task::{run_local, run_pending_tasks};
+    use crate::{Property, Resource, ResourceState};
+
+    #[test]
+    fn test_loading_to_ready() {
+        run_local(async {
+            let resource = Resource::new();
+            resource.load(async {
+                tokio::time::sleep(Duration::from_millis(100)).await;
+                Ok(5)
+            });
+            run_pending_tasks().await;
+            assert_eq!(resource.get(), ResourceState::Loading);
+            assert!(resource.is_loading.get());
+
+            tokio::time::sleep(Duration::from_millis(150)).await;
+            assert_eq!(resource.get(), ResourceState::Ready(5));
+            assert!(!resource.is_loading.get());
+            assert!(!resource.has_error.get());
+        });
+    }
+
+    #[test]
+    fn test_loading_to_failed() {
+        run_local(async {
+            let resource = Resource::<i32>::new();
+            resource.load(async {
+                tokio::time::sleep(Duration::from_millis(100)).await;
+                Err(anyhow!("Not found!"))
+            });
+            run_pending_tasks().await;
+            assert!(resource.is_loading.get());
+
+            tokio::time::sleep(Duration::from_millis(150)).await;
+            assert!(resource.get().error().is_some());
+            assert!(!resource.is_loading.get());
+            assert!(resource.has_error.get());
+            assert_eq!(resource.error_message.get(), "Not found!");
+        });
+    }
+
+    #[test]
+    fn test_stale_request_does_not_overwrite_newer_result() {
+        run_local(async {
+            let query = Property::new(1);
+            let resource = Resource::from_property(&query, |query| async move {
+                // the first request is the slowest one
+                let delay = if query == 1 { 200 } else { 50 };
+                tokio::time::sleep(Duration::from_millis(delay)).await;
+                Ok(query * 10)
+            });
+            run_pending_tasks().await;
+
+            query.set(2);
+            run_pending_tasks().await;
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            assert_eq!(resource.get(), ResourceState::Ready(20));
+
+            tokio::time::sleep(Duration::from_millis(200)).await;
+            assert_eq!(resource.get(), ResourceState::Ready(20));
+        });
+    }
+}
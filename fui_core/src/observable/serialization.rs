@@ -0,0 +1,45 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{ObservableVec, Property};
+
+///
+/// Property serializes as its value.
+///
+impl<T> Serialize for Property<T>
+where
+    T: 'static + Clone + PartialEq + Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.read().serialize(serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Property<T>
+where
+    T: 'static + Clone + PartialEq + Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(Property::new)
+    }
+}
+
+///
+/// ObservableVec serializes as a sequence of its items.
+///
+impl<T> Serialize for ObservableVec<T>
+where
+    T: 'static + Clone + Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.lock_ref().iter())
+    }
+}
+
+impl<'de, T> Deserialize<'de> for ObservableVec<T>
+where
+    T: 'static + Clone + Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::<T>::deserialize(deserializer).map(|items| items.into_iter().collect())
+    }
+}
@@ -0,0 +1,204 @@
+use std::rc::Rc;
+
+use anyhow::{anyhow, Result};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::{ObservableVec, Property, Subscription, VecDiff};
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SnapshotFormat {
+    Json,
+    Toml,
+}
+
+type SubscribeFn = dyn Fn(Rc<dyn Fn()>) -> Subscription;
+
+struct SnapshotEntry {
+    key: String,
+    save: Box<dyn Fn() -> Result<serde_json::Value>>,
+    restore: Box<dyn Fn(serde_json::Value) -> Result<()>>,
+    subscribe: Box<SubscribeFn>,
+}
+
+///
+/// StateSnapshot saves and restores values of the registered
+/// properties and observable vectors (typically fields of a view model).
+///
+/// Restoring sets the values of the existing observables,
+/// so the bindings made to them stay intact.
+///
+/// # Example
+///
+/// ```ignore
+/// let snapshot = StateSnapshot::new()
+///     .with_property("width", &vm.width)
+///     .with_property("height", &vm.height)
+///     .with_vec("recent_files", &vm.recent_files);
+///
+/// let text = snapshot.to_string(SnapshotFormat::Toml)?;
+/// snapshot.restore_from_str(&text, SnapshotFormat::Toml)?;
+/// ```
+///
+pub struct StateSnapshot {
+    entries: Vec<SnapshotEntry>,
+}
+
+impl StateSnapshot {
+    pub fn new() -> Self {
+        StateSnapshot {
+            entries: Vec::new(),
+        }
+    }
+
+    pub fn with_property<T>(mut self, key: &str, property: &Property<T>) -> Self
+    where
+        T: 'static + Clone + PartialEq + Serialize + DeserializeOwned,
+    {
+        self.add_property(key, property);
+        self
+    }
+
+    pub fn with_vec<T>(mut self, key: &str, vec: &ObservableVec<T>) -> Self
+    where
+        T: 'static + Clone + Serialize + DeserializeOwned,
+    {
+        self.add_vec(key, vec);
+        self
+    }
+
+    pub fn add_property<T>(&mut self, key: &str, property: &Property<T>)
+    where
+        T: 'static + Clone + PartialEq + Serialize + DeserializeOwned,
+    {
+        self.entries.push(SnapshotEntry {
+            key: key.to_string(),
+            save: Box::new({
+                let property = property.clone();
+                move || Ok(serde_json::to_value(&*property.read())?)
+            }),
+            restore: Box::new({
+                let property = property.clone();
+                move |value| {
+                    property.set(serde_json::from_value(value)?);
+                    Ok(())
+                }
+            }),
+            subscribe: Box::new({
+                let property = property.clone();
+                move |notify_fn| {
+                    // the current value is reported first
+                    let mut is_initial = true;
+                    property.on_changed(move |_| {
+                        if is_initial {
+                            is_initial = false;
+                        } else {
+                            notify_fn();
+                        }
+                    })
+                }
+            }),
+        });
+    }
+
+    pub fn add_vec<T>(&mut self, key: &str, vec: &ObservableVec<T>)
+    where
+        T: 'static + Clone + Serialize + DeserializeOwned,
+    {
+        self.entries.push(SnapshotEntry {
+            key: key.to_string(),
+            save: Box::new({
+                let vec = vec.clone();
+                move || Ok(serde_json::to_value(&vec)?)
+            }),
+            restore: Box::new({
+                let vec = vec.clone();
+                move |value| {
+                    let items: Vec<T> = serde_json::from_value(value)?;
+                    vec.lock_mut().replace_cloned(items);
+                    Ok(())
+                }
+            }),
+            subscribe: Box::new({
+                let vec = vec.clone();
+                move |notify_fn| {
+                    // the current items are reported first (as clear followed by inserts),
+                    // but only if there are any
+                    let mut initial_len = vec.len();
+                    let mut initial_diffs_left = 0;
+                    vec.on_changed(move |diff| {
+                        if initial_len > 0 {
+                            let len = std::mem::take(&mut initial_len);
+                            if let VecDiff::Clear { values } = &diff
+                                && values.len() == len
+                            {
+                                initial_diffs_left = len;
+                                return;
+                            }
+                        }
+
+                        if initial_diffs_left > 0 {
+                            initial_diffs_left -= 1;
+                        } else {
+                            notify_fn();
+                        }
+                    })
+                }
+            }),
+        });
+    }
+
+    /// Returns the current values as a map of keys to values.
+    pub fn save(&self) -> Result<serde_json::Value> {
+        let mut map = serde_json::Map::new();
+        for entry in &self.entries {
+            map.insert(entry.key.clone(), (entry.save)()?);
+        }
+        Ok(serde_json::Value::Object(map))
+    }
+
+    /// Restores values from the map of keys to values.
+    /// Keys missing in the map are left unchanged.
+    pub fn restore(&self, value: &serde_json::Value) -> Result<()> {
+        let map = value
+            .as_object()
+            .ok_or_else(|| anyhow!("State snapshot has to be a map!"))?;
+        for entry in &self.entries {
+            if let Some(value) = map.get(&entry.key) {
+                (entry.restore)(value.clone())?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn to_string(&self, format: SnapshotFormat) -> Result<String> {
+        let value = self.save()?;
+        Ok(match format {
+            SnapshotFormat::Json => serde_json::to_string_pretty(&value)?,
+            SnapshotFormat::Toml => toml::to_string_pretty(&value)?,
+        })
+    }
+
+    pub fn restore_from_str(&self, text: &str, format: SnapshotFormat) -> Result<()> {
+        let value: serde_json::Value = match format {
+            SnapshotFormat::Json => serde_json::from_str(text)?,
+            SnapshotFormat::Toml => toml::from_str(text)?,
+        };
+        self.restore(&value)
+    }
+
+    /// Calls `f` when any of the registered observables changes
+    /// (not for the current values reported when the subscriptions start).
+    pub fn on_changed(&self, f: Rc<dyn Fn()>) -> Vec<Subscription> {
+        self.entries
+            .iter()
+            .map(|entry| (entry.subscribe)(f.clone()))
+            .collect()
+    }
+}
+
+impl Default for StateSnapshot {
+    fn default() -> Self {
+        Self::new()
+    }
+}
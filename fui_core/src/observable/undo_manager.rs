@@ -0,0 +1,618 @@
+use std::cell::{Cell, RefCell};
+use std::rc::{Rc, Weak};
+use std::time::{Duration, Instant};
+
+use crate::{spawn_local_and_forget, Callback, ObservableVec, Property, Subscription, VecDiff};
+
+///
+/// A single change that can be reverted and applied again.
+///
+pub trait UndoableChange {
+    fn undo(&self);
+    fn redo(&self);
+}
+
+struct Transaction {
+    id: usize,
+    name: String,
+    changes: Vec<Box<dyn UndoableChange>>,
+
+    /// Changes with the same merge key that follow quickly
+    /// one after another are merged into one transaction.
+    merge_key: Option<usize>,
+    last_change_time: Instant,
+}
+
+struct UndoManagerData {
+    undo_stack: RefCell<Vec<Transaction>>,
+    redo_stack: RefCell<Vec<Transaction>>,
+    open_transaction: RefCell<Option<Transaction>>,
+
+    merge_interval: Cell<Duration>,
+    next_id: Cell<usize>,
+
+    can_undo: Property<bool>,
+    can_redo: Property<bool>,
+    undo_name: Property<String>,
+    redo_name: Property<String>,
+
+    tracking_subscriptions: RefCell<Vec<Subscription>>,
+}
+
+///
+/// UndoManager records changes of the tracked properties and observable vectors
+/// and allows to revert and reapply them.
+///
+/// Changes are reported by the observables asynchronously,
+/// so they are recorded on the next run of the local task queue
+/// (which always happens before the next input event is processed).
+///
+/// # Example
+///
+/// ```ignore
+/// let undo_manager = UndoManager::new();
+/// undo_manager.track_property(&vm.name, "Edit name");
+/// undo_manager.track_vec(&vm.items, "Edit items");
+///
+/// ui! {
+///     Button {
+///         clicked: undo_manager.undo_command(),
+///         Text { text: &undo_manager.undo_name() },
+///     }
+/// }
+/// ```
+///
+#[derive(Clone)]
+pub struct UndoManager {
+    data: Rc<UndoManagerData>,
+}
+
+impl UndoManager {
+    pub fn new() -> Self {
+        UndoManager {
+            data: Rc::new(UndoManagerData {
+                undo_stack: RefCell::new(Vec::new()),
+                redo_stack: RefCell::new(Vec::new()),
+                open_transaction: RefCell::new(None),
+                merge_interval: Cell::new(Duration::from_millis(1000)),
+                next_id: Cell::new(0),
+                can_undo: Property::new(false),
+                can_redo: Property::new(false),
+                undo_name: Property::new(String::new()),
+                redo_name: Property::new(String::new()),
+                tracking_subscriptions: RefCell::new(Vec::new()),
+            }),
+        }
+    }
+
+    /// Sets the maximum time between subsequent changes of the same
+    /// observable that are merged into one transaction (like typing).
+    /// Zero disables merging.
+    pub fn set_merge_interval(&self, merge_interval: Duration) {
+        self.data.merge_interval.set(merge_interval);
+    }
+
+    pub fn can_undo(&self) -> Property<bool> {
+        self.data.can_undo.clone()
+    }
+
+    pub fn can_redo(&self) -> Property<bool> {
+        self.data.can_redo.clone()
+    }
+
+    /// Name of the transaction that will be reverted by `undo()`.
+    pub fn undo_name(&self) -> Property<String> {
+        self.data.undo_name.clone()
+    }
+
+    /// Name of the transaction that will be reapplied by `redo()`.
+    pub fn redo_name(&self) -> Property<String> {
+        self.data.redo_name.clone()
+    }
+
+    /// Records every change of the property under the `name` transaction.
+    pub fn track_property<T>(&self, property: &Property<T>, name: &str)
+    where
+        T: 'static + Clone + PartialEq,
+    {
+        let merge_key = self.next_id();
+        let name = name.to_string();
+        let last_value = Rc::new(RefCell::new(property.get()));
+        let data_weak = Rc::downgrade(&self.data);
+
+        let subscription = property.on_changed({
+            let property = property.clone();
+            move |value| {
+                let old_value = last_value.replace(value.clone());
+                if old_value == value {
+                    // initial value or the value set by undo / redo
+                    return;
+                }
+
+                if let Some(undo_manager) = Self::upgrade(&data_weak) {
+                    undo_manager.record(
+                        &name,
+                        Some(merge_key),
+                        Box::new(PropertyChange {
+                            property: property.clone(),
+                            last_value: last_value.clone(),
+                            old_value,
+                            new_value: value,
+                        }),
+                    );
+                }
+            }
+        });
+
+        self.data
+            .tracking_subscriptions
+            .borrow_mut()
+            .push(subscription);
+    }
+
+    /// Records every change of the vector under the `name` transaction.
+    pub fn track_vec<T>(&self, vec: &ObservableVec<T>, name: &str)
+    where
+        T: 'static + Clone,
+    {
+        let merge_key = self.next_id();
+        let name = name.to_string();
+        let ignore_count = Rc::new(Cell::new(0usize));
+        let mut initial_len = vec.len();
+        let data_weak = Rc::downgrade(&self.data);
+
+        let subscription = vec.on_changed({
+            let vec = vec.clone();
+            move |diff| {
+                // the current items are reported first (as clear followed by inserts),
+                // but only if there are any
+                if initial_len > 0 {
+                    let len = std::mem::take(&mut initial_len);
+                    if let VecDiff::Clear { values } = &diff
+                        && values.len() == len
+                    {
+                        ignore_count.set(ignore_count.get() + len);
+                        return;
+                    }
+                }
+
+                // skip the changes made by undo / redo
+                if ignore_count.get() > 0 {
+                    ignore_count.set(ignore_count.get() - 1);
+                    return;
+                }
+
+                if let VecDiff::Clear { values } = &diff
+                    && values.is_empty()
+                {
+                    return;
+                }
+
+                if let Some(undo_manager) = Self::upgrade(&data_weak) {
+                    undo_manager.record(
+                        &name,
+                        Some(merge_key),
+                        Box::new(ObservableVecChange {
+                            vec: vec.clone(),
+                            ignore_count: ignore_count.clone(),
+                            diff,
+                        }),
+                    );
+                }
+            }
+        });
+
+        self.data
+            .tracking_subscriptions
+            .borrow_mut()
+            .push(subscription);
+    }
+
+    /// Records a custom change.
+    ///
+    /// Subsequent changes with the same `merge_key` are merged together
+    /// if they come within the merge interval.
+    pub fn record(&self, name: &str, merge_key: Option<usize>, change: Box<dyn UndoableChange>) {
+        self.data.redo_stack.borrow_mut().clear();
+
+        if let Some(transaction) = self.data.open_transaction.borrow_mut().as_mut() {
+            transaction.changes.push(change);
+            self.update_state();
+            return;
+        }
+
+        let now = Instant::now();
+        {
+            let mut undo_stack = self.data.undo_stack.borrow_mut();
+            match undo_stack.last_mut() {
+                Some(last)
+                    if merge_key.is_some()
+                        && last.merge_key == merge_key
+                        && now - last.last_change_time <= self.data.merge_interval.get() =>
+                {
+                    last.changes.push(change);
+                    last.last_change_time = now;
+                }
+                _ => {
+                    undo_stack.push(Transaction {
+                        id: self.next_id(),
+                        name: name.to_string(),
+                        changes: vec![change],
+                        merge_key,
+                        last_change_time: now,
+                    });
+                }
+            }
+        }
+
+        self.update_state();
+    }
+
+    /// Starts a named transaction. All the changes recorded
+    /// until `end_transaction()` are reverted together.
+    pub fn begin_transaction(&self, name: &str) {
+        self.close_transaction();
+
+        *self.data.open_transaction.borrow_mut() = Some(Transaction {
+            id: self.next_id(),
+            name: name.to_string(),
+            changes: Vec::new(),
+            merge_key: None,
+            last_change_time: Instant::now(),
+        });
+    }
+
+    /// Ends the transaction started with `begin_transaction()`.
+    pub fn end_transaction(&self) {
+        let transaction_id = match self.data.open_transaction.borrow().as_ref() {
+            Some(transaction) => transaction.id,
+            None => return,
+        };
+
+        // changes are reported asynchronously,
+        // close the transaction after the pending ones are recorded
+        let data_weak = Rc::downgrade(&self.data);
+        spawn_local_and_forget(async move {
+            if let Some(undo_manager) = Self::upgrade(&data_weak) {
+                let is_same_transaction = undo_manager
+                    .data
+                    .open_transaction
+                    .borrow()
+                    .as_ref()
+                    .is_some_and(|t| t.id == transaction_id);
+                if is_same_transaction {
+                    undo_manager.close_transaction();
+                }
+            }
+        });
+    }
+
+    /// Runs `f` inside a named transaction.
+    pub fn transaction<F: FnOnce()>(&self, name: &str, f: F) {
+        self.begin_transaction(name);
+        f();
+        self.end_transaction();
+    }
+
+    /// Reverts the last transaction.
+    /// Returns false if there was nothing to undo.
+    pub fn undo(&self) -> bool {
+        self.close_transaction();
+
+        let transaction = self.data.undo_stack.borrow_mut().pop();
+        match transaction {
+            Some(mut transaction) => {
+                for change in transaction.changes.iter().rev() {
+                    change.undo();
+                }
+                transaction.merge_key = None;
+                self.data.redo_stack.borrow_mut().push(transaction);
+                self.seal_last_transaction();
+                self.update_state();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Reapplies the last reverted transaction.
+    /// Returns false if there was nothing to redo.
+    pub fn redo(&self) -> bool {
+        self.close_transaction();
+
+        let transaction = self.data.redo_stack.borrow_mut().pop();
+        match transaction {
+            Some(transaction) => {
+                for change in transaction.changes.iter() {
+                    change.redo();
+                }
+                self.data.undo_stack.borrow_mut().push(transaction);
+                self.update_state();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Removes all the recorded transactions.
+    pub fn clear(&self) {
+        self.data.open_transaction.borrow_mut().take();
+        self.data.undo_stack.borrow_mut().clear();
+        self.data.redo_stack.borrow_mut().clear();
+        self.update_state();
+    }
+
+    pub fn undo_command(&self) -> Callback<()> {
+        let data_weak = Rc::downgrade(&self.data);
+        Callback::new_sync(move |_| {
+            if let Some(undo_manager) = Self::upgrade(&data_weak) {
+                undo_manager.undo();
+            }
+        })
+    }
+
+    pub fn redo_command(&self) -> Callback<()> {
+        let data_weak = Rc::downgrade(&self.data);
+        Callback::new_sync(move |_| {
+            if let Some(undo_manager) = Self::upgrade(&data_weak) {
+                undo_manager.redo();
+            }
+        })
+    }
+
+    fn upgrade(data_weak: &Weak<UndoManagerData>) -> Option<UndoManager> {
+        data_weak.upgrade().map(|data| UndoManager { data })
+    }
+
+    fn next_id(&self) -> usize {
+        let id = self.data.next_id.get();
+        self.data.next_id.set(id + 1);
+        id
+    }
+
+    fn close_transaction(&self) {
+        let transaction = self.data.open_transaction.borrow_mut().take();
+        if let Some(transaction) = transaction
+            && !transaction.changes.is_empty()
+        {
+            self.data.undo_stack.borrow_mut().push(transaction);
+            self.update_state();
+        }
+    }
+
+    /// Prevents merging new changes into the last transaction.
+    fn seal_last_transaction(&self) {
+        if let Some(last) = self.data.undo_stack.borrow_mut().last_mut() {
+            last.merge_key = None;
+        }
+    }
+
+    fn update_state(&self) {
+        let open_transaction = self.data.open_transaction.borrow();
+        let undo_stack = self.data.undo_stack.borrow();
+        let redo_stack = self.data.redo_stack.borrow();
+
+        let undo_transaction = open_transaction
+            .as_ref()
+            .filter(|t| !t.changes.is_empty())
+            .or(undo_stack.last());
+
+        self.data.can_undo.set(undo_transaction.is_some());
+        self.data.can_redo.set(!redo_stack.is_empty());
+        self.data
+            .undo_name
+            .set(undo_transaction.map(|t| t.name.clone()).unwrap_or_default());
+        self.data.redo_name.set(
+            redo_stack
+                .last()
+                .map(|t| t.name.clone())
+                .unwrap_or_default(),
+        );
+    }
+}
+
+impl Default for UndoManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+///
+/// Allows to pass the undo manager to controls.
+///
+/// Example:
+///
+/// ui! { TextBox { text: &mut vm.text, undo_manager: &vm.undo_manager }}
+///
+impl From<&UndoManager> for Option<UndoManager> {
+    fn from(undo_manager: &UndoManager) -> Self {
+        Some(undo_manager.clone())
+    }
+}
+
+//
+// Property change
+//
+
+struct PropertyChange<T: 'static + Clone + PartialEq> {
+    property: Property<T>,
+    last_value: Rc<RefCell<T>>,
+    old_value: T,
+    new_value: T,
+}
+
+impl<T: 'static + Clone + PartialEq> UndoableChange for PropertyChange<T> {
+    fn undo(&self) {
+        // update the tracked value first, so the change is not recorded again
+        *self.last_value.borrow_mut() = self.old_value.clone();
+        self.property.set(self.old_value.clone());
+    }
+
+    fn redo(&self) {
+        *self.last_value.borrow_mut() = self.new_value.clone();
+        self.property.set(self.new_value.clone());
+    }
+}
+
+//
+// ObservableVec change
+//
+
+struct ObservableVecChange<T: 'static + Clone> {
+    vec: ObservableVec<T>,
+    ignore_count: Rc<Cell<usize>>,
+    diff: VecDiff<T>,
+}
+
+impl<T: 'static + Clone> ObservableVecChange<T> {
+    fn ignore_next(&self, count: usize) {
+        self.ignore_count.set(self.ignore_count.get() + count);
+    }
+}
+
+impl<T: 'static + Clone> UndoableChange for ObservableVecChange<T> {
+    fn undo(&self) {
+        let mut items = self.vec.lock_mut();
+        match &self.diff {
+            VecDiff::Clear { values } => {
+                // replace is reported as clear followed by inserts
+                self.ignore_next(values.len() + 1);
+                items.replace_cloned(values.clone());
+            }
+            VecDiff::InsertAt { index, value: _ } => {
+                self.ignore_next(1);
+                items.remove(*index);
+            }
+            VecDiff::RemoveAt { index, value } => {
+                self.ignore_next(1);
+                items.insert_cloned(*index, value.clone());
+            }
+            VecDiff::Move {
+                old_index,
+                new_index,
+            } => {
+                self.ignore_next(1);
+                items.move_from_to(*new_index, *old_index);
+            }
+            VecDiff::Pop { value } => {
+                self.ignore_next(1);
+                items.push_cloned(value.clone());
+            }
+            VecDiff::Push { value: _ } => {
+                self.ignore_next(1);
+                items.pop();
+            }
+        }
+    }
+
+    fn redo(&self) {
+        let mut items = self.vec.lock_mut();
+        self.ignore_next(1);
+        match &self.diff {
+            VecDiff::Clear { values: _ } => {
+                items.clear();
+            }
+            VecDiff::InsertAt { index, value } => {
+                items.insert_cloned(*index, value.clone());
+            }
+            VecDiff::RemoveAt { index, value: _ } => {
+                items.remove(*index);
+            }
+            VecDiff::Move {
+                old_index,
+                new_index,
+            } => {
+                items.move_from_to(*old_index, *new_index);
+            }
+            VecDiff::Pop { value: _ } => {
+                items.pop();
+            }
+            VecDiff::Push { value } => {
+                items.push_cloned(value.clone());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::task::{run_local, run_pending_tasks};
+    use crate::{ObservableVec, Property, UndoManager};
+
+    #[test]
+    fn test_track_property() {
+        run_local(async {
+            let name = Property::new("a".to_string());
+            let undo_manager = UndoManager::new();
+            undo_manager.track_property(&name, "Edit name");
+            run_pending_tasks().await;
+            assert!(!undo_manager.can_undo().get());
+
+            name.set("b".to_string());
+            run_pending_tasks().await;
+            assert!(undo_manager.can_undo().get());
+            assert_eq!(undo_manager.undo_name().get(), "Edit name");
+
+            assert!(undo_manager.undo());
+            run_pending_tasks().await;
+            assert_eq!(name.get(), "a");
+            assert!(!undo_manager.can_undo().get());
+
+            assert!(undo_manager.redo());
+            run_pending_tasks().await;
+            assert_eq!(name.get(), "b");
+            assert!(!undo_manager.can_redo().get());
+        });
+    }
+
+    #[test]
+    fn test_track_vec_skips_initial_items() {
+        run_local(async {
+            let items = ObservableVec::new();
+            items.push(1);
+            items.push(2);
+
+            let undo_manager = UndoManager::new();
+            undo_manager.track_vec(&items, "Edit items");
+            run_pending_tasks().await;
+            assert!(!undo_manager.can_undo().get());
+
+            items.push(3);
+            run_pending_tasks().await;
+            assert!(undo_manager.can_undo().get());
+
+            assert!(undo_manager.undo());
+            run_pending_tasks().await;
+            assert_eq!(items.lock_ref().to_vec(), vec![1, 2]);
+            assert!(!undo_manager.can_undo().get());
+        });
+    }
+
+    #[test]
+    fn test_track_empty_vec() {
+        run_local(async {
+            let items = ObservableVec::new();
+
+            let undo_manager = UndoManager::new();
+            undo_manager.track_vec(&items, "Edit items");
+            run_pending_tasks().await;
+            assert!(!undo_manager.can_undo().get());
+
+            items.push(1);
+            run_pending_tasks().await;
+            assert!(undo_manager.can_undo().get());
+
+            items.push(2);
+            run_pending_tasks().await;
+
+            assert!(undo_manager.undo());
+            run_pending_tasks().await;
+            assert!(items.lock_ref().is_empty());
+            assert!(!undo_manager.can_undo().get());
+
+            assert!(undo_manager.redo());
+            run_pending_tasks().await;
+            assert_eq!(items.lock_ref().to_vec(), vec![1, 2]);
+        });
+    }
+}
@@ -7,5 +7,10 @@ pub use self::file_dialog_service::*;
 mod services;
 pub use self::services::*;
 
+#[cfg(feature = "serde")]
+mod settings_store;
+#[cfg(feature = "serde")]
+pub use self::settings_store::*;
+
 mod window_service;
 pub use self::window_service::*;
@@ -0,0 +1,190 @@
+use std::cell::RefCell;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::time::Duration;
+
+use anyhow::Result;
+
+use crate::{spawn_local, JoinHandle, SnapshotFormat, StateSnapshot, Subscription};
+
+struct SettingsStoreData {
+    path: PathBuf,
+    format: SnapshotFormat,
+    snapshot: StateSnapshot,
+    pending_save: RefCell<Option<JoinHandle<()>>>,
+}
+
+///
+/// SettingsStore keeps the observables registered in the snapshot
+/// in sync with a settings file.
+///
+/// The file is loaded when the store is created and saved
+/// automatically shortly after any of the observables changes.
+///
+/// The file format is TOML for the `.toml` extension and JSON otherwise.
+///
+pub struct SettingsStore {
+    data: Rc<SettingsStoreData>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl SettingsStore {
+    pub fn new<P: AsRef<Path>>(path: P, snapshot: StateSnapshot) -> Self {
+        let path = path.as_ref().to_path_buf();
+        let format = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => SnapshotFormat::Toml,
+            _ => SnapshotFormat::Json,
+        };
+
+        let data = Rc::new(SettingsStoreData {
+            path,
+            format,
+            snapshot,
+            pending_save: RefCell::new(None),
+        });
+
+        if data.path.exists()
+            && let Err(err) = Self::load_data(&data)
+        {
+            eprintln!("Cannot load settings from {:?}: {}", data.path, err);
+        }
+
+        let subscriptions = data.snapshot.on_changed(Rc::new({
+            let data_weak = Rc::downgrade(&data);
+            move || {
+                if let Some(data) = data_weak.upgrade() {
+                    Self::schedule_save(&data);
+                }
+            }
+        }));
+
+        SettingsStore {
+            data,
+            _subscriptions: subscriptions,
+        }
+    }
+
+    pub fn get_path(&self) -> &Path {
+        &self.data.path
+    }
+
+    /// Reloads the settings from the file.
+    pub fn load(&self) -> Result<()> {
+        Self::load_data(&self.data)
+    }
+
+    /// Saves the settings to the file immediately.
+    pub fn save(&self) -> Result<()> {
+        self.data.pending_save.borrow_mut().take();
+        Self::save_data(&self.data)
+    }
+
+    fn load_data(data: &SettingsStoreData) -> Result<()> {
+        let text = std::fs::read_to_string(&data.path)?;
+        data.snapshot.restore_from_str(&text, data.format)
+    }
+
+    fn save_data(data: &SettingsStoreData) -> Result<()> {
+        let text = data.snapshot.to_string(data.format)?;
+        if let Some(parent) = data.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&data.path, text)?;
+        Ok(())
+    }
+
+    fn schedule_save(data: &Rc<SettingsStoreData>) {
+        // many changes in a short time result in a single save
+        let data_weak = Rc::downgrade(data);
+        data.pending_save
+            .borrow_mut()
+            .replace(spawn_local(async move {
+                tokio::time::sleep(Duration::from_millis(500)).await;
+                if let Some(data) = data_weak.upgrade()
+                    && let Err(err) = Self::save_data(&data)
+                {
+                    eprintln!("Cannot save settings to {:?}: {}", data.path, err);
+                }
+            }));
+    }
+}
+
+impl Drop for SettingsStore {
+    fn drop(&mut self) {
+        // do not lose the changes waiting to be saved
+        if self.data.pending_save.borrow_mut().take().is_some()
+            && let Err(err) = Self::save_data(&self.data)
+        {
+            eprintln!("Cannot save settings to {:?}: {}", self.data.path, err);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::task::{run_local, run_pending_tasks};
+    use crate::{ObservableVec, Property, SettingsStore, StateSnapshot};
+
+    #[test]
+    fn test_saves_only_after_changes() {
+        run_local(async {
+            let path = std::env::temp_dir().join(format!(
+                "fui_settings_store_test_{}.json",
+                std::process::id()
+            ));
+            let _ = std::fs::remove_file(&path);
+
+            let width = Property::new(100);
+            let recent_files = ObservableVec::new();
+            recent_files.push("a.txt".to_string());
+            let store = SettingsStore::new(
+                &path,
+                StateSnapshot::new()
+                    .with_property("width", &width)
+                    .with_vec("recent_files", &recent_files),
+            );
+
+            run_pending_tasks().await;
+            tokio::time::sleep(Duration::from_secs(1)).await;
+            assert!(!path.exists());
+
+            width.set(200);
+            run_pending_tasks().await;
+            tokio::time::sleep(Duration::from_secs(1)).await;
+            assert!(path.exists());
+
+            drop(store);
+            std::fs::remove_file(&path).unwrap();
+        });
+    }
+    #[test]
+    fn test_saves_after_the_first_item_of_empty_vec() {
+        run_local(async {
+            let path = std::env::temp_dir().join(format!(
+                "fui_settings_store_empty_vec_test_{}.json",
+                std::process::id()
+            ));
+            let _ = std::fs::remove_file(&path);
+
+            let recent_files = ObservableVec::new();
+            let store = SettingsStore::new(
+                &path,
+                StateSnapshot::new().with_vec("recent_files", &recent_files),
+            );
+
+            run_pending_tasks().await;
+            tokio::time::sleep(Duration::from_secs(1)).await;
+            assert!(!path.exists());
+
+            recent_files.push("a.txt".to_string());
+            run_pending_tasks().await;
+            tokio::time::sleep(Duration::from_secs(1)).await;
+            assert!(path.exists());
+
+            drop(store);
+            std::fs::remove_file(&path).unwrap();
+        });
+    }
+}
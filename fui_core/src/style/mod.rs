@@ -1,2 +1,5 @@
 mod style;
 pub use self::style::*;
+
+mod style_sheet;
+pub use self::style_sheet::*;
@@ -0,0 +1,282 @@
+use std::any::TypeId;
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use crate::{ControlContext, TypeMap, TypeMapKey, VisualStateFlag};
+
+//
+// Attached values
+//
+
+///
+/// Style sheet of the control and its descendants.
+///
+/// Style sheets of all the ancestors are applied (the outer ones first,
+/// so the inner ones can override them).
+///
+pub struct Styles;
+impl TypeMapKey for Styles {
+    type Value = Rc<StyleSheet>;
+}
+
+///
+/// Space separated list of style classes of the control (e.g. `"primary large"`).
+///
+pub struct StyleClass;
+impl TypeMapKey for StyleClass {
+    type Value = String;
+}
+
+impl StyleClass {
+    pub fn contains(control_context: &ControlContext, class: &str) -> bool {
+        control_context
+            .get_attached_value::<StyleClass>()
+            .map(|classes| classes.split_whitespace().any(|c| c == class))
+            .unwrap_or(false)
+    }
+}
+
+//
+// StyleSetter
+//
+
+thread_local! {
+    static NEXT_STYLE_SETTER_ID: Cell<usize> = const { Cell::new(0) };
+}
+
+///
+/// Attached value set by the style sheet on the matching controls.
+///
+pub struct StyleSetter {
+    // unique id, so the controls can tell if the value comes from the same setter
+    id: usize,
+    key: TypeId,
+    apply: Box<dyn Fn(&mut TypeMap)>,
+}
+
+impl StyleSetter {
+    pub fn new<K: TypeMapKey + 'static>(value: K::Value) -> Self
+    where
+        K::Value: Clone,
+    {
+        StyleSetter {
+            id: NEXT_STYLE_SETTER_ID.with(|id| id.replace(id.get() + 1)),
+            key: TypeId::of::<K>(),
+            apply: Box::new(move |values| {
+                values.insert::<K>(value.clone());
+            }),
+        }
+    }
+}
+
+//
+// Selector
+//
+
+///
+/// Selects controls by their type, style classes and ancestors.
+///
+/// # Example
+///
+/// ```ignore
+/// // all the buttons with the "primary" class inside a tool bar
+/// Selector::of_type::<Button>()
+///     .with_class("primary")
+///     .inside(Selector::class("toolbar"))
+/// ```
+///
+#[derive(Clone)]
+pub struct Selector {
+    control_type: Option<TypeId>,
+    classes: Vec<String>,
+    visual_states: Vec<VisualStateFlag>,
+    ancestor: Option<Box<Selector>>,
+}
+
+impl Selector {
+    /// Matches all the controls.
+    pub fn any() -> Self {
+        Selector {
+            control_type: None,
+            classes: Vec::new(),
+            visual_states: Vec::new(),
+            ancestor: None,
+        }
+    }
+
+    /// Matches controls of the given type (e.g. `Button`).
+    pub fn of_type<T: 'static>() -> Self {
+        Selector {
+            control_type: Some(TypeId::of::<T>()),
+            ..Selector::any()
+        }
+    }
+
+    /// Matches controls with the given style class.
+    pub fn class(class: &str) -> Self {
+        Selector::any().with_class(class)
+    }
+
+    /// Additionally requires the given style class.
+    pub fn with_class(mut self, class: &str) -> Self {
+        self.classes.push(class.to_string());
+        self
+    }
+
+    ///
+    /// Additionally requires the given visual state (e.g. hovered buttons).
+    ///
+    /// The rule is applied again when the visual state of the control changes
+    /// (not when the state of its ancestors changes).
+    ///
+    pub fn with_state(mut self, visual_state: VisualStateFlag) -> Self {
+        self.visual_states.push(visual_state);
+        self
+    }
+
+    /// Additionally requires an ancestor matching the given selector.
+    pub fn inside(mut self, ancestor: Selector) -> Self {
+        let ancestor = match self.ancestor.take() {
+            // `a.inside(b).inside(c)` means `a` inside `b` inside `c`
+            Some(inner) => (*inner).inside(ancestor),
+            None => ancestor,
+        };
+        self.ancestor = Some(Box::new(ancestor));
+        self
+    }
+
+    pub fn matches(&self, control_context: &ControlContext) -> bool {
+        if let Some(control_type) = self.control_type
+            && control_context.get_control_type() != Some(control_type)
+        {
+            return false;
+        }
+
+        if !self
+            .classes
+            .iter()
+            .all(|class| StyleClass::contains(control_context, class))
+        {
+            return false;
+        }
+
+        let visual_state = control_context.get_visual_state();
+        if !self
+            .visual_states
+            .iter()
+            .all(|flag| visual_state.contains(*flag))
+        {
+            return false;
+        }
+
+        match self.ancestor {
+            Some(ref ancestor) => {
+                let mut current_parent = control_context.get_parent();
+                while let Some(parent) = current_parent {
+                    if ancestor.matches(parent.get_context()) {
+                        return true;
+                    }
+                    current_parent = parent.get_context().get_parent();
+                }
+                false
+            }
+            None => true,
+        }
+    }
+}
+
+//
+// StyleSheet
+//
+
+struct StyleRule {
+    selector: Selector,
+    setters: Vec<StyleSetter>,
+}
+
+///
+/// Attached values set on all the controls matching the selectors.
+///
+/// The style sheet is attached with `Styles` to the window root control
+/// or to any control of the tree and applies to the control and its descendants.
+/// Rules are applied in order (later rules override earlier ones).
+/// Values given to the control itself always win over the style sheet.
+///
+/// # Example
+///
+/// ```ignore
+/// ui! {
+///     Vertical {
+///         Styles: StyleSheet::new()
+///             .with_rule(Selector::of_type::<Text>(), vec![
+///                 StyleSetter::new::<FontSize>(Property::new(16.0f32)),
+///             ])
+///             .with_rule(Selector::of_type::<Button>().inside(Selector::class("toolbar")), vec![
+///                 StyleSetter::new::<Margin>(Thickness::all(2.0f32)),
+///             ])
+///             .with_rule(Selector::of_type::<Text>().with_state(VisualStateFlag::Disabled), vec![
+///                 StyleSetter::new::<Foreground>(Property::new(Color::rgba(0.5, 0.5, 0.5, 1.0))),
+///             ]),
+///
+///         Horizontal {
+///             StyleClass: "toolbar",
+///
+///             Button { Text { text: "Open" } },
+///             Button { Text { text: "Save" } },
+///         },
+///     }
+/// }
+/// ```
+///
+pub struct StyleSheet {
+    rules: Vec<StyleRule>,
+}
+
+impl StyleSheet {
+    pub fn new() -> Self {
+        StyleSheet { rules: Vec::new() }
+    }
+
+    pub fn with_rule(mut self, selector: Selector, setters: Vec<StyleSetter>) -> Self {
+        self.rules.push(StyleRule { selector, setters });
+        self
+    }
+
+    /// Adds the rule for controls with the given style class.
+    pub fn with_class(self, class: &str, setters: Vec<StyleSetter>) -> Self {
+        self.with_rule(Selector::class(class), setters)
+    }
+
+    pub(crate) fn has_visual_state_rules(&self) -> bool {
+        self.rules
+            .iter()
+            .any(|rule| !rule.selector.visual_states.is_empty())
+    }
+
+    ///
+    /// Sets the values of the matching rules. The ids of the setters
+    /// the values come from are stored in `setter_ids`.
+    ///
+    pub(crate) fn apply(
+        &self,
+        control_context: &ControlContext,
+        values: &mut TypeMap,
+        setter_ids: &mut HashMap<TypeId, usize>,
+    ) {
+        for rule in &self.rules {
+            if rule.selector.matches(control_context) {
+                for setter in &rule.setters {
+                    (setter.apply)(values);
+                    setter_ids.insert(setter.key, setter.id);
+                }
+            }
+        }
+    }
+}
+
+impl Default for StyleSheet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
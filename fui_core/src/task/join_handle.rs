@@ -3,11 +3,28 @@
 ///
 pub struct JoinHandle<T> {
     handle: tokio::task::JoinHandle<T>,
+
+    #[cfg(feature = "diagnostics")]
+    diagnostics_token: crate::diagnostics::SubscriptionToken,
 }
 
 impl<T> JoinHandle<T> {
+    #[cfg_attr(feature = "diagnostics", track_caller)]
     pub fn new(handle: tokio::task::JoinHandle<T>) -> Self {
-        JoinHandle { handle }
+        JoinHandle {
+            handle,
+
+            #[cfg(feature = "diagnostics")]
+            diagnostics_token: crate::diagnostics::SubscriptionToken::new(
+                crate::diagnostics::SubscriptionKind::Task,
+                std::panic::Location::caller(),
+            ),
+        }
+    }
+
+    #[cfg(feature = "diagnostics")]
+    pub(crate) fn diagnostics_id(&self) -> u64 {
+        self.diagnostics_token.id()
     }
 }
 
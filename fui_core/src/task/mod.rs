@@ -3,3 +3,8 @@ pub use spawn::*;
 
 mod join_handle;
 pub use join_handle::*;
+
+#[cfg(test)]
+mod test_runtime;
+#[cfg(test)]
+pub(crate) use test_runtime::*;
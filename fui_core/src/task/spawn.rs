@@ -4,6 +4,7 @@ use std::future::Future;
 ///
 /// Spawn task on the same thread and return handle that will abort it on drop.
 ///
+#[cfg_attr(feature = "diagnostics", track_caller)]
 pub fn spawn_local<F>(future: F) -> JoinHandle<F::Output>
 where
     F: Future + 'static,
@@ -0,0 +1,25 @@
+use std::future::Future;
+
+///
+/// Runs the test on a single threaded runtime with a local task set
+/// (required by `spawn_local`). The time is paused and advances
+/// automatically when all the tasks wait for timers.
+///
+pub(crate) fn run_local<F: Future>(future: F) -> F::Output {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .start_paused(true)
+        .build()
+        .unwrap();
+    tokio::task::LocalSet::new().block_on(&runtime, future)
+}
+
+///
+/// Lets the spawned tasks (e.g. property subscriptions)
+/// handle the pending changes without advancing the time.
+///
+pub(crate) async fn run_pending_tasks() {
+    for _ in 0..10 {
+        tokio::task::yield_now().await;
+    }
+}